@@ -0,0 +1,237 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::approx::ApproxEq;
+use crate::scalar::Scalar;
+
+/// Plane angle
+///
+/// Stored internally in radians; Constructors and accessors are provided for radians, degrees, turns and gradians, so that the unit is always explicit at the point of use.
+///
+/// ```
+/// use unifiedgeometry::angle::Angle;
+///
+/// let right_angle = Angle::<f64>::from_degrees(90.0);
+/// assert_eq!(right_angle, Angle::from_turns(0.25));
+/// assert_eq!(right_angle.radians(), std::f64::consts::FRAC_PI_2);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug)]
+pub struct Angle<T>(T);
+
+impl<T: Scalar> Angle<T> {
+    /// Zero angle
+    pub const ZERO: Self = Angle(T::ZERO);
+
+    /// Straight angle; PI radians, 180 degrees
+    pub const STRAIGHT: Self = Angle(T::PI);
+
+    /// Create an angle from a value in radians
+    #[inline]
+    pub fn from_radians(radians: T) -> Self {
+        Angle(radians)
+    }
+
+    /// Create an angle from a value in degrees; 360 degrees per turn
+    #[inline]
+    pub fn from_degrees(degrees: T) -> Self {
+        Angle(degrees * (T::PI / T::i(180)))
+    }
+
+    /// Create an angle from a number of full turns
+    #[inline]
+    pub fn from_turns(turns: T) -> Self {
+        Angle(turns * (T::i(2) * T::PI))
+    }
+
+    /// Create an angle from a value in gradians; 400 gradians per turn
+    #[inline]
+    pub fn from_gradians(gradians: T) -> Self {
+        Angle(gradians * (T::PI / T::i(200)))
+    }
+
+    /// Value of this angle in radians
+    #[inline]
+    pub fn radians(self) -> T {
+        self.0
+    }
+
+    /// Value of this angle in degrees
+    #[inline]
+    pub fn degrees(self) -> T {
+        self.0 * (T::i(180) / T::PI)
+    }
+
+    /// Value of this angle in turns
+    #[inline]
+    pub fn turns(self) -> T {
+        self.0 / (T::i(2) * T::PI)
+    }
+
+    /// Value of this angle in gradians
+    #[inline]
+    pub fn gradians(self) -> T {
+        self.0 * (T::i(200) / T::PI)
+    }
+
+    /// True if this angle is a finite non-NaN value, see [`Scalar::is_finite`]
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Equivalent angle in the range `[0, 2π)`
+    ///
+    /// Angles already in range are returned unchanged
+    pub fn normalized(self) -> Self {
+        let full = T::i(2) * T::PI;
+        if self.0 >= T::ZERO && self.0 < full {
+            return self;
+        }
+        let reduced = self.0 - full * (self.0 / full).floor();
+        // Rounding may place the result just outside of the range; Tiny negative angles may round up to exactly 2π
+        if reduced >= full {
+            Angle(T::ZERO)
+        } else if reduced < T::ZERO {
+            Angle(reduced + full).normalized()
+        } else {
+            Angle(reduced)
+        }
+    }
+
+    /// Equivalent angle in the range `(-π, π]`
+    ///
+    /// Angles already in range are returned unchanged
+    pub fn normalized_signed(self) -> Self {
+        if self.0 > -T::PI && self.0 <= T::PI {
+            return self;
+        }
+        let full = T::i(2) * T::PI;
+        let reduced = self.0 - full * ((self.0 - T::PI) / full).ceil();
+        // Rounding may place the result just outside of the range
+        if reduced <= -T::PI {
+            Angle(reduced + full)
+        } else if reduced > T::PI {
+            Angle(reduced - full)
+        } else {
+            Angle(reduced)
+        }
+    }
+
+    /// Sine of this angle
+    #[inline]
+    pub fn sin(self) -> T {
+        self.0.sin()
+    }
+
+    /// Cosine of this angle
+    #[inline]
+    pub fn cos(self) -> T {
+        self.0.cos()
+    }
+
+    /// Tangent of this angle
+    #[inline]
+    pub fn tan(self) -> T {
+        self.0.sin() / self.0.cos()
+    }
+
+    /// Arc-sine, in the range `[-π/2, π/2]`
+    ///
+    /// Returns `None` if `value` is outside `[-1, 1]`
+    #[inline]
+    pub fn asin(value: T) -> Option<Self> {
+        value.asin().map(Angle)
+    }
+
+    /// Arc-cosine, in the range `[0, π]`
+    ///
+    /// Returns `None` if `value` is outside `[-1, 1]`
+    #[inline]
+    pub fn acos(value: T) -> Option<Self> {
+        value.acos().map(Angle)
+    }
+
+    /// Four-quadrant arc-tangent of `y / x`, in the range `[-π, π]`
+    ///
+    /// Returns zero if both `x` and `y` are zero
+    #[inline]
+    pub fn atan2(y: T, x: T) -> Self {
+        Angle(T::atan2(y, x))
+    }
+}
+
+impl<T: Scalar> Neg for Angle<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Angle(-self.0)
+    }
+}
+
+impl<T: Scalar> Add for Angle<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Angle(self.0 + rhs.0)
+    }
+}
+
+impl<T: Scalar> AddAssign for Angle<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0
+    }
+}
+
+impl<T: Scalar> Sub for Angle<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Angle(self.0 - rhs.0)
+    }
+}
+
+impl<T: Scalar> SubAssign for Angle<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0
+    }
+}
+
+impl<T: Scalar> Mul<T> for Angle<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Angle(self.0 * rhs)
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Angle<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 *= rhs
+    }
+}
+
+impl<T: Scalar> Div<T> for Angle<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Angle(self.0 / rhs)
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Angle<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.0 /= rhs
+    }
+}
+
+/// Ratio of two angles
+impl<T: Scalar> Div for Angle<T> {
+    type Output = T;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.0 / rhs.0
+    }
+}
+
+/// Compares radians; Angles differing by full turns are not considered equal
+impl<T: ApproxEq<Epsilon=T>> ApproxEq for Angle<T> {
+    impl_approx_eq_fields!(T; 0);
+}
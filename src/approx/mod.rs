@@ -0,0 +1,215 @@
+//! Tolerance-aware approximate equality
+//!
+//! Floating point results rarely compare exactly equal; [`ApproxEq`] provides three comparison modes:
+//! * Absolute: `|a - b| <= epsilon`. Suitable for values near zero
+//! * Relative: `|a - b| <= max(|a|, |b|) * max_relative`. Suitable for values of arbitrary magnitude
+//! * ULPs: `a` and `b` are at most `max_ulps` representable values apart. Suitable for values expected to differ only by rounding
+//!
+//! Relative and ULPs comparisons additionally accept values within the absolute `epsilon`, as neither mode behaves well near zero.
+//!
+//! Compound types (vectors, matrices, shapes) compare component-wise; All components must be approximately equal.
+//!
+//! ```
+//! use unifiedgeometry::{approx_eq, assert_approx_eq};
+//! use unifiedgeometry::geometry3d::Vector3D;
+//!
+//! let a = Vector3D::<f64, ()>::new([0.1 + 0.2, 1.0, 0.0]);
+//! let b = Vector3D::<f64, ()>::new([0.3, 1.0, 0.0]);
+//! assert_ne!(a, b);
+//! assert_approx_eq!(a, b);
+//! assert_approx_eq!(a, b, max_ulps = 1);
+//! assert!(!approx_eq!(a, b, epsilon = 0.0));
+//! ```
+
+/// Approximate equality, see the [module documentation](crate::approx) for comparison modes
+///
+/// Types where exact equality is appropriate may still implement this trait, such that they can be used in generic code and compound types.
+pub trait ApproxEq: Copy {
+    /// Type of absolute and relative tolerances; The component scalar type
+    type Epsilon: Copy;
+
+    /// Default absolute tolerance, used where only a relative or ULPs tolerance is specified
+    fn default_epsilon() -> Self::Epsilon;
+
+    /// Default relative tolerance
+    fn default_max_relative() -> Self::Epsilon;
+
+    /// Default ULPs tolerance
+    fn default_max_ulps() -> u32;
+
+    /// Absolute comparison; True if `self` and `other` differ by at most `epsilon`
+    fn abs_diff_eq(self, other: Self, epsilon: Self::Epsilon) -> bool;
+
+    /// Relative comparison; True if `self` and `other` differ by at most `max_relative` times the larger magnitude, or by at most `epsilon`
+    fn relative_eq(self, other: Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+
+    /// ULPs comparison; True if `self` and `other` are at most `max_ulps` representable values apart, or differ by at most `epsilon`
+    fn ulps_eq(self, other: Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+}
+
+macro_rules! impl_approx_eq_float {
+    ($float:ty, $bits:ty) => {
+        impl ApproxEq for $float {
+            type Epsilon = $float;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon { <$float>::EPSILON }
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon { <$float>::EPSILON }
+            #[inline]
+            fn default_max_ulps() -> u32 { 4 }
+
+            #[inline]
+            fn abs_diff_eq(self, other: Self, epsilon: Self::Epsilon) -> bool {
+                self == other || (self - other).abs() <= epsilon    // Exact comparison first, such that equal infinities compare equal
+            }
+
+            fn relative_eq(self, other: Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                } else if !self.is_finite() || !other.is_finite() {
+                    return false;
+                }
+                let largest = self.abs().max(other.abs());
+                (self - other).abs() <= largest * max_relative
+            }
+
+            fn ulps_eq(self, other: Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                } else if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+                    return false;
+                }
+                // For equal signs, the ordering of the bit patterns matches the ordering of the values
+                let distance = (self.to_bits() as $bits).abs_diff(other.to_bits() as $bits);
+                distance <= max_ulps as _
+            }
+        }
+    };
+}
+
+impl_approx_eq_float!(f32, i32);
+impl_approx_eq_float!(f64, i64);
+
+// Helpers for the comparison macros, which infer the tolerance type from the compared value
+#[doc(hidden)]
+#[inline]
+pub fn default_epsilon_of<A: ApproxEq>(_: &A) -> A::Epsilon {
+    A::default_epsilon()
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn default_max_relative_of<A: ApproxEq>(_: &A) -> A::Epsilon {
+    A::default_max_relative()
+}
+
+/// True if both values are approximately equal, see [`ApproxEq`]
+///
+/// Uses a relative comparison with default tolerances, unless specified otherwise:
+/// * `approx_eq!(a, b)`
+/// * `approx_eq!(a, b, epsilon = e)` (absolute)
+/// * `approx_eq!(a, b, max_relative = r)` / `approx_eq!(a, b, epsilon = e, max_relative = r)`
+/// * `approx_eq!(a, b, max_ulps = u)` / `approx_eq!(a, b, epsilon = e, max_ulps = u)`
+#[macro_export]
+macro_rules! approx_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let (left, right) = ($left, $right);
+        $crate::approx::ApproxEq::relative_eq(left, right, $crate::approx::default_epsilon_of(&left), $crate::approx::default_max_relative_of(&left))
+    }};
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::approx::ApproxEq::abs_diff_eq($left, $right, $epsilon)
+    };
+    ($left:expr, $right:expr, max_relative = $max_relative:expr $(,)?) => {{
+        let (left, right) = ($left, $right);
+        $crate::approx::ApproxEq::relative_eq(left, right, $crate::approx::default_epsilon_of(&left), $max_relative)
+    }};
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::approx::ApproxEq::relative_eq($left, $right, $epsilon, $max_relative)
+    };
+    ($left:expr, $right:expr, max_ulps = $max_ulps:expr $(,)?) => {{
+        let (left, right) = ($left, $right);
+        $crate::approx::ApproxEq::ulps_eq(left, right, $crate::approx::default_epsilon_of(&left), $max_ulps)
+    }};
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::approx::ApproxEq::ulps_eq($left, $right, $epsilon, $max_ulps)
+    };
+}
+
+/// Asserts that both values are approximately equal, see [`approx_eq!`] for tolerance options
+///
+/// On failure, panics with the [`Debug`] representations of both values
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(, $tolerance:ident = $value:expr)* $(,)?) => {{
+        let (left, right) = ($left, $right);
+        if !$crate::approx_eq!(left, right $(, $tolerance = $value)*) {
+            panic!("assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}", left, right);
+        }
+    }};
+}
+
+/// Asserts that both values are not approximately equal, see [`approx_eq!`] for tolerance options
+///
+/// On failure, panics with the [`Debug`] representations of both values
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(, $tolerance:ident = $value:expr)* $(,)?) => {{
+        let (left, right) = ($left, $right);
+        if $crate::approx_eq!(left, right $(, $tolerance = $value)*) {
+            panic!("assertion `left ≉ right` failed\n  left: {:?}\n right: {:?}", left, right);
+        }
+    }};
+}
+
+/// Implements [`ApproxEq`] for a struct by comparing each listed field, all of which share the same [`ApproxEq::Epsilon`]
+macro_rules! impl_approx_eq_fields {
+    ($epsilon:ty; $first:tt $(, $field:tt)* $(,)?) => {
+        type Epsilon = $epsilon;
+
+        #[inline]
+        fn default_epsilon() -> Self::Epsilon { <$epsilon as $crate::approx::ApproxEq>::default_epsilon() }
+        #[inline]
+        fn default_max_relative() -> Self::Epsilon { <$epsilon as $crate::approx::ApproxEq>::default_max_relative() }
+        #[inline]
+        fn default_max_ulps() -> u32 { <$epsilon as $crate::approx::ApproxEq>::default_max_ulps() }
+
+        #[inline]
+        fn abs_diff_eq(self, other: Self, epsilon: Self::Epsilon) -> bool {
+            $crate::approx::ApproxEq::abs_diff_eq(self.$first, other.$first, epsilon) $(&& $crate::approx::ApproxEq::abs_diff_eq(self.$field, other.$field, epsilon))*
+        }
+
+        #[inline]
+        fn relative_eq(self, other: Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+            $crate::approx::ApproxEq::relative_eq(self.$first, other.$first, epsilon, max_relative) $(&& $crate::approx::ApproxEq::relative_eq(self.$field, other.$field, epsilon, max_relative))*
+        }
+
+        #[inline]
+        fn ulps_eq(self, other: Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+            $crate::approx::ApproxEq::ulps_eq(self.$first, other.$first, epsilon, max_ulps) $(&& $crate::approx::ApproxEq::ulps_eq(self.$field, other.$field, epsilon, max_ulps))*
+        }
+    };
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon { T::default_epsilon() }
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon { T::default_max_relative() }
+    #[inline]
+    fn default_max_ulps() -> u32 { T::default_max_ulps() }
+
+    fn abs_diff_eq(self, other: Self, epsilon: Self::Epsilon) -> bool {
+        self.into_iter().zip(other).all(|(l, r)| l.abs_diff_eq(r, epsilon))
+    }
+
+    fn relative_eq(self, other: Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self.into_iter().zip(other).all(|(l, r)| l.relative_eq(r, epsilon, max_relative))
+    }
+
+    fn ulps_eq(self, other: Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.into_iter().zip(other).all(|(l, r)| l.ulps_eq(r, epsilon, max_ulps))
+    }
+}
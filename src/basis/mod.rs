@@ -1,14 +1,211 @@
+use std::marker::PhantomData;
+use crate::scalar::Ring;
+use crate::vector::{PointN, VectorN};
+
 /// Trait for Vector spaces Basis
 ///
 /// Used with the `B` type parameter on [`VectorN`](crate::vector::VectorN) and related types.
 /// This type parameter encodes the Basis of the vector, and rejects operations on vectors of differing bases.
 ///
 /// As a default/"generic"/unknown basis, [`()`] may be used
+///
+/// Concrete bases declare their axes relative to the canonical basis through [`Basis::AXES`], which enables conversion between bases with [`ConvertBasis`].
+/// For 3D, the canonical basis is X right, Y up, Z backward (towards the viewer); This is right-handed, and identical to [`YUpRightHanded`].
+///
+/// ```
+/// use unifiedgeometry::basis::{Basis, Handedness, SignedAxis};
+///
+/// /// X forward, Y left, Z up
+/// #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+/// struct Robotics;
+///
+/// impl Basis<3> for Robotics {
+///     const AXES: Option<[SignedAxis; 3]> = Some([SignedAxis::negative(2), SignedAxis::negative(0), SignedAxis::positive(1)]);
+/// }
+///
+/// assert_eq!(<Robotics as Basis<3>>::HANDEDNESS, Some(Handedness::Right));
+/// ```
 pub trait Basis<const N: usize>: Copy + PartialEq + Eq {
-    // A basis conversion function may be added later, but should probably have a default-no implementation?
+    /// Axes of this basis, expressed as signed axes of the canonical basis; `None` for abstract/unknown bases
+    ///
+    /// Entry `i` is the canonical axis which axis `i` of this basis points along.
+    /// Must be a permutation of the canonical axes; Otherwise the basis is treated as having unknown orientation and cannot be converted.
+    const AXES: Option<[SignedAxis; N]> = None;
 
-    // Maybe handedness?
+    /// Handedness of this basis, derived from [`Basis::AXES`]
+    ///
+    /// Affects [`cross_product`](crate::geometry3d::Vector3D::cross_product), which follows the right-hand rule for every basis of known handedness.
+    /// Rotation matrices and quaternions operate directly on coordinates, and are unaffected.
+    const HANDEDNESS: Option<Handedness> = match Self::AXES {
+        Some(axes) => handedness(&axes),
+        None => None
+    };
 }
 
 /// "Unknown"/default vector basis. In most cases where only 1 geometry context is used, there is no need to explicitly define a basis
 impl<const N: usize> Basis<N> for () {}
+
+/// Orientation of a basis
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Handedness {
+    Right,
+    Left,
+}
+
+/// Axis of the canonical basis, with direction
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SignedAxis {
+    /// Index of the canonical axis
+    pub axis: usize,
+    /// Whether this points along the negative direction of the axis
+    pub negative: bool,
+}
+
+impl SignedAxis {
+    /// Positive direction of canonical axis `axis`
+    #[inline]
+    pub const fn positive(axis: usize) -> Self {
+        SignedAxis { axis, negative: false }
+    }
+
+    /// Negative direction of canonical axis `axis`
+    #[inline]
+    pub const fn negative(axis: usize) -> Self {
+        SignedAxis { axis, negative: true }
+    }
+}
+
+/// Handedness of a signed permutation of the canonical (right-handed) axes
+///
+/// Returns `None` if `axes` is not a permutation
+const fn handedness<const N: usize>(axes: &[SignedAxis; N]) -> Option<Handedness> {
+    // Determinant of a signed permutation matrix is the permutation parity, times the product of the signs
+    let mut odd = false;
+    let mut i = 0;
+    while i < N {
+        if axes[i].axis >= N {
+            return None;
+        }
+        odd ^= axes[i].negative;
+        let mut j = i + 1;
+        while j < N {
+            if axes[i].axis == axes[j].axis {
+                return None;
+            }
+            odd ^= axes[i].axis > axes[j].axis;    // Count inversions
+            j += 1;
+        }
+        i += 1;
+    }
+    Some(if odd { Handedness::Left } else { Handedness::Right })
+}
+
+/// Y up, X right, Z backward (towards the viewer); Right-handed
+///
+/// Used by e.g. OpenGL, glTF and Maya. This is the canonical 3D basis
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct YUpRightHanded;
+
+impl Basis<3> for YUpRightHanded {
+    const AXES: Option<[SignedAxis; 3]> = Some([SignedAxis::positive(0), SignedAxis::positive(1), SignedAxis::positive(2)]);
+}
+
+/// Z up, X right, Y forward (away from the viewer); Right-handed
+///
+/// Used by e.g. Blender and 3ds Max
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ZUpRightHanded;
+
+impl Basis<3> for ZUpRightHanded {
+    const AXES: Option<[SignedAxis; 3]> = Some([SignedAxis::positive(0), SignedAxis::negative(2), SignedAxis::positive(1)]);
+}
+
+/// Y up, X right, Z forward (away from the viewer); Left-handed
+///
+/// Used by e.g. Unity and Direct3D
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct YUpLeftHanded;
+
+impl Basis<3> for YUpLeftHanded {
+    const AXES: Option<[SignedAxis; 3]> = Some([SignedAxis::positive(0), SignedAxis::positive(1), SignedAxis::negative(2)]);
+}
+
+/// Conversion of vectors and points from basis `From` to basis `To`
+///
+/// Both bases must declare their [axes](Basis::AXES); The conversion is then a signed permutation of coordinates, and is exact.
+///
+/// ```
+/// use unifiedgeometry::basis::{ConvertBasis, YUpLeftHanded, ZUpRightHanded};
+/// use unifiedgeometry::geometry3d::Vector3D;
+///
+/// let blender_to_unity = ConvertBasis::<ZUpRightHanded, YUpLeftHanded, 3>::new().unwrap();
+/// let up: Vector3D<f64, ZUpRightHanded> = Vector3D::new([0.0, 0.0, 1.0]);
+/// assert_eq!(blender_to_unity.apply(up), [0.0, 1.0, 0.0]);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ConvertBasis<From, To, const N: usize> {
+    /// For each axis of `To`, the axis of `From` it is read from, and whether it is negated
+    mapping: [SignedAxis; N],
+    bases: PhantomData<(From, To)>
+}
+
+impl<From: Basis<N>, To: Basis<N>, const N: usize> ConvertBasis<From, To, N> {
+    /// Create a new conversion
+    ///
+    /// Returns `None` if either basis does not declare valid [axes](Basis::AXES), such as the unknown basis `()`
+    pub fn new() -> Option<Self> {
+        let (Some(from), Some(to)) = (From::AXES, To::AXES) else {
+            return None;
+        };
+        // Validates both are permutations
+        From::HANDEDNESS?;
+        To::HANDEDNESS?;
+
+        let mut mapping = [SignedAxis::positive(0); N];
+        for (target, to_axis) in mapping.iter_mut().zip(to) {
+            let source = from.iter().position(|from_axis| from_axis.axis == to_axis.axis)?;
+            *target = SignedAxis { axis: source, negative: from[source].negative != to_axis.negative };
+        }
+        Some(ConvertBasis { mapping, bases: PhantomData })
+    }
+
+    /// Conversion in the opposite direction
+    pub fn inverse(self) -> ConvertBasis<To, From, N> {
+        let mut mapping = [SignedAxis::positive(0); N];
+        for (target, source) in self.mapping.into_iter().enumerate() {
+            mapping[source.axis] = SignedAxis { axis: target, negative: source.negative };
+        }
+        ConvertBasis { mapping, bases: PhantomData }
+    }
+
+    /// Returns true if this conversion changes handedness, e.g. right-handed to left-handed
+    pub fn flips_handedness(self) -> bool {
+        From::HANDEDNESS != To::HANDEDNESS
+    }
+
+    /// Conversion matrix, as row-major arrays
+    ///
+    /// The matrix is a signed permutation matrix, and is orthonormal
+    pub fn to_row_major<T: Ring>(self) -> [[T; N]; N] {
+        self.mapping.map(|source| {
+            let mut row = [T::ZERO; N];
+            row[source.axis] = if source.negative { -T::i(1) } else { T::i(1) };
+            row
+        })
+    }
+
+    /// Convert a vector from basis `From` to basis `To`
+    pub fn apply<T: Ring>(self, vector: VectorN<T, N, From>) -> VectorN<T, N, To> {
+        let array = vector.to_array();
+        VectorN::new(self.mapping.map(|source| {
+            if source.negative { -array[source.axis] } else { array[source.axis] }
+        }))
+    }
+
+    /// Convert a point from basis `From` to basis `To`
+    ///
+    /// Bases share their origin, so points convert identically to vectors
+    pub fn apply_point<T: Ring>(self, point: PointN<T, N, From>) -> PointN<T, N, To> {
+        PointN::from_vector(self.apply(point.to_vector()))
+    }
+}
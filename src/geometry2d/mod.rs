@@ -1,5 +1,84 @@
 //! Specialized items for 2D geometry
-use crate::vector::{VectorN};
+use std::ops::Mul;
+use crate::basis::Basis;
+use crate::scalar::Scalar;
+use crate::shapes::aabb::AabbN;
+use crate::vector::{PointN, VectorN};
 
-pub type Point2D<T, B> = VectorN<T, 2, B>;
+pub type Point2D<T, B> = PointN<T, 2, B>;
 pub type Vector2D<T, B> = VectorN<T, 2, B>;
+pub type Aabb2D<T, B> = AabbN<T, 2, B>;
+
+/// 2D rotation
+///
+/// Stored as the cosine and sine of the rotation angle, equivalent to the rotation matrix
+/// ```text
+/// ⎡cos(θ) -sin(θ)⎤
+/// ⎣sin(θ)  cos(θ)⎦
+/// ```
+///
+/// Rotations are composed through the [`Mul`] trait.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rotation2D<T, B: Basis<2>> {
+    cos: T,
+    sin: T,
+    basis: std::marker::PhantomData<B>,
+}
+
+impl<T: Scalar, B: Basis<2>> Rotation2D<T, B> {
+    /// Counter-clockwise rotation of `angle` radians, in a basis where the Y axis is counter-clockwise from the X axis
+    #[inline]
+    pub fn from_angle(angle: T) -> Self {
+        Rotation2D { cos: angle.cos(), sin: angle.sin(), basis: std::marker::PhantomData }
+    }
+
+    /// Identity rotation
+    #[inline]
+    pub fn identity() -> Self {
+        Rotation2D { cos: T::i(1), sin: T::ZERO, basis: std::marker::PhantomData }
+    }
+
+    /// Rotation angle in radians, in the range `[-PI, PI]`
+    #[inline]
+    pub fn angle(self) -> T {
+        T::atan2(self.sin, self.cos)
+    }
+
+    /// Apply this rotation to the specified vector
+    #[inline]
+    pub fn apply(self, vector: Vector2D<T, B>) -> Vector2D<T, B> {
+        let [x, y] = vector.to_array();
+        Vector2D::new([(self.cos * x) - (self.sin * y), (self.sin * x) + (self.cos * y)])
+    }
+
+    /// Inverse rotation
+    #[inline]
+    pub fn inverse(self) -> Self {
+        Rotation2D { cos: self.cos, sin: -self.sin, basis: std::marker::PhantomData }
+    }
+
+    /// Convert this rotation into a row-major 2x2 rotation matrix
+    #[inline]
+    pub fn to_row_major(self) -> [[T; 2]; 2] {
+        [
+            [self.cos, -self.sin],
+            [self.sin, self.cos]
+        ]
+    }
+}
+
+impl<T: Scalar, B: Basis<2>> Mul for Rotation2D<T, B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Rotation2D {
+            cos: (self.cos * rhs.cos) - (self.sin * rhs.sin),
+            sin: (self.sin * rhs.cos) + (self.cos * rhs.sin),
+            basis: std::marker::PhantomData,
+        }
+    }
+}
+
+pub mod shapes;
+pub mod transform;
@@ -0,0 +1,93 @@
+use std::fmt::{Display, Formatter};
+use crate::approx::ApproxEq;
+use crate::basis::Basis;
+use crate::geometry2d::{Aabb2D, Point2D, Vector2D};
+use crate::scalar::Scalar;
+use crate::shapes::aabb::Bounded;
+use crate::shapes::enclosing;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum InvalidCircleError {
+    /// The radius is negative
+    NegativeRadius,
+    /// The center or radius is infinite or NaN
+    NonFinite,
+}
+
+/// Circle, or the disk it encloses where used as an area
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Circle<T, B: Basis<2>> {
+    pub center: Point2D<T, B>,
+    pub radius: T
+}
+
+impl<T: Display, B: Basis<2>> Display for Circle<T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Circle{{{}, radius={}}}", self.center, self.radius)
+    }
+}
+
+impl<T: ApproxEq<Epsilon=T>, B: Basis<2>> ApproxEq for Circle<T, B> {
+    impl_approx_eq_fields!(T; center, radius);
+}
+
+impl<T: Scalar, B: Basis<2>> Circle<T, B> {
+    /// Create a new circle, rejecting negative radii and non-finite values; A radius of zero is valid
+    pub fn new(center: Point2D<T, B>, radius: T) -> Result<Self, InvalidCircleError> {
+        if !center.is_finite() || !radius.is_finite() {
+            Err(InvalidCircleError::NonFinite)
+        } else if radius < T::ZERO {
+            Err(InvalidCircleError::NegativeRadius)
+        } else {
+            Ok(Circle { center, radius })
+        }
+    }
+
+    /// Area of the enclosed disk, `π·r²`
+    #[inline]
+    pub fn area(self) -> T {
+        T::PI * self.radius.powi(2)
+    }
+
+    /// Circumference, `2·π·r`
+    #[inline]
+    pub fn circumference(self) -> T {
+        T::i(2) * T::PI * self.radius
+    }
+
+    /// True if `point` lies inside or on the circle
+    #[inline]
+    pub fn contains(self, point: Point2D<T, B>) -> bool {
+        let offset = point - self.center;
+        offset.dot(offset) <= self.radius.powi(2)
+    }
+
+    /// Smallest circle containing all `points`, through Welzl's randomized algorithm in expected linear time
+    ///
+    /// Uses a fixed seed, so the result is deterministic; See [`Circle::minimum_enclosing_seeded`].
+    /// Returns `None` if there are no points, or any point is not finite
+    #[inline]
+    pub fn minimum_enclosing(points: &[Point2D<T, B>]) -> Option<Self> {
+        Self::minimum_enclosing_seeded(points, enclosing::DEFAULT_SEED)
+    }
+
+    /// Smallest circle containing all `points`, shuffling them with `seed` for Welzl's algorithm
+    ///
+    /// The seed only affects the running time and rounding of the result
+    pub fn minimum_enclosing_seeded(points: &[Point2D<T, B>], seed: u64) -> Option<Self> {
+        enclosing::minimum_enclosing_ball(points, seed).map(|(center, radius)| Circle { center, radius })
+    }
+
+    /// Circle containing all `points` through Ritter's algorithm; Faster than [`Circle::minimum_enclosing`], though typically a few percent larger
+    pub fn approximate_enclosing(points: &[Point2D<T, B>]) -> Option<Self> {
+        enclosing::approximate_enclosing_ball(points).map(|(center, radius)| Circle { center, radius })
+    }
+}
+
+impl<T: Scalar, B: Basis<2>> Bounded<T, 2, B> for Circle<T, B> {
+    #[inline]
+    fn bounding_box(&self) -> Aabb2D<T, B> {
+        let offset = Vector2D::new([self.radius; 2]);
+        Aabb2D::new(self.center - offset, self.center + offset)
+    }
+}
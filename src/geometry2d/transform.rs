@@ -0,0 +1,278 @@
+//! Rigid, similarity and affine transforms for 2D geometry
+//!
+//! Transforms distinguish between points and directions: [`transform_point`](Isometry2D::transform_point) applies the translation, [`transform_vector`](Isometry2D::transform_vector) does not.
+//! Transforms are composed through the [`Mul`] trait; `(a * b).transform_point(p)` is equivalent to `a.transform_point(b.transform_point(p))`
+use std::ops::Mul;
+use crate::basis::Basis;
+use crate::geometry2d::{Aabb2D, Point2D, Rotation2D, Vector2D};
+use crate::matrix::{MatrixMN, MatrixN};
+use crate::scalar::Scalar;
+
+/// Embed a 2x2 linear map and translation into a 3x3 homogeneous matrix
+#[inline]
+fn homogeneous<T: Scalar>(linear: [[T; 2]; 2], translation: [T; 2]) -> MatrixN<T, 3, ()> {
+    let [
+        [r11, r12],
+        [r21, r22]
+    ] = linear;
+    let [x, y] = translation;
+    MatrixMN::from_row_major([
+        [r11, r12, x],
+        [r21, r22, y],
+        [T::ZERO, T::ZERO, T::i(1)]
+    ])
+}
+
+/// Rigid transform; Rotation followed by translation
+///
+/// Preserves lengths and angles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Isometry2D<T, B: Basis<2>> {
+    pub rotation: Rotation2D<T, B>,
+    pub translation: Vector2D<T, B>,
+}
+
+impl<T: Scalar, B: Basis<2>> Isometry2D<T, B> {
+    /// Create a new isometry which first rotates, then translates
+    #[inline]
+    pub fn new(rotation: Rotation2D<T, B>, translation: Vector2D<T, B>) -> Self {
+        Isometry2D { rotation, translation }
+    }
+
+    /// Identity transform
+    #[inline]
+    pub fn identity() -> Self {
+        Isometry2D { rotation: Rotation2D::identity(), translation: Vector2D::new([T::ZERO; 2]) }
+    }
+
+    /// Pure translation
+    #[inline]
+    pub fn from_translation(translation: Vector2D<T, B>) -> Self {
+        Isometry2D { rotation: Rotation2D::identity(), translation }
+    }
+
+    /// Pure rotation around the origin
+    #[inline]
+    pub fn from_rotation(rotation: Rotation2D<T, B>) -> Self {
+        Isometry2D { rotation, translation: Vector2D::new([T::ZERO; 2]) }
+    }
+
+    /// Transform a point; Rotated, then translated
+    #[inline]
+    pub fn transform_point(self, point: Point2D<T, B>) -> Point2D<T, B> {
+        Point2D::from_vector(self.transform_vector(point.to_vector()) + self.translation)
+    }
+
+    /// Transform a direction vector; Rotated only
+    #[inline]
+    pub fn transform_vector(self, vector: Vector2D<T, B>) -> Vector2D<T, B> {
+        self.rotation.apply(vector)
+    }
+
+    /// Inverse transform
+    #[inline]
+    pub fn inverse(self) -> Self {
+        let rotation = self.rotation.inverse();
+        Isometry2D { rotation, translation: -rotation.apply(self.translation) }
+    }
+
+    /// Convert to a 3x3 homogeneous matrix, operating on column vectors `[x, y, 1]` for points and `[x, y, 0]` for directions
+    ///
+    /// Use [`MatrixMN::to_column_major`] for export to graphics APIs which expect column-major matrices
+    #[inline]
+    pub fn to_homogeneous(self) -> MatrixN<T, 3, ()> {
+        homogeneous(self.rotation.to_row_major(), self.translation.to_array())
+    }
+}
+
+impl<T: Scalar, B: Basis<2>> Mul for Isometry2D<T, B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Isometry2D {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_vector(rhs.translation) + self.translation,
+        }
+    }
+}
+
+/// Similarity transform; Uniform scaling, followed by rotation, followed by translation
+///
+/// Preserves angles and ratios of lengths.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Similarity2D<T, B: Basis<2>> {
+    pub scale: T,
+    pub rotation: Rotation2D<T, B>,
+    pub translation: Vector2D<T, B>,
+}
+
+impl<T: Scalar, B: Basis<2>> Similarity2D<T, B> {
+    /// Create a new similarity which first scales, then rotates, then translates
+    #[inline]
+    pub fn new(scale: T, rotation: Rotation2D<T, B>, translation: Vector2D<T, B>) -> Self {
+        Similarity2D { scale, rotation, translation }
+    }
+
+    /// Identity transform
+    #[inline]
+    pub fn identity() -> Self {
+        Similarity2D::from(Isometry2D::identity())
+    }
+
+    /// Pure uniform scaling around the origin
+    #[inline]
+    pub fn from_scale(scale: T) -> Self {
+        Similarity2D { scale, rotation: Rotation2D::identity(), translation: Vector2D::new([T::ZERO; 2]) }
+    }
+
+    /// Transform a point; Scaled, rotated, then translated
+    #[inline]
+    pub fn transform_point(self, point: Point2D<T, B>) -> Point2D<T, B> {
+        Point2D::from_vector(self.transform_vector(point.to_vector()) + self.translation)
+    }
+
+    /// Transform a direction vector; Scaled and rotated only
+    #[inline]
+    pub fn transform_vector(self, vector: Vector2D<T, B>) -> Vector2D<T, B> {
+        self.rotation.apply(vector * self.scale)
+    }
+
+    /// Inverse transform
+    ///
+    /// Returns `None` if the scale is zero
+    #[inline]
+    pub fn inverse(self) -> Option<Self> {
+        if self.scale == T::ZERO || !self.scale.is_finite() {
+            return None;
+        }
+        let scale = T::i(1) / self.scale;
+        let rotation = self.rotation.inverse();
+        Some(Similarity2D { scale, rotation, translation: -rotation.apply(self.translation * scale) })
+    }
+
+    /// Convert to a 3x3 homogeneous matrix, operating on column vectors `[x, y, 1]` for points and `[x, y, 0]` for directions
+    ///
+    /// Use [`MatrixMN::to_column_major`] for export to graphics APIs which expect column-major matrices
+    #[inline]
+    pub fn to_homogeneous(self) -> MatrixN<T, 3, ()> {
+        homogeneous(self.rotation.to_row_major().map(|row| row.map(|value| value * self.scale)), self.translation.to_array())
+    }
+}
+
+impl<T: Scalar, B: Basis<2>> Mul for Similarity2D<T, B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Similarity2D {
+            scale: self.scale * rhs.scale,
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_vector(rhs.translation) + self.translation,
+        }
+    }
+}
+
+impl<T: Scalar, B: Basis<2>> From<Isometry2D<T, B>> for Similarity2D<T, B> {
+    #[inline]
+    fn from(isometry: Isometry2D<T, B>) -> Self {
+        Similarity2D { scale: T::i(1), rotation: isometry.rotation, translation: isometry.translation }
+    }
+}
+
+/// General affine transform; Linear map followed by translation
+///
+/// May include non-uniform scaling, shearing and reflection, which do not preserve angles or lengths.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Affine2D<T, B: Basis<2>> {
+    pub linear: MatrixN<T, 2, B>,
+    pub translation: Vector2D<T, B>,
+}
+
+impl<T: Scalar, B: Basis<2>> Affine2D<T, B> {
+    /// Create a new affine transform which first applies the linear map, then translates
+    #[inline]
+    pub fn new(linear: MatrixN<T, 2, B>, translation: Vector2D<T, B>) -> Self {
+        Affine2D { linear, translation }
+    }
+
+    /// Identity transform
+    #[inline]
+    pub fn identity() -> Self {
+        Affine2D { linear: MatrixN::identity(), translation: Vector2D::new([T::ZERO; 2]) }
+    }
+
+    /// Non-uniform scaling along each axis
+    #[inline]
+    pub fn from_scale(scale: Vector2D<T, B>) -> Self {
+        let [x, y] = scale.to_array();
+        Affine2D {
+            linear: MatrixMN::from_row_major([
+                [x, T::ZERO],
+                [T::ZERO, y]
+            ]),
+            translation: Vector2D::new([T::ZERO; 2]),
+        }
+    }
+
+    /// Transform a point; Linear map, then translated
+    #[inline]
+    pub fn transform_point(self, point: Point2D<T, B>) -> Point2D<T, B> {
+        Point2D::from_vector(self.transform_vector(point.to_vector()) + self.translation)
+    }
+
+    /// Transform a direction vector; Linear map only
+    #[inline]
+    pub fn transform_vector(self, vector: Vector2D<T, B>) -> Vector2D<T, B> {
+        self.linear * vector
+    }
+
+    /// Smallest axis-aligned box containing `aabb` after transforming it
+    #[inline]
+    pub fn transform_aabb(self, aabb: Aabb2D<T, B>) -> Aabb2D<T, B> {
+        aabb.transform(self.linear, self.translation)
+    }
+
+    /// Inverse transform
+    ///
+    /// Returns `None` if the linear map is singular
+    #[inline]
+    pub fn inverse(self) -> Option<Self> {
+        let linear = self.linear.inverse()?;
+        Some(Affine2D { linear, translation: -(linear * self.translation) })
+    }
+
+    /// Convert to a 3x3 homogeneous matrix, operating on column vectors `[x, y, 1]` for points and `[x, y, 0]` for directions
+    ///
+    /// Use [`MatrixMN::to_column_major`] for export to graphics APIs which expect column-major matrices
+    #[inline]
+    pub fn to_homogeneous(self) -> MatrixN<T, 3, ()> {
+        homogeneous(self.linear.to_row_major(), self.translation.to_array())
+    }
+}
+
+impl<T: Scalar, B: Basis<2>> Mul for Affine2D<T, B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Affine2D {
+            linear: self.linear * rhs.linear,
+            translation: self.transform_vector(rhs.translation) + self.translation,
+        }
+    }
+}
+
+impl<T: Scalar, B: Basis<2>> From<Isometry2D<T, B>> for Affine2D<T, B> {
+    #[inline]
+    fn from(isometry: Isometry2D<T, B>) -> Self {
+        Affine2D { linear: MatrixMN::from_row_major(isometry.rotation.to_row_major()), translation: isometry.translation }
+    }
+}
+
+impl<T: Scalar, B: Basis<2>> From<Similarity2D<T, B>> for Affine2D<T, B> {
+    #[inline]
+    fn from(similarity: Similarity2D<T, B>) -> Self {
+        Affine2D { linear: MatrixMN::from_row_major(similarity.rotation.to_row_major()) * similarity.scale, translation: similarity.translation }
+    }
+}
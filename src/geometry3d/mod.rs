@@ -1,22 +1,37 @@
 //! Specialized items for 3D geometry
-use std::ops::{Add, Mul, Sub};
-use crate::basis::Basis;
+use std::ops::{Add, Mul, Neg, Sub};
+use crate::approx::ApproxEq;
+use crate::basis::{Basis, ConvertBasis, Handedness};
 use crate::scalar::Scalar;
-use crate::vector::VectorN;
+use crate::shapes::aabb::AabbN;
+use crate::vector::{PointN, VectorN};
 
-pub type Point3D<T, B> = VectorN<T, 3, B>;
+pub type Point3D<T, B> = PointN<T, 3, B>;
 pub type Vector3D<T, B> = VectorN<T, 3, B>;
+pub type Aabb3D<T, B> = AabbN<T, 3, B>;
 
 // Generic bound could be widened to Clone if support for "BigDecimal" types is needed
 impl<T: Sub<Output=T> + Mul<Output=T> + Copy, B: Basis<3>> Vector3D<T, B> {
     /// Calculates vector cross product `self × rhs`
     ///
+    /// The result follows the right-hand rule for bases of known [handedness](Basis::HANDEDNESS);
+    /// In left-handed bases this is the negation of the coordinate formula, such that cross products are preserved by [basis conversion](crate::basis::ConvertBasis).
+    /// Bases of unknown handedness, such as `()`, use the coordinate formula.
+    ///
     /// # Arguments
     ///
     /// * `rhs`: Right hand side
     ///
     /// returns: VectorN<T, 3>
     pub fn cross_product(self, rhs: Self) -> Self {
+        match B::HANDEDNESS {
+            Some(Handedness::Left) => rhs.coordinate_cross_product(self),   // a × b = -(b × a)
+            _ => self.coordinate_cross_product(rhs)
+        }
+    }
+
+    /// Cross product computed directly on coordinates, regardless of handedness
+    pub(crate) fn coordinate_cross_product(self, rhs: Self) -> Self {
         let [r_1, r_2, r_3] = self.to_array();
         let [l_1, l_2, l_3] = rhs.to_array();
         Vector3D::new([
@@ -73,12 +88,12 @@ impl<T, B: Basis<3>> RotationMatrix<T, B> {
     /// ```
     /// use unifiedgeometry::geometry3d::RotationMatrix;
     ///
-    /// let x;
-    /// RotationMatrix::from_row_major([
+    /// let x: f64 = 0.5;
+    /// let matrix: RotationMatrix<f64, ()> = RotationMatrix::from_row_major([
     ///     [1.0, 0.0, 0.0],
     ///     [0.0, x.cos(), -x.sin()],
     ///     [0.0, x.sin(), x.cos()]
-    /// ])
+    /// ]);
     /// ```
     /// Results in
     /// ```text
@@ -101,13 +116,9 @@ impl<T, B: Basis<3>> RotationMatrix<T, B> {
         ])
     }
 
-    /// Construct a new rotation matrix from a row-major set of 3x3 arrays
+    /// Convert this rotation matrix into a row-major set of 3x3 arrays
     ///
-    /// # Arguments
-    ///
-    /// * `matrix`: Matrix data
-    ///
-    /// returns: RotMatrix<T>
+    /// Acts as inverse to [`RotationMatrix::from_row_major`]
     ///
     /// # Examples
     /// Given `matrix`
@@ -118,7 +129,9 @@ impl<T, B: Basis<3>> RotationMatrix<T, B> {
     /// ```
     ///
     /// ```
-    /// let (matrix, x);
+    /// # use unifiedgeometry::geometry3d::RotationMatrix;
+    /// # let x: f64 = 0.5;
+    /// # let matrix: RotationMatrix<f64, ()> = RotationMatrix::from_row_major([[1.0, 0.0, 0.0], [0.0, x.cos(), -x.sin()], [0.0, x.sin(), x.cos()]]);
     /// let [
     ///     [r11, r12, r13],
     ///     [r21, r22, r23],
@@ -151,6 +164,23 @@ impl<T, B: Basis<3>> RotationMatrix<T, B> {
             [r31, r32, r33]
         ]
     }
+
+    /// Transpose of this matrix
+    ///
+    /// For a valid rotation matrix, this is the inverse rotation; See [`RotationMatrix::inverse`]
+    #[inline]
+    pub fn transpose(self) -> RotationMatrix<T, B> {
+        let [x, y, z] = self.0;
+        RotationMatrix::from_row_major([x.array, y.array, z.array])   // Column-major vectors read as rows
+    }
+
+    /// Inverse rotation, equal to the [transpose](RotationMatrix::transpose) as rotation matrices are orthonormal
+    ///
+    /// Matrices which have drifted from orthonormality may be corrected with [`RotationMatrix::orthonormalize_polar`] or [`RotationMatrix::orthonormalize_gram_schmidt`] first
+    #[inline]
+    pub fn inverse(self) -> RotationMatrix<T, B> {
+        self.transpose()
+    }
 }
 
 impl<T: Scalar, B: Basis<3>> RotationMatrix<T, B> {
@@ -175,6 +205,326 @@ impl<T: Scalar, B: Basis<3>> RotationMatrix<T, B> {
     }
 }
 
+/// Axis order for Euler angles, used by [`RotationMatrix::from_euler`] and [`RotationMatrix::to_euler`]
+///
+/// An order `XYZ` with angles `(a, b, c)` describes the rotation `Rx(a) · Ry(b) · Rz(c)`;
+/// Intrinsic rotations about X, then the rotated Y, then the twice-rotated Z axis. This is equivalent to extrinsic rotations about the fixed axes in reverse order (Z, then Y, then X)
+///
+/// The first six orders are Tait–Bryan angles (three distinct axes), the last six are proper Euler angles (first and last axis equal)
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerOrder {
+    /// All 12 Euler orders
+    pub const ALL: [EulerOrder; 12] = [
+        EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ, EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX,
+        EulerOrder::XYX, EulerOrder::XZX, EulerOrder::YXY, EulerOrder::YZY, EulerOrder::ZXZ, EulerOrder::ZYZ,
+    ];
+
+    /// Axis indices (0 = X, 1 = Y, 2 = Z) of the first, second, and third rotation
+    #[inline]
+    pub fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// True for proper Euler angles, false for Tait–Bryan angles
+    #[inline]
+    pub fn is_proper(self) -> bool {
+        let [first, _, third] = self.axes();
+        first == third
+    }
+}
+
+/// Row-major matrix product `lhs · rhs`
+#[inline]
+fn row_major_product<T: Scalar>(lhs: [[T; 3]; 3], rhs: [[T; 3]; 3]) -> [[T; 3]; 3] {
+    std::array::from_fn(|row| std::array::from_fn(|column| {
+        (lhs[row][0] * rhs[0][column]) + (lhs[row][1] * rhs[1][column]) + (lhs[row][2] * rhs[2][column])
+    }))
+}
+
+/// Row-major matrix for a rotation of `angle` around the X (0), Y (1), or Z (2) axis
+#[inline]
+fn elementary_rotation<T: Scalar>(axis: usize, angle: T) -> [[T; 3]; 3] {
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let (p, q) = ((axis + 1) % 3, (axis + 2) % 3);
+    let mut matrix = [[T::ZERO; 3]; 3];
+    matrix[axis][axis] = T::i(1);
+    matrix[p][p] = cos;
+    matrix[p][q] = -sin;
+    matrix[q][p] = sin;
+    matrix[q][q] = cos;
+    matrix
+}
+
+/// Squared length below which a pair of matrix elements is considered to be zero when decomposing Euler angles
+#[inline]
+fn gimbal_lock_threshold<T: Scalar>() -> T {
+    T::f(1e-20)
+}
+
+impl<T: Scalar, B: Basis<3>> RotationMatrix<T, B> {
+    /// Identity rotation
+    #[inline]
+    pub fn identity() -> Self {
+        RotationMatrix::from_row_major([
+            [T::i(1), T::ZERO, T::ZERO],
+            [T::ZERO, T::i(1), T::ZERO],
+            [T::ZERO, T::ZERO, T::i(1)]
+        ])
+    }
+
+    /// Rotation of `angle` radians around `axis`, counter-clockwise when looking down the axis towards the origin in a right-handed basis
+    ///
+    /// `axis` does not need to be of unit length, but must not be zero length
+    ///
+    /// # Examples
+    /// ```
+    /// use unifiedgeometry::geometry3d::{RotationMatrix, Vector3D};
+    ///
+    /// let rotation: RotationMatrix<f64, ()> = RotationMatrix::from_axis_angle(Vector3D::new([0.0, 0.0, 1.0]), std::f64::consts::FRAC_PI_2);
+    /// let [x, y, z] = rotation.apply(Vector3D::new([1.0, 0.0, 0.0])).to_array();
+    /// assert!(x.abs() < 1e-15 && (y - 1.0).abs() < 1e-15 && z == 0.0);
+    /// ```
+    pub fn from_axis_angle(axis: Vector3D<T, B>, angle: T) -> Self {
+        let [x, y, z] = axis.with_unit_length().to_array();
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let t = T::i(1) - cos;
+
+        // Rodrigues' rotation formula
+        RotationMatrix::from_row_major([
+            [cos + x * x * t, x * y * t - z * sin, x * z * t + y * sin],
+            [x * y * t + z * sin, cos + y * y * t, y * z * t - x * sin],
+            [x * z * t - y * sin, y * z * t + x * sin, cos + z * z * t]
+        ])
+    }
+
+    /// Decompose this rotation into an axis of unit length and an angle in radians within `[0, PI]`
+    ///
+    /// Acts as inverse to [`RotationMatrix::from_axis_angle`]. For the identity rotation the axis is undefined, and the X axis is returned.
+    pub fn to_axis_angle(self) -> (Vector3D<T, B>, T) {
+        // Going through the quaternion representation avoids the loss of precision of the trace-based angle near 0 and PI
+        let quaternion = Quaternion::from_rotation_matrix(self);
+        let sin_half = quaternion.vector().magnitude();
+        if sin_half == T::ZERO {
+            (Vector3D::new([T::i(1), T::ZERO, T::ZERO]), T::ZERO)
+        } else {
+            (quaternion.vector() / sin_half, T::i(2) * T::atan2(sin_half, quaternion.w()))
+        }
+    }
+
+    /// Rotation from Euler angles `a`, `b`, `c` (in radians) applied in the specified axis order
+    ///
+    /// See [`EulerOrder`] for the convention used
+    pub fn from_euler(order: EulerOrder, a: T, b: T, c: T) -> Self {
+        let [first, second, third] = order.axes();
+        RotationMatrix::from_row_major(row_major_product(
+            row_major_product(elementary_rotation(first, a), elementary_rotation(second, b)),
+            elementary_rotation(third, c)
+        ))
+    }
+
+    /// Decompose this rotation into Euler angles `(a, b, c)` in radians for the specified axis order
+    ///
+    /// Acts as inverse to [`RotationMatrix::from_euler`]. `a` and `c` are in the range `[-PI, PI]`; `b` is in the range `[-PI/2, PI/2]` for Tait–Bryan orders and `[0, PI]` for proper Euler orders.
+    ///
+    /// In gimbal lock (`b` is ±PI/2 for Tait–Bryan orders, or 0 or PI for proper Euler orders) the first and third axes coincide and the decomposition is not unique;
+    /// `a` is then set to zero and `c` holds the combined rotation.
+    pub fn to_euler(self, order: EulerOrder) -> (T, T, T) {
+        let m = self.to_row_major();
+        let [i, j, _] = order.axes();
+        let k = 3 - i - j;  // Axis not used by the second rotation
+        let parity = if (j + 3 - i) % 3 == 1 { T::i(1) } else { T::i(-1) };   // Sign of permutation (i, j, k)
+
+        // Determine the first angle, then remove it from the matrix so the remaining angles can be read from the residual rotation
+        // This remains stable near gimbal lock, where the first and third angles cannot be separated
+        let (a_sin, a_cos) = if order.is_proper() {
+            (m[j][i], -parity * m[k][i])
+        } else {
+            (-parity * m[j][k], m[k][k])
+        };
+        let a = if (a_sin * a_sin) + (a_cos * a_cos) <= gimbal_lock_threshold() {
+            T::ZERO
+        } else {
+            T::atan2(a_sin, a_cos)
+        };
+
+        let residual = row_major_product(elementary_rotation(i, -a), m);
+        if order.is_proper() {
+            let b = T::atan2(-parity * residual[k][i], residual[i][i]);
+            let c = T::atan2(-parity * residual[j][k], residual[j][j]);
+            (a, b, c)
+        } else {
+            let b = T::atan2(parity * residual[i][k], residual[k][k]);
+            let c = T::atan2(parity * residual[j][i], residual[j][j]);
+            (a, b, c)
+        }
+    }
+
+    /// Shortest-arc rotation which rotates the direction of `from` onto the direction of `to`
+    ///
+    /// Where `from` and `to` point in opposite directions, a half-turn around an arbitrary perpendicular axis is returned.
+    ///
+    /// Returns `None` if either vector is zero length or not finite
+    pub fn rotation_between(from: Vector3D<T, B>, to: Vector3D<T, B>) -> Option<Self> {
+        let from_length = from.magnitude();
+        let to_length = to.magnitude();
+        if from_length == T::ZERO || to_length == T::ZERO || !from_length.is_finite() || !to_length.is_finite() {
+            return None;
+        }
+        let from = from / from_length;
+        let to = to / to_length;
+
+        let half = from + to;
+        let half_length = half.magnitude();
+        // Both vectors are unit length, so `half` only vanishes into rounding error for exactly opposite directions;
+        // Nearly opposite directions are still handled accurately below, and must not be snapped to a half-turn
+        if T::i(1) + half_length / T::i(8) == T::i(1) {
+            // Opposite directions; Rotate around the axis least aligned with `from`
+            let [x, y, z] = from.to_array();
+            let (x2, y2, z2) = (x * x, y * y, z * z);
+            let least_aligned = if x2 <= y2 && x2 <= z2 {
+                Vector3D::new([T::i(1), T::ZERO, T::ZERO])
+            } else if y2 <= z2 {
+                Vector3D::new([T::ZERO, T::i(1), T::ZERO])
+            } else {
+                Vector3D::new([T::ZERO, T::ZERO, T::i(1)])
+            };
+            Some(RotationMatrix::from_axis_angle(from.coordinate_cross_product(least_aligned), T::PI))
+        } else {
+            // The quaternion for a rotation of twice the angle between `from` and `half` is (from · half, from × half)
+            let half = half / half_length;
+            Some(Quaternion::from_scalar_vector(from.dot(half), from.coordinate_cross_product(half)).to_rotation_matrix())
+        }
+    }
+
+    /// Rotation which maps the Z axis onto `forward`, and the Y axis onto the direction of `up` projected perpendicular to `forward`
+    ///
+    /// The columns of the resulting matrix are the "right", "up" and "forward" directions. For view matrices looking down the negative Z axis, pass the negated view direction as `forward`.
+    /// The transpose of this matrix transforms from world space into the look-at space.
+    ///
+    /// Returns `None` if either vector is zero length, or if `forward` and `up` are parallel
+    pub fn look_at(forward: Vector3D<T, B>, up: Vector3D<T, B>) -> Option<Self> {
+        let forward_length = forward.magnitude();
+        if forward_length == T::ZERO || !forward_length.is_finite() {
+            return None;
+        }
+        let z_axis = forward / forward_length;
+
+        let right = up.coordinate_cross_product(z_axis);
+        let right_length = right.magnitude();
+        if right_length == T::ZERO || !right_length.is_finite() {
+            return None;
+        }
+        let x_axis = right / right_length;
+        let y_axis = z_axis.coordinate_cross_product(x_axis);
+
+        Some(RotationMatrix([x_axis, y_axis, z_axis]))
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> RotationMatrix<T, B> {
+    /// Determinant of this matrix; 1 for a valid rotation matrix, -1 for a matrix which includes a reflection
+    #[inline]
+    pub fn determinant(self) -> T {
+        let [x, y, z] = self.0;
+        x.dot(y.coordinate_cross_product(z))
+    }
+
+    /// Returns true if this matrix is orthonormal with a determinant of +1 (i.e. a proper rotation without reflection), within the specified tolerance
+    ///
+    /// Each column must have unit length, and the columns must be mutually perpendicular.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance`: Maximum absolute deviation of each column dot product and the determinant from their exact values
+    ///
+    /// returns: bool
+    pub fn is_orthonormal(self, tolerance: T) -> bool {
+        let within_tolerance = |value: T, expected: T| {
+            let difference = value - expected;
+            difference <= tolerance && difference >= -tolerance
+        };
+
+        let [x, y, z] = self.0;
+        within_tolerance(x.dot(x), T::i(1))
+            && within_tolerance(y.dot(y), T::i(1))
+            && within_tolerance(z.dot(z), T::i(1))
+            && within_tolerance(x.dot(y), T::ZERO)
+            && within_tolerance(y.dot(z), T::ZERO)
+            && within_tolerance(z.dot(x), T::ZERO)
+            && within_tolerance(self.determinant(), T::i(1))
+    }
+
+    /// Re-orthonormalize this matrix through the Gram–Schmidt process
+    ///
+    /// The direction of the first column (the rotated X axis) is kept exactly, and error is pushed into the other axes.
+    /// This is cheap, but biased; [`RotationMatrix::orthonormalize_polar`] yields the nearest rotation matrix instead.
+    ///
+    /// The first two columns must not be parallel or zero length.
+    pub fn orthonormalize_gram_schmidt(self) -> RotationMatrix<T, B> {
+        let [x, y, _] = self.0;
+        let x = x.with_unit_length();
+        let y = (y - (x * x.dot(y))).with_unit_length();
+        let z = x.coordinate_cross_product(y);     // Constructing the last axis from the cross product ensures a right-handed result
+        RotationMatrix([x, y, z])
+    }
+
+    /// Re-orthonormalize this matrix by polar decomposition, yielding the nearest (in the Frobenius norm) orthonormal matrix
+    ///
+    /// Computed with the Newton iteration `M = (M + M⁻ᵀ) / 2`, which converges quadratically for matrices that are close to orthonormal.
+    ///
+    /// The matrix must have a positive determinant.
+    pub fn orthonormalize_polar(self) -> RotationMatrix<T, B> {
+        const MAX_ITERATIONS: usize = 16;
+
+        let half = T::f(0.5);
+        let mut matrix = self;
+        for _ in 0..MAX_ITERATIONS {
+            let [x, y, z] = matrix.0;
+            let determinant = x.dot(y.coordinate_cross_product(z));
+            // Columns of the inverse transpose are the cross products of the other two columns, divided by the determinant
+            let inverse_transpose = [y.coordinate_cross_product(z), z.coordinate_cross_product(x), x.coordinate_cross_product(y)].map(|column| column / determinant);
+            let next = RotationMatrix([
+                (x + inverse_transpose[0]) * half,
+                (y + inverse_transpose[1]) * half,
+                (z + inverse_transpose[2]) * half,
+            ]);
+            if next == matrix {
+                break;
+            }
+            matrix = next;
+        }
+        matrix
+    }
+}
+
 impl<T: Add<Output=T> + Mul<Output=T> + Copy, B: Basis<3>> Mul for RotationMatrix<T, B> {
     type Output = RotationMatrix<T, B>;
 
@@ -191,13 +541,247 @@ impl<T: Add<Output=T> + Mul<Output=T> + Copy, B: Basis<3>> Mul for RotationMatri
         [b31, b32, b33]
         ] = rhs.to_row_major();
 
-        RotationMatrix([
-            Vector3D::new([a11 * b11 + a12 * b21 + a13 * b31, a11 * b12 + a12 * b22 + a13 * b32, a11 * b13 + a12 * b23 + a13 * b33]),
-            Vector3D::new([a21 * b11 + a22 * b21 + a23 * b31, a21 * b12 + a22 * b22 + a23 * b32, a21 * b13 + a22 * b23 + a23 * b33]),
-            Vector3D::new([a31 * b11 + a32 * b21 + a23 * b31, a31 * b12 + a32 * b22 + a33 * b32, a31 * b13 + a32 * b23 + a33 * b33])
+        RotationMatrix::from_row_major([
+            [a11 * b11 + a12 * b21 + a13 * b31, a11 * b12 + a12 * b22 + a13 * b32, a11 * b13 + a12 * b23 + a13 * b33],
+            [a21 * b11 + a22 * b21 + a23 * b31, a21 * b12 + a22 * b22 + a23 * b32, a21 * b13 + a22 * b23 + a23 * b33],
+            [a31 * b11 + a32 * b21 + a33 * b31, a31 * b12 + a32 * b22 + a33 * b32, a31 * b13 + a32 * b23 + a33 * b33]
+        ])
+    }
+}
+
+impl<T: ApproxEq<Epsilon=T>, B: Basis<3>> ApproxEq for RotationMatrix<T, B> {
+    impl_approx_eq_fields!(T; 0);
+}
+
+impl<From: Basis<3>, To: Basis<3>> ConvertBasis<From, To, 3> {
+    /// Convert a rotation from basis `From` to basis `To`
+    ///
+    /// The result describes the same physical rotation, such that `convert.apply(rotation.apply(v))` equals `convert.apply_rotation(rotation).apply(convert.apply(v))`
+    pub fn apply_rotation<T: Scalar>(self, rotation: RotationMatrix<T, From>) -> RotationMatrix<T, To> {
+        let inverse = self.inverse();
+        // Columns of P · R · Pᵀ are the images of the axes of `To`
+        RotationMatrix([0, 1, 2].map(|axis| {
+            let mut unit = [T::ZERO; 3];
+            unit[axis] = T::i(1);
+            self.apply(rotation.apply(inverse.apply(Vector3D::new(unit))))
+        }))
+    }
+}
+
+/// Quaternion, used to represent 3D rotations
+///
+/// Stored as a scalar part `w` and vector part `(x, y, z)`, such that `q = w + xi + yj + zk`.
+/// Only quaternions of unit length represent rotations; [`Quaternion::with_unit_length`] may be used to correct accumulated error after repeated composition.
+///
+/// Quaternions are composed with the Hamilton product through the [`Mul`] trait; `(q1 * q2).apply(v)` is equivalent to `q1.apply(q2.apply(v))`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quaternion<T, B: Basis<3>> {
+    w: T,
+    vector: Vector3D<T, B>,
+}
+
+impl<T, B: Basis<3>> Quaternion<T, B> {
+    /// Create a new quaternion `w + xi + yj + zk` from its components
+    #[inline]
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion { w, vector: Vector3D::new([x, y, z]) }
+    }
+
+    /// Create a new quaternion from a scalar part and a vector part
+    #[inline]
+    pub fn from_scalar_vector(w: T, vector: Vector3D<T, B>) -> Self {
+        Quaternion { w, vector }
+    }
+
+    /// "Unwraps" this quaternion, yielding an array of `[w, x, y, z]`
+    ///
+    /// Acts as inverse to [`Quaternion::new`]
+    #[inline]
+    pub fn to_array(self) -> [T; 4] {
+        let [x, y, z] = self.vector.to_array();
+        [self.w, x, y, z]
+    }
+}
+
+impl<T: Copy, B: Basis<3>> Quaternion<T, B> {
+    /// Scalar (real) part of this quaternion
+    #[inline]
+    pub fn w(self) -> T {
+        self.w
+    }
+
+    /// Vector (imaginary) part of this quaternion
+    #[inline]
+    pub fn vector(self) -> Vector3D<T, B> {
+        self.vector
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> Quaternion<T, B> {
+    /// Identity rotation
+    #[inline]
+    pub fn identity() -> Self {
+        Quaternion::new(T::i(1), T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    /// Rotation of `angle` radians around `axis`, counter-clockwise when looking down the axis towards the origin in a right-handed basis
+    ///
+    /// `axis` does not need to be of unit length, but must not be zero length
+    pub fn from_axis_angle(axis: Vector3D<T, B>, angle: T) -> Self {
+        let half_angle = angle / T::i(2);
+        Quaternion { w: half_angle.cos(), vector: axis.with_length(half_angle.sin()) }
+    }
+
+    /// Conjugate `w - xi - yj - zk`
+    ///
+    /// For unit quaternions, this is the inverse rotation
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Quaternion { w: self.w, vector: -self.vector }
+    }
+
+    /// Squared norm of this quaternion
+    #[inline]
+    pub fn norm_squared(self) -> T {
+        self.w.powi(2) + self.vector.dot(self.vector)
+    }
+
+    /// Norm/length of this quaternion
+    #[inline]
+    pub fn norm(self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    /// Multiplicative inverse, such that `q * q.inverse()` is the identity
+    ///
+    /// Returns `None` for the zero quaternion
+    pub fn inverse(self) -> Option<Self> {
+        let norm_squared = self.norm_squared();
+        if norm_squared == T::ZERO || !norm_squared.is_finite() {
+            None
+        } else {
+            let conjugate = self.conjugate();
+            Some(Quaternion { w: conjugate.w / norm_squared, vector: conjugate.vector / norm_squared })
+        }
+    }
+
+    /// Returns quaternion with unit (1) norm
+    #[inline]
+    pub fn with_unit_length(self) -> Self {
+        let norm = self.norm();
+        Quaternion { w: self.w / norm, vector: self.vector / norm }
+    }
+
+    /// Calculates the 4D dot product between this and another quaternion
+    #[inline]
+    pub fn dot(self, rhs: Self) -> T {
+        self.w * rhs.w + self.vector.dot(rhs.vector)
+    }
+
+    /// Apply this rotation to the specified vector
+    ///
+    /// This quaternion must be of unit length
+    ///
+    /// # Arguments
+    ///
+    /// * `vector`: Vector to rotate
+    ///
+    /// returns: VectorN<T, 3>
+    pub fn apply(self, vector: Vector3D<T, B>) -> Vector3D<T, B> {
+        // Expanded form of `q * v * q⁻¹`: v + 2w(q × v) + 2(q × (q × v))
+        let t = self.vector.coordinate_cross_product(vector) * T::i(2);
+        vector + (t * self.w) + self.vector.coordinate_cross_product(t)
+    }
+
+    /// Convert this quaternion into an equivalent rotation matrix
+    ///
+    /// This quaternion must be of unit length
+    pub fn to_rotation_matrix(self) -> RotationMatrix<T, B> {
+        let Quaternion { w, vector: Vector3D { array: [x, y, z], .. } } = self;
+        let one = T::i(1);
+        let two = T::i(2);
+
+        RotationMatrix::from_row_major([
+            [one - two * (y * y + z * z), two * (x * y - w * z), two * (x * z + w * y)],
+            [two * (x * y + w * z), one - two * (x * x + z * z), two * (y * z - w * x)],
+            [two * (x * z - w * y), two * (y * z + w * x), one - two * (x * x + y * y)]
         ])
     }
+
+    /// Convert a rotation matrix into an equivalent unit quaternion
+    ///
+    /// Uses Shepperd's method; The largest of the diagonal and trace is used as divisor to avoid loss of precision.
+    /// Returned quaternions always have a non-negative `w` component where the rotation matrix is valid.
+    pub fn from_rotation_matrix(matrix: RotationMatrix<T, B>) -> Self {
+        let [
+            [r11, r12, r13],
+            [r21, r22, r23],
+            [r31, r32, r33]
+        ] = matrix.to_row_major();
+        let one = T::i(1);
+        let four = T::i(4);
+        let trace = r11 + r22 + r33;
+
+        let quaternion = if trace >= r11 && trace >= r22 && trace >= r33 {
+            let w = (one + trace).sqrt() / T::i(2);
+            Quaternion::new(w, (r32 - r23) / (four * w), (r13 - r31) / (four * w), (r21 - r12) / (four * w))
+        } else if r11 >= r22 && r11 >= r33 {
+            let x = (one + r11 - r22 - r33).sqrt() / T::i(2);
+            Quaternion::new((r32 - r23) / (four * x), x, (r12 + r21) / (four * x), (r13 + r31) / (four * x))
+        } else if r22 >= r33 {
+            let y = (one - r11 + r22 - r33).sqrt() / T::i(2);
+            Quaternion::new((r13 - r31) / (four * y), (r12 + r21) / (four * y), y, (r23 + r32) / (four * y))
+        } else {
+            let z = (one - r11 - r22 + r33).sqrt() / T::i(2);
+            Quaternion::new((r21 - r12) / (four * z), (r13 + r31) / (four * z), (r23 + r32) / (four * z), z)
+        };
+
+        // q and -q represent the same rotation; Pick the one with positive w for consistency
+        if quaternion.w < T::ZERO {
+            -quaternion
+        } else {
+            quaternion
+        }
+    }
+}
+
+impl<T: ApproxEq<Epsilon=T>, B: Basis<3>> ApproxEq for Quaternion<T, B> {
+    impl_approx_eq_fields!(T; w, vector);
+}
+
+impl<T: Scalar, B: Basis<3>> Neg for Quaternion<T, B> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Quaternion { w: -self.w, vector: -self.vector }
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> Mul for Quaternion<T, B> {
+    type Output = Self;
+
+    /// Hamilton product
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quaternion {
+            w: (self.w * rhs.w) - self.vector.dot(rhs.vector),
+            vector: (rhs.vector * self.w) + (self.vector * rhs.w) + self.vector.coordinate_cross_product(rhs.vector),
+        }
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> From<RotationMatrix<T, B>> for Quaternion<T, B> {
+    fn from(matrix: RotationMatrix<T, B>) -> Self {
+        Quaternion::from_rotation_matrix(matrix)
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> From<Quaternion<T, B>> for RotationMatrix<T, B> {
+    fn from(quaternion: Quaternion<T, B>) -> Self {
+        quaternion.to_rotation_matrix()
+    }
 }
 
 pub mod shapes;
+pub mod transform;
 
@@ -1,7 +1,22 @@
 use std::fmt::{Display, Formatter};
+use crate::approx::ApproxEq;
 use crate::basis::Basis;
-use crate::geometry3d::Point3D;
+use crate::geometry3d::{Aabb3D, Point3D, RotationMatrix, Vector3D};
+use crate::matrix::{MatrixMN, MatrixN};
+use crate::scalar::Scalar;
+use crate::shapes::aabb::Bounded;
+use crate::shapes::enclosing;
+use crate::shapes::triangle::Triangle;
 
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum InvalidSphereError {
+    /// The radius is negative
+    NegativeRadius,
+    /// The center or radius is infinite or NaN
+    NonFinite,
+}
+
+/// Sphere, or the ball it encloses where used as a volume
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Sphere<T, B: Basis<3>> {
     pub center: Point3D<T, B>,
@@ -13,3 +28,699 @@ impl<T: Display, B: Basis<3>> Display for Sphere<T, B> {
         write!(f, "Sphere{{{}, radius={}}}", self.center, self.radius)
     }
 }
+
+impl<T: ApproxEq<Epsilon=T>, B: Basis<3>> ApproxEq for Sphere<T, B> {
+    impl_approx_eq_fields!(T; center, radius);
+}
+
+impl<T: Scalar, B: Basis<3>> Sphere<T, B> {
+    /// Create a new sphere, rejecting negative radii and non-finite values; A radius of zero is valid
+    pub fn new(center: Point3D<T, B>, radius: T) -> Result<Self, InvalidSphereError> {
+        if !center.is_finite() || !radius.is_finite() {
+            Err(InvalidSphereError::NonFinite)
+        } else if radius < T::ZERO {
+            Err(InvalidSphereError::NegativeRadius)
+        } else {
+            Ok(Sphere { center, radius })
+        }
+    }
+
+    /// Volume of the enclosed ball, `4/3·π·r³`
+    #[inline]
+    pub fn volume(self) -> T {
+        T::i(4) / T::i(3) * T::PI * self.radius.powi(3)
+    }
+
+    /// Surface area, `4·π·r²`
+    #[inline]
+    pub fn surface_area(self) -> T {
+        T::i(4) * T::PI * self.radius.powi(2)
+    }
+
+    /// True if `point` lies inside or on the sphere
+    #[inline]
+    pub fn contains(self, point: Point3D<T, B>) -> bool {
+        let offset = point - self.center;
+        offset.dot(offset) <= self.radius.powi(2)
+    }
+
+    /// Signed distance from the surface to `point`; Negative inside the sphere
+    #[inline]
+    pub fn distance_to(self, point: Point3D<T, B>) -> T {
+        (point - self.center).magnitude() - self.radius
+    }
+
+    /// Point on the surface closest to `point`
+    ///
+    /// Every point on the surface is equally close to the center; The point along the positive first axis is returned for it
+    pub fn closest_point(self, point: Point3D<T, B>) -> Point3D<T, B> {
+        let offset = point - self.center;
+        let distance = offset.magnitude();
+        if distance > T::ZERO {
+            self.center + offset * (self.radius / distance)
+        } else {
+            self.center + Vector3D::new([self.radius, T::ZERO, T::ZERO])
+        }
+    }
+
+    /// True if the enclosed balls intersect or touch
+    #[inline]
+    pub fn overlaps(self, other: Self) -> bool {
+        let offset = other.center - self.center;
+        offset.dot(offset) <= (self.radius + other.radius).powi(2)
+    }
+
+    /// Circle where the surfaces of two spheres intersect; Of radius zero where they touch
+    ///
+    /// Returns `None` if the spheres are separate, one lies inside the other, or they are identical (such that every point intersects).
+    /// The normal of the circle points from the center of `self` towards the center of `other`.
+    pub fn sphere_intersection(self, other: Self) -> Option<Circle3D<T, B>> {
+        let axis = other.center - self.center;
+        let distance = axis.magnitude();
+        if distance == T::ZERO || distance > self.radius + other.radius || distance < (self.radius - other.radius).abs() {
+            return None;
+        }
+        let normal = axis / distance;
+        // Distance from the center of `self` to the plane of the circle
+        let along = (distance.powi(2) + self.radius.powi(2) - other.radius.powi(2)) / (T::i(2) * distance);
+        Some(Circle3D {
+            center: self.center + normal * along,
+            normal,
+            radius: (self.radius.powi(2) - along.powi(2)).max(T::ZERO).sqrt(),
+        })
+    }
+
+    /// Parameters `(entry, exit)` where `line` enters and exits the sphere, with `entry ≤ exit`; Equal where the line is tangent
+    ///
+    /// Returns `None` if the line misses the sphere, or its direction is zero
+    pub fn line_intersection(self, line: Line3D<T, B>) -> Option<(T, T)> {
+        let direction_squared = line.direction.dot(line.direction);
+        if direction_squared == T::ZERO {
+            return None;
+        }
+        // Solved relative to the point of closest approach, which avoids the cancellation of the usual quadratic formula for distant origins
+        let closest_t = (self.center - line.point).dot(line.direction) / direction_squared;
+        let offset = line.at(closest_t) - self.center;
+        let half_chord_squared = self.radius.powi(2) - offset.dot(offset);
+        if half_chord_squared < T::ZERO {
+            return None;
+        }
+        let half_chord_t = (half_chord_squared / direction_squared).sqrt();
+        Some((closest_t - half_chord_t, closest_t + half_chord_t))
+    }
+
+    /// Parameters `(entry, exit)` where `ray` enters and exits the sphere, with `entry ≤ exit`
+    ///
+    /// Where the ray starts inside the sphere, `entry` is negative.
+    /// Returns `None` if the ray misses the sphere, or the sphere lies entirely behind its origin
+    #[inline]
+    pub fn ray_intersection(self, ray: Ray3D<T, B>) -> Option<(T, T)> {
+        self.line_intersection(Line3D { point: ray.origin, direction: ray.direction })
+            .filter(|&(_, exit)| exit >= T::ZERO)
+    }
+
+    /// Circle where `plane` intersects the sphere; Of radius zero where it is tangent
+    ///
+    /// The normal of the circle is the normal of the plane. Returns `None` if the plane misses the sphere
+    pub fn plane_intersection(self, plane: Plane3D<T, B>) -> Option<Circle3D<T, B>> {
+        let distance = plane.signed_distance(self.center);
+        if distance.abs() > self.radius {
+            return None;
+        }
+        Some(Circle3D {
+            center: self.center - plane.normal() * distance,
+            normal: plane.normal(),
+            radius: (self.radius.powi(2) - distance.powi(2)).max(T::ZERO).sqrt(),
+        })
+    }
+
+    /// Smallest sphere containing all `points`, through Welzl's randomized algorithm in expected linear time
+    ///
+    /// Uses a fixed seed, so the result is deterministic; See [`Sphere::minimum_enclosing_seeded`].
+    /// Returns `None` if there are no points, or any point is not finite
+    #[inline]
+    pub fn minimum_enclosing(points: &[Point3D<T, B>]) -> Option<Self> {
+        Self::minimum_enclosing_seeded(points, enclosing::DEFAULT_SEED)
+    }
+
+    /// Smallest sphere containing all `points`, shuffling them with `seed` for Welzl's algorithm
+    ///
+    /// The seed only affects the running time and rounding of the result
+    pub fn minimum_enclosing_seeded(points: &[Point3D<T, B>], seed: u64) -> Option<Self> {
+        enclosing::minimum_enclosing_ball(points, seed).map(|(center, radius)| Sphere { center, radius })
+    }
+
+    /// Sphere containing all `points` through Ritter's algorithm; Faster than [`Sphere::minimum_enclosing`], though typically a few percent larger
+    pub fn approximate_enclosing(points: &[Point3D<T, B>]) -> Option<Self> {
+        enclosing::approximate_enclosing_ball(points).map(|(center, radius)| Sphere { center, radius })
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> Bounded<T, 3, B> for Sphere<T, B> {
+    #[inline]
+    fn bounding_box(&self) -> Aabb3D<T, B> {
+        let offset = Vector3D::new([self.radius; 3]);
+        Aabb3D::new(self.center - offset, self.center + offset)
+    }
+}
+
+/// Circle in 3D space, in the plane through `center` perpendicular to the unit length `normal`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Circle3D<T, B: Basis<3>> {
+    pub center: Point3D<T, B>,
+    pub normal: Vector3D<T, B>,
+    pub radius: T,
+}
+
+impl<T: ApproxEq<Epsilon=T>, B: Basis<3>> ApproxEq for Circle3D<T, B> {
+    impl_approx_eq_fields!(T; center, normal, radius);
+}
+
+/// Infinite line of points `point + t·direction`
+///
+/// The direction need not be unit length; Parameters `t` are in multiples of its length
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Line3D<T, B: Basis<3>> {
+    pub point: Point3D<T, B>,
+    pub direction: Vector3D<T, B>,
+}
+
+impl<T: Scalar, B: Basis<3>> Line3D<T, B> {
+    /// Line through two points, with `a` at parameter zero and `b` at one
+    #[inline]
+    pub fn from_points(a: Point3D<T, B>, b: Point3D<T, B>) -> Self {
+        Line3D { point: a, direction: b - a }
+    }
+
+    /// Point at parameter `t`
+    #[inline]
+    pub fn at(self, t: T) -> Point3D<T, B> {
+        self.point + self.direction * t
+    }
+
+    /// Parameter of the orthogonal projection of `point` onto the line; Zero if the direction is zero
+    #[inline]
+    pub fn closest_parameter(self, point: Point3D<T, B>) -> T {
+        projection_parameter(self.point, self.direction, point)
+    }
+
+    /// Point on the line closest to `point`
+    #[inline]
+    pub fn closest_point(self, point: Point3D<T, B>) -> Point3D<T, B> {
+        self.at(self.closest_parameter(point))
+    }
+
+    /// Distance from the line to `point`
+    #[inline]
+    pub fn distance_to(self, point: Point3D<T, B>) -> T {
+        (point - self.closest_point(point)).magnitude()
+    }
+
+    /// Parameters `(t, u)` of the closest points between this line and `other`, at `self.at(t)` and `other.at(u)`
+    ///
+    /// Returns `None` if the lines are (nearly) parallel, in which case every point is equally close; See [`Line3D::distance_to_line`]
+    pub fn closest_parameters(self, other: Self) -> Option<(T, T)> {
+        let (d1, d2, r) = (self.direction, other.direction, self.point - other.point);
+        let (a, b, e) = (d1.dot(d1), d1.dot(d2), d2.dot(d2));
+        let (c, f) = (d1.dot(r), d2.dot(r));
+        let denominator = a * e - b * b;
+        if denominator <= T::i(8) * T::EPSILON * a * e {
+            return None;
+        }
+        Some(((b * f - c * e) / denominator, (a * f - b * c) / denominator))
+    }
+
+    /// Shortest distance between this line and `other`, including where they are parallel
+    pub fn distance_to_line(self, other: Self) -> T {
+        match self.closest_parameters(other) {
+            Some((t, u)) => (self.at(t) - other.at(u)).magnitude(),
+            // A line with a zero direction is a single point, so measure from it to the other line instead
+            None if self.direction.dot(self.direction) == T::ZERO => other.distance_to(self.point),
+            None => self.distance_to(other.point),
+        }
+    }
+}
+
+/// Parameter `t` of the orthogonal projection of `point` onto `origin + t·direction`; Zero if the direction is zero
+#[inline]
+fn projection_parameter<T: Scalar, B: Basis<3>>(origin: Point3D<T, B>, direction: Vector3D<T, B>, point: Point3D<T, B>) -> T {
+    let length_squared = direction.dot(direction);
+    if length_squared == T::ZERO {
+        T::ZERO
+    } else {
+        (point - origin).dot(direction) / length_squared
+    }
+}
+
+impl<T: ApproxEq<Epsilon=T>, B: Basis<3>> ApproxEq for Line3D<T, B> {
+    impl_approx_eq_fields!(T; point, direction);
+}
+
+/// Half-line of points `origin + t·direction` for `t ≥ 0`
+///
+/// The direction need not be unit length; Parameters `t` are in multiples of its length
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ray3D<T, B: Basis<3>> {
+    pub origin: Point3D<T, B>,
+    pub direction: Vector3D<T, B>,
+}
+
+impl<T: Scalar, B: Basis<3>> Ray3D<T, B> {
+    /// Point at parameter `t`
+    #[inline]
+    pub fn at(self, t: T) -> Point3D<T, B> {
+        self.origin + self.direction * t
+    }
+
+    /// Parameter of the point on the ray closest to `point`; The projection onto the line of the ray, clamped to be non-negative
+    #[inline]
+    pub fn closest_parameter(self, point: Point3D<T, B>) -> T {
+        projection_parameter(self.origin, self.direction, point).max(T::ZERO)
+    }
+
+    /// Point on the ray closest to `point`
+    #[inline]
+    pub fn closest_point(self, point: Point3D<T, B>) -> Point3D<T, B> {
+        self.at(self.closest_parameter(point))
+    }
+
+    /// Distance from the ray to `point`
+    #[inline]
+    pub fn distance_to(self, point: Point3D<T, B>) -> T {
+        (point - self.closest_point(point)).magnitude()
+    }
+}
+
+impl<T: ApproxEq<Epsilon=T>, B: Basis<3>> ApproxEq for Ray3D<T, B> {
+    impl_approx_eq_fields!(T; origin, direction);
+}
+
+/// Line segment of points `start + t·(end - start)` for `0 ≤ t ≤ 1`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Segment3D<T, B: Basis<3>> {
+    pub start: Point3D<T, B>,
+    pub end: Point3D<T, B>,
+}
+
+impl<T: Scalar, B: Basis<3>> Segment3D<T, B> {
+    /// Point at parameter `t`; `start` at zero, and `end` at one
+    #[inline]
+    pub fn at(self, t: T) -> Point3D<T, B> {
+        self.start.lerp(self.end, t)
+    }
+
+    /// Vector from `start` to `end`
+    #[inline]
+    pub fn direction(self) -> Vector3D<T, B> {
+        self.end - self.start
+    }
+
+    /// Distance from `start` to `end`
+    #[inline]
+    pub fn length(self) -> T {
+        self.direction().magnitude()
+    }
+
+    /// Parameter of the point on the segment closest to `point`; The projection onto the line of the segment, clamped to `[0, 1]`
+    #[inline]
+    pub fn closest_parameter(self, point: Point3D<T, B>) -> T {
+        projection_parameter(self.start, self.direction(), point).max(T::ZERO).min(T::ONE)
+    }
+
+    /// Point on the segment closest to `point`
+    #[inline]
+    pub fn closest_point(self, point: Point3D<T, B>) -> Point3D<T, B> {
+        self.at(self.closest_parameter(point))
+    }
+
+    /// Distance from the segment to `point`
+    #[inline]
+    pub fn distance_to(self, point: Point3D<T, B>) -> T {
+        (point - self.closest_point(point)).magnitude()
+    }
+
+    /// Parameters `(t, u)` in `[0, 1]` of the closest points between this segment and `other`, at `self.at(t)` and `other.at(u)`
+    ///
+    /// Where the closest points are not unique, as for overlapping parallel segments, one pair of them is returned.
+    /// Segments of length zero are handled as points
+    pub fn closest_parameters(self, other: Self) -> (T, T) {
+        let (d1, d2, r) = (self.direction(), other.direction(), self.start - other.start);
+        let (a, e, f) = (d1.dot(d1), d2.dot(d2), d2.dot(r));
+        let clamp = |x: T| x.max(T::ZERO).min(T::ONE);
+        if a == T::ZERO && e == T::ZERO {
+            return (T::ZERO, T::ZERO);
+        }
+        if a == T::ZERO {
+            return (T::ZERO, clamp(f / e));
+        }
+        let c = d1.dot(r);
+        if e == T::ZERO {
+            return (clamp(-c / a), T::ZERO);
+        }
+        // Closest points of the infinite lines, with `t` clamped to the segment; Parallel segments start from `t = 0`
+        let b = d1.dot(d2);
+        let denominator = a * e - b * b;
+        let t = if denominator > T::i(8) * T::EPSILON * a * e { clamp((b * f - c * e) / denominator) } else { T::ZERO };
+        // Closest point on `other` to `self.at(t)`, then `t` recomputed where that leaves `other`
+        let u = (b * t + f) / e;
+        if u < T::ZERO {
+            (clamp(-c / a), T::ZERO)
+        } else if u > T::ONE {
+            (clamp((b - c) / a), T::ONE)
+        } else {
+            (t, u)
+        }
+    }
+
+    /// Shortest distance between this segment and `other`
+    #[inline]
+    pub fn distance_to_segment(self, other: Self) -> T {
+        let (t, u) = self.closest_parameters(other);
+        (self.at(t) - other.at(u)).magnitude()
+    }
+}
+
+impl<T: ApproxEq<Epsilon=T>, B: Basis<3>> ApproxEq for Segment3D<T, B> {
+    impl_approx_eq_fields!(T; start, end);
+}
+
+/// Plane of points `p` where `normal · p = offset`, with a unit length normal
+///
+/// The normal points towards the "front" of the plane, where signed distances are positive
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Plane3D<T, B: Basis<3>> {
+    normal: Vector3D<T, B>,
+    offset: T,
+}
+
+impl<T: Scalar, B: Basis<3>> Plane3D<T, B> {
+    /// Create a new plane from a normal and offset, where `normal · p = offset` for points `p` on the plane
+    ///
+    /// The normal need not be unit length; Both are scaled such that it is.
+    /// Returns `None` if the normal is zero, or if any value is not finite
+    pub fn new(normal: Vector3D<T, B>, offset: T) -> Option<Self> {
+        let length = normal.magnitude();
+        if length > T::ZERO && length.is_finite() && offset.is_finite() {
+            Some(Plane3D { normal: normal / length, offset: offset / length })
+        } else {
+            None
+        }
+    }
+
+    /// Create a new plane through `point`, with the given normal
+    ///
+    /// Returns `None` if the normal is zero, or if any value is not finite
+    #[inline]
+    pub fn from_point_normal(point: Point3D<T, B>, normal: Vector3D<T, B>) -> Option<Self> {
+        let normal = normal.with_unit_length();
+        Self::new(normal, normal.dot(point.to_vector()))
+    }
+
+    /// Create a new plane through 3 points, with the normal along the [cross product](Vector3D::cross_product) `(b - a) × (c - a)`
+    ///
+    /// Returns `None` if the points are collinear, or if any value is not finite
+    #[inline]
+    pub fn from_points(a: Point3D<T, B>, b: Point3D<T, B>, c: Point3D<T, B>) -> Option<Self> {
+        Self::from_point_normal(a, (b - a).cross_product(c - a))
+    }
+
+    /// Plane of a triangle, with the normal of the triangle; See [`Triangle::plane`]
+    #[inline]
+    pub fn from_triangle(triangle: Triangle<T, 3, B>) -> Option<Self> {
+        triangle.plane()
+    }
+
+    /// Unit normal
+    #[inline]
+    pub fn normal(self) -> Vector3D<T, B> {
+        self.normal
+    }
+
+    /// Signed distance of the plane from the origin, along the normal
+    #[inline]
+    pub fn offset(self) -> T {
+        self.offset
+    }
+
+    /// Signed distance from the plane to `point`; Positive in front of the plane, in the direction of the normal
+    #[inline]
+    pub fn signed_distance(self, point: Point3D<T, B>) -> T {
+        self.normal.dot(point.to_vector()) - self.offset
+    }
+
+    /// Same plane facing the opposite direction
+    #[inline]
+    pub fn flip(self) -> Self {
+        Plane3D { normal: -self.normal, offset: -self.offset }
+    }
+
+    /// Orthogonal projection of `point` onto the plane, which is the closest point on the plane
+    #[inline]
+    pub fn closest_point(self, point: Point3D<T, B>) -> Point3D<T, B> {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Component of `vector` parallel to the plane
+    #[inline]
+    pub fn project_vector(self, vector: Vector3D<T, B>) -> Vector3D<T, B> {
+        vector - self.normal * self.normal.dot(vector)
+    }
+
+    /// Parameter where `line` crosses the plane
+    ///
+    /// Returns `None` if the line is parallel to the plane, including where it lies in the plane
+    #[inline]
+    pub fn line_intersection(self, line: Line3D<T, B>) -> Option<T> {
+        let speed = self.normal.dot(line.direction);
+        (speed != T::ZERO).then(|| -self.signed_distance(line.point) / speed)
+    }
+
+    /// Parameter where `ray` crosses the plane; Returns `None` if it is parallel to the plane, or points away from it
+    #[inline]
+    pub fn ray_intersection(self, ray: Ray3D<T, B>) -> Option<T> {
+        self.line_intersection(Line3D { point: ray.origin, direction: ray.direction }).filter(|&t| t >= T::ZERO)
+    }
+
+    /// Parameter in `[0, 1]` where `segment` crosses the plane; Returns `None` if it is parallel to the plane, or lies entirely on one side
+    pub fn segment_intersection(self, segment: Segment3D<T, B>) -> Option<T> {
+        let (start, end) = (self.signed_distance(segment.start), self.signed_distance(segment.end));
+        if (start > T::ZERO && end > T::ZERO) || (start < T::ZERO && end < T::ZERO) || start == end {
+            return None;
+        }
+        // Interpolated from the signed distances of the end points, which keeps the parameter in range
+        Some(start / (start - end))
+    }
+
+    /// Line where two planes intersect, with the direction `self.normal() × other.normal()`
+    ///
+    /// Returns `None` if the planes are (nearly) parallel
+    pub fn plane_intersection(self, other: Self) -> Option<Line3D<T, B>> {
+        let direction = self.normal.cross_product(other.normal);
+        let length_squared = direction.dot(direction);
+        if length_squared <= T::i(8) * T::EPSILON {
+            return None;
+        }
+        // Point in the span of both normals; `length_squared = 1 - cos²` between them
+        let cos = self.normal.dot(other.normal);
+        let point = (self.normal * (self.offset - other.offset * cos) + other.normal * (other.offset - self.offset * cos)) / length_squared;
+        Some(Line3D { point: Point3D::from_vector(point), direction })
+    }
+
+    /// Single point where three planes intersect, through Cramer's rule `(d₁·(n₂ × n₃) + d₂·(n₃ × n₁) + d₃·(n₁ × n₂)) / (n₁ · (n₂ × n₃))`
+    ///
+    /// Returns `None` if any two planes are (nearly) parallel, or all three share a line
+    pub fn three_plane_intersection(first: Self, second: Self, third: Self) -> Option<Point3D<T, B>> {
+        let (n1, n2, n3) = (first.normal, second.normal, third.normal);
+        let (n23, n31, n12) = (n2.coordinate_cross_product(n3), n3.coordinate_cross_product(n1), n1.coordinate_cross_product(n2));
+        let determinant = n1.dot(n23);
+        if determinant.abs() <= T::i(8) * T::EPSILON {
+            return None;
+        }
+        Some(Point3D::from_vector((n23 * first.offset + n31 * second.offset + n12 * third.offset) / determinant))
+    }
+}
+
+impl<T: ApproxEq<Epsilon=T>, B: Basis<3>> ApproxEq for Plane3D<T, B> {
+    impl_approx_eq_fields!(T; normal, offset);
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum InvalidObbError {
+    /// A half-extent is negative
+    NegativeExtent,
+    /// The center, half-extents or orientation is infinite or NaN
+    NonFinite,
+}
+
+/// Oriented bounding box; A box with its edges along the axes of `orientation`, extending `half_extents` along each axis from `center`
+///
+/// The box axes are the columns of `orientation`, which must be orthonormal
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Obb3D<T, B: Basis<3>> {
+    pub center: Point3D<T, B>,
+    pub half_extents: Vector3D<T, B>,
+    pub orientation: RotationMatrix<T, B>,
+}
+
+impl<T: ApproxEq<Epsilon=T>, B: Basis<3>> ApproxEq for Obb3D<T, B> {
+    impl_approx_eq_fields!(T; center, half_extents, orientation);
+}
+
+impl<T: Scalar, B: Basis<3>> Obb3D<T, B> {
+    /// Create a new box, rejecting negative half-extents and non-finite values; Half-extents of zero are valid
+    pub fn new(center: Point3D<T, B>, half_extents: Vector3D<T, B>, orientation: RotationMatrix<T, B>) -> Result<Self, InvalidObbError> {
+        let orientation_finite = orientation.to_row_major().into_iter().flatten().all(|x| x.is_finite());
+        if !center.is_finite() || !half_extents.is_finite() || !orientation_finite {
+            Err(InvalidObbError::NonFinite)
+        } else if half_extents.into_iter().any(|extent| extent < T::ZERO) {
+            Err(InvalidObbError::NegativeExtent)
+        } else {
+            Ok(Obb3D { center, half_extents, orientation })
+        }
+    }
+
+    /// Box fitted to a point cloud through principal component analysis
+    ///
+    /// The axes are the eigenvectors of the covariance matrix of the points, and the extents the range of the points along each axis.
+    /// Tight for elongated point clouds, though not the minimum volume box; `None` for an empty or non-finite point set
+    pub fn from_points(points: &[Point3D<T, B>]) -> Option<Self> {
+        if points.is_empty() || !points.iter().all(|point| point.is_finite()) {
+            return None;
+        }
+        let count = points.iter().fold(T::ZERO, |count, _| count + T::ONE);
+        let mean = points[0] + points.iter().fold(Vector3D::new([T::ZERO; 3]), |sum, &point| sum + (point - points[0])) / count;
+        let mut covariance = MatrixN::<T, 3, B>::zero();
+        for &point in points {
+            let offset = (point - mean).to_array();
+            for row in 0..3 {
+                for column in 0..3 {
+                    covariance[(row, column)] += offset[row] * offset[column] / count;
+                }
+            }
+        }
+        let (_, vectors) = covariance.symmetric_eigen();
+        let [x, y, _] = vectors.to_columns();
+        // Right-handed in coordinates, such that the axes form a rotation rather than a reflection
+        let z = x.coordinate_cross_product(y);
+        let orientation = RotationMatrix::from_row_major(MatrixMN::from_columns([x, y, z]).to_row_major());
+        let axes = [x, y, z];
+        let (min, max) = points.iter().fold(([T::ZERO; 3], [T::ZERO; 3]), |(mut min, mut max), &point| {
+            for (axis, vector) in axes.iter().enumerate() {
+                let projection = (point - mean).dot(*vector);
+                min[axis] = min[axis].min(projection);
+                max[axis] = max[axis].max(projection);
+            }
+            (min, max)
+        });
+        let middle = Vector3D::new(std::array::from_fn(|axis| T::f(0.5) * (min[axis] + max[axis])));
+        Some(Obb3D {
+            center: mean + orientation.apply(middle),
+            half_extents: Vector3D::new(std::array::from_fn(|axis| T::f(0.5) * (max[axis] - min[axis]))),
+            orientation,
+        })
+    }
+
+    /// Unit length box axes; The columns of the orientation
+    #[inline]
+    pub fn axes(self) -> [Vector3D<T, B>; 3] {
+        self.orientation.transpose().to_row_major().map(Vector3D::new)
+    }
+
+    /// Volume of the box
+    #[inline]
+    pub fn volume(self) -> T {
+        self.half_extents.into_iter().fold(T::i(8), |product, extent| product * extent)
+    }
+
+    /// The 8 corners of the box; Corner `i` lies on the positive side of axis `k` where bit `k` of `i` is set
+    pub fn corners(self) -> [Point3D<T, B>; 8] {
+        let (axes, extents) = (self.axes(), self.half_extents.to_array());
+        std::array::from_fn(|corner| (0..3).fold(self.center, |point, axis| {
+            let offset = axes[axis] * extents[axis];
+            if corner >> axis & 1 == 0 { point - offset } else { point + offset }
+        }))
+    }
+
+    /// Coordinates of `point` along the box axes, relative to the center
+    #[inline]
+    fn to_local(self, point: Point3D<T, B>) -> [T; 3] {
+        self.orientation.transpose().apply(point - self.center).to_array()
+    }
+
+    /// True if `point` lies inside or on the boundary of the box
+    #[inline]
+    pub fn contains(self, point: Point3D<T, B>) -> bool {
+        self.to_local(point).into_iter().zip(self.half_extents).all(|(coordinate, extent)| coordinate.abs() <= extent)
+    }
+
+    /// Point in the box closest to `point`; `point` itself if it is contained
+    pub fn closest_point(self, point: Point3D<T, B>) -> Point3D<T, B> {
+        let clamped = self.to_local(point).into_iter().zip(self.half_extents).map(|(coordinate, extent)| coordinate.max(-extent).min(extent));
+        self.center + self.axes().into_iter().zip(clamped).fold(Vector3D::new([T::ZERO; 3]), |sum, (axis, coordinate)| sum + axis * coordinate)
+    }
+
+    /// Distance from `point` to the box; Zero inside the box
+    #[inline]
+    pub fn distance_to(self, point: Point3D<T, B>) -> T {
+        (point - self.closest_point(point)).magnitude()
+    }
+
+    /// True if the boxes intersect or touch, through the separating axis test
+    ///
+    /// Tests the 3 axes of each box, and the 9 cross products of an axis from each. Near-parallel edges are handled with a small tolerance, erring towards overlap
+    pub fn overlaps(self, other: Self) -> bool {
+        let (a, b) = (self.axes(), other.axes());
+        let (ea, eb) = (self.half_extents.to_array(), other.half_extents.to_array());
+        // Rotation expressing `other` in the frame of `self`, and the offset between the centers in that frame
+        let offset = other.center - self.center;
+        let r: [[T; 3]; 3] = std::array::from_fn(|i| std::array::from_fn(|j| a[i].dot(b[j])));
+        let abs_r = r.map(|row| row.map(|x| x.abs() + T::i(8) * T::EPSILON));
+        let t = a.map(|axis| offset.dot(axis));
+
+        let separated_a = (0..3).any(|i| {
+            t[i].abs() > ea[i] + (0..3).fold(T::ZERO, |sum, j| sum + eb[j] * abs_r[i][j])
+        });
+        let separated_b = (0..3).any(|j| {
+            let distance = (0..3).fold(T::ZERO, |sum, i| sum + t[i] * r[i][j]);
+            distance.abs() > eb[j] + (0..3).fold(T::ZERO, |sum, i| sum + ea[i] * abs_r[i][j])
+        });
+        let separated_cross = (0..3).flat_map(|i| (0..3).map(move |j| (i, j))).any(|(i, j)| {
+            let (i1, i2, j1, j2) = ((i + 1) % 3, (i + 2) % 3, (j + 1) % 3, (j + 2) % 3);
+            let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+            let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+            (t[i2] * r[i1][j] - t[i1] * r[i2][j]).abs() > ra + rb
+        });
+        !(separated_a || separated_b || separated_cross)
+    }
+
+    /// True if `triangle` intersects or touches the box, through the separating axis test
+    ///
+    /// Tests the 3 box axes, the triangle normal, and the 9 cross products of a box axis and a triangle edge
+    pub fn overlaps_triangle(self, triangle: Triangle<T, 3, B>) -> bool {
+        let vertices = [triangle.A(), triangle.B(), triangle.C()].map(|vertex| Vector3D::<T, B>::new(self.to_local(vertex)));
+        let edges = [vertices[1] - vertices[0], vertices[2] - vertices[1], vertices[0] - vertices[2]];
+        let extents = self.half_extents.to_array();
+        let unit = |axis: usize| Vector3D::<T, B>::new(std::array::from_fn(|index| if index == axis { T::ONE } else { T::ZERO }));
+        let separates = |axis: Vector3D<T, B>| {
+            let radius = axis.into_iter().zip(extents).fold(T::ZERO, |sum, (component, extent)| sum + component.abs() * extent);
+            let projections = vertices.map(|vertex| vertex.dot(axis));
+            let (min, max) = (projections[0].min(projections[1]).min(projections[2]), projections[0].max(projections[1]).max(projections[2]));
+            min > radius || max < -radius
+        };
+        let box_axes = (0..3).map(unit);
+        let normal = std::iter::once(edges[0].coordinate_cross_product(edges[1]));
+        let cross = (0..3).flat_map(|axis| edges.map(|edge| unit(axis).coordinate_cross_product(edge)));
+        !box_axes.chain(normal).chain(cross).any(separates)
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> Bounded<T, 3, B> for Obb3D<T, B> {
+    /// Smallest axis-aligned box containing the oriented box, extending `|R|·h` from the center
+    fn bounding_box(&self) -> Aabb3D<T, B> {
+        let (axes, extents) = (self.axes(), self.half_extents.to_array());
+        let reach = Vector3D::new(std::array::from_fn(|coordinate| {
+            (0..3).fold(T::ZERO, |sum, axis| sum + (axes[axis].to_array()[coordinate] * extents[axis]).abs())
+        }));
+        Aabb3D::new(self.center - reach, self.center + reach)
+    }
+}
@@ -0,0 +1,281 @@
+//! Rigid, similarity and affine transforms for 3D geometry
+//!
+//! Transforms distinguish between points and directions: [`transform_point`](Isometry3D::transform_point) applies the translation, [`transform_vector`](Isometry3D::transform_vector) does not.
+//! Transforms are composed through the [`Mul`] trait; `(a * b).transform_point(p)` is equivalent to `a.transform_point(b.transform_point(p))`
+use std::ops::Mul;
+use crate::basis::Basis;
+use crate::geometry3d::{Aabb3D, Point3D, RotationMatrix, Vector3D};
+use crate::matrix::{MatrixMN, MatrixN};
+use crate::scalar::Scalar;
+
+/// Embed a 3x3 linear map and translation into a 4x4 homogeneous matrix
+#[inline]
+fn homogeneous<T: Scalar>(linear: [[T; 3]; 3], translation: [T; 3]) -> MatrixN<T, 4, ()> {
+    let [
+        [r11, r12, r13],
+        [r21, r22, r23],
+        [r31, r32, r33]
+    ] = linear;
+    let [x, y, z] = translation;
+    MatrixMN::from_row_major([
+        [r11, r12, r13, x],
+        [r21, r22, r23, y],
+        [r31, r32, r33, z],
+        [T::ZERO, T::ZERO, T::ZERO, T::i(1)]
+    ])
+}
+
+/// Rigid transform; Rotation followed by translation
+///
+/// Preserves lengths and angles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Isometry3D<T, B: Basis<3>> {
+    pub rotation: RotationMatrix<T, B>,
+    pub translation: Vector3D<T, B>,
+}
+
+impl<T: Scalar, B: Basis<3>> Isometry3D<T, B> {
+    /// Create a new isometry which first rotates, then translates
+    #[inline]
+    pub fn new(rotation: RotationMatrix<T, B>, translation: Vector3D<T, B>) -> Self {
+        Isometry3D { rotation, translation }
+    }
+
+    /// Identity transform
+    #[inline]
+    pub fn identity() -> Self {
+        Isometry3D { rotation: RotationMatrix::identity(), translation: Vector3D::new([T::ZERO; 3]) }
+    }
+
+    /// Pure translation
+    #[inline]
+    pub fn from_translation(translation: Vector3D<T, B>) -> Self {
+        Isometry3D { rotation: RotationMatrix::identity(), translation }
+    }
+
+    /// Pure rotation around the origin
+    #[inline]
+    pub fn from_rotation(rotation: RotationMatrix<T, B>) -> Self {
+        Isometry3D { rotation, translation: Vector3D::new([T::ZERO; 3]) }
+    }
+
+    /// Transform a point; Rotated, then translated
+    #[inline]
+    pub fn transform_point(self, point: Point3D<T, B>) -> Point3D<T, B> {
+        Point3D::from_vector(self.transform_vector(point.to_vector()) + self.translation)
+    }
+
+    /// Transform a direction vector; Rotated only
+    #[inline]
+    pub fn transform_vector(self, vector: Vector3D<T, B>) -> Vector3D<T, B> {
+        self.rotation.apply(vector)
+    }
+
+    /// Inverse transform
+    #[inline]
+    pub fn inverse(self) -> Self {
+        let rotation = self.rotation.inverse();
+        Isometry3D { rotation, translation: -rotation.apply(self.translation) }
+    }
+
+    /// Convert to a 4x4 homogeneous matrix, operating on column vectors `[x, y, z, 1]` for points and `[x, y, z, 0]` for directions
+    ///
+    /// Use [`MatrixMN::to_column_major`] for export to graphics APIs which expect column-major matrices
+    #[inline]
+    pub fn to_homogeneous(self) -> MatrixN<T, 4, ()> {
+        homogeneous(self.rotation.to_row_major(), self.translation.to_array())
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> Mul for Isometry3D<T, B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Isometry3D {
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_vector(rhs.translation) + self.translation,
+        }
+    }
+}
+
+/// Similarity transform; Uniform scaling, followed by rotation, followed by translation
+///
+/// Preserves angles and ratios of lengths.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Similarity3D<T, B: Basis<3>> {
+    pub scale: T,
+    pub rotation: RotationMatrix<T, B>,
+    pub translation: Vector3D<T, B>,
+}
+
+impl<T: Scalar, B: Basis<3>> Similarity3D<T, B> {
+    /// Create a new similarity which first scales, then rotates, then translates
+    #[inline]
+    pub fn new(scale: T, rotation: RotationMatrix<T, B>, translation: Vector3D<T, B>) -> Self {
+        Similarity3D { scale, rotation, translation }
+    }
+
+    /// Identity transform
+    #[inline]
+    pub fn identity() -> Self {
+        Similarity3D::from(Isometry3D::identity())
+    }
+
+    /// Pure uniform scaling around the origin
+    #[inline]
+    pub fn from_scale(scale: T) -> Self {
+        Similarity3D { scale, rotation: RotationMatrix::identity(), translation: Vector3D::new([T::ZERO; 3]) }
+    }
+
+    /// Transform a point; Scaled, rotated, then translated
+    #[inline]
+    pub fn transform_point(self, point: Point3D<T, B>) -> Point3D<T, B> {
+        Point3D::from_vector(self.transform_vector(point.to_vector()) + self.translation)
+    }
+
+    /// Transform a direction vector; Scaled and rotated only
+    #[inline]
+    pub fn transform_vector(self, vector: Vector3D<T, B>) -> Vector3D<T, B> {
+        self.rotation.apply(vector * self.scale)
+    }
+
+    /// Inverse transform
+    ///
+    /// Returns `None` if the scale is zero
+    #[inline]
+    pub fn inverse(self) -> Option<Self> {
+        if self.scale == T::ZERO || !self.scale.is_finite() {
+            return None;
+        }
+        let scale = T::i(1) / self.scale;
+        let rotation = self.rotation.inverse();
+        Some(Similarity3D { scale, rotation, translation: -rotation.apply(self.translation * scale) })
+    }
+
+    /// Convert to a 4x4 homogeneous matrix, operating on column vectors `[x, y, z, 1]` for points and `[x, y, z, 0]` for directions
+    ///
+    /// Use [`MatrixMN::to_column_major`] for export to graphics APIs which expect column-major matrices
+    #[inline]
+    pub fn to_homogeneous(self) -> MatrixN<T, 4, ()> {
+        homogeneous(self.rotation.to_row_major().map(|row| row.map(|value| value * self.scale)), self.translation.to_array())
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> Mul for Similarity3D<T, B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Similarity3D {
+            scale: self.scale * rhs.scale,
+            rotation: self.rotation * rhs.rotation,
+            translation: self.transform_vector(rhs.translation) + self.translation,
+        }
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> From<Isometry3D<T, B>> for Similarity3D<T, B> {
+    #[inline]
+    fn from(isometry: Isometry3D<T, B>) -> Self {
+        Similarity3D { scale: T::i(1), rotation: isometry.rotation, translation: isometry.translation }
+    }
+}
+
+/// General affine transform; Linear map followed by translation
+///
+/// May include non-uniform scaling, shearing and reflection, which do not preserve angles or lengths.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Affine3D<T, B: Basis<3>> {
+    pub linear: MatrixN<T, 3, B>,
+    pub translation: Vector3D<T, B>,
+}
+
+impl<T: Scalar, B: Basis<3>> Affine3D<T, B> {
+    /// Create a new affine transform which first applies the linear map, then translates
+    #[inline]
+    pub fn new(linear: MatrixN<T, 3, B>, translation: Vector3D<T, B>) -> Self {
+        Affine3D { linear, translation }
+    }
+
+    /// Identity transform
+    #[inline]
+    pub fn identity() -> Self {
+        Affine3D { linear: MatrixN::identity(), translation: Vector3D::new([T::ZERO; 3]) }
+    }
+
+    /// Non-uniform scaling along each axis
+    #[inline]
+    pub fn from_scale(scale: Vector3D<T, B>) -> Self {
+        let [x, y, z] = scale.to_array();
+        Affine3D {
+            linear: MatrixMN::from_row_major([
+                [x, T::ZERO, T::ZERO],
+                [T::ZERO, y, T::ZERO],
+                [T::ZERO, T::ZERO, z]
+            ]),
+            translation: Vector3D::new([T::ZERO; 3]),
+        }
+    }
+
+    /// Transform a point; Linear map, then translated
+    #[inline]
+    pub fn transform_point(self, point: Point3D<T, B>) -> Point3D<T, B> {
+        Point3D::from_vector(self.transform_vector(point.to_vector()) + self.translation)
+    }
+
+    /// Transform a direction vector; Linear map only
+    #[inline]
+    pub fn transform_vector(self, vector: Vector3D<T, B>) -> Vector3D<T, B> {
+        self.linear * vector
+    }
+
+    /// Smallest axis-aligned box containing `aabb` after transforming it
+    #[inline]
+    pub fn transform_aabb(self, aabb: Aabb3D<T, B>) -> Aabb3D<T, B> {
+        aabb.transform(self.linear, self.translation)
+    }
+
+    /// Inverse transform
+    ///
+    /// Returns `None` if the linear map is singular
+    #[inline]
+    pub fn inverse(self) -> Option<Self> {
+        let linear = self.linear.inverse()?;
+        Some(Affine3D { linear, translation: -(linear * self.translation) })
+    }
+
+    /// Convert to a 4x4 homogeneous matrix, operating on column vectors `[x, y, z, 1]` for points and `[x, y, z, 0]` for directions
+    ///
+    /// Use [`MatrixMN::to_column_major`] for export to graphics APIs which expect column-major matrices
+    #[inline]
+    pub fn to_homogeneous(self) -> MatrixN<T, 4, ()> {
+        homogeneous(self.linear.to_row_major(), self.translation.to_array())
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> Mul for Affine3D<T, B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Affine3D {
+            linear: self.linear * rhs.linear,
+            translation: self.transform_vector(rhs.translation) + self.translation,
+        }
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> From<Isometry3D<T, B>> for Affine3D<T, B> {
+    #[inline]
+    fn from(isometry: Isometry3D<T, B>) -> Self {
+        Affine3D { linear: MatrixN::from(isometry.rotation), translation: isometry.translation }
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> From<Similarity3D<T, B>> for Affine3D<T, B> {
+    #[inline]
+    fn from(similarity: Similarity3D<T, B>) -> Self {
+        Affine3D { linear: MatrixN::from(similarity.rotation) * similarity.scale, translation: similarity.translation }
+    }
+}
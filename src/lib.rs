@@ -1,12 +1,17 @@
 #![allow(mixed_script_confusables)]
 
 pub mod scalar;
+#[macro_use]
+pub mod approx;
+pub mod angle;
 pub mod basis;
 pub mod vector;
+pub mod matrix;
 #[macro_use]
 pub mod shapes;
 pub mod geometry2d;
 pub mod geometry3d;
+pub mod predicates;
 pub mod utility;
 
 #[cfg(test)]
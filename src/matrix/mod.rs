@@ -0,0 +1,381 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::approx::ApproxEq;
+use crate::basis::Basis;
+use crate::geometry3d::RotationMatrix;
+use crate::scalar::Scalar;
+use crate::vector::VectorN;
+
+/// M×N matrix; M rows and N columns
+///
+/// Matrices are represented internally as column-major arrays of [`VectorN`], in the same manner as [`RotationMatrix`].
+/// Matrix-vector multiplication is performed "pre-multiplied" with column vectors, and both matrix-matrix and matrix-vector multiplication are performed through the [`Mul`] trait.
+///
+/// The basis type parameter `B` is the basis of the column vectors. Operations which take or produce vectors of length N additionally require `B: Basis<N>`;
+/// For non-square matrices this is in practice limited to the default [`()`] basis.
+///
+/// A type alias for square matrices ([`MatrixN`]) is provided
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MatrixMN<T, const M: usize, const N: usize, B: Basis<M>>([VectorN<T, M, B>; N]);
+
+/// Type alias for square N×N matrices
+pub type MatrixN<T, const N: usize, B> = MatrixMN<T, N, N, B>;
+
+impl<T, const M: usize, const N: usize, B: Basis<M>> MatrixMN<T, M, N, B> {
+    /// Construct a new matrix from an array of column vectors
+    #[inline]
+    pub fn from_columns(columns: [VectorN<T, M, B>; N]) -> Self {
+        MatrixMN(columns)
+    }
+
+    /// "Unwraps" this matrix, yielding an array of column vectors
+    ///
+    /// Acts as inverse to [`MatrixMN::from_columns`]
+    #[inline]
+    pub fn to_columns(self) -> [VectorN<T, M, B>; N] {
+        self.0
+    }
+
+    /// Construct a new matrix from a column-major set of arrays
+    #[inline]
+    pub fn from_column_major(matrix: [[T; M]; N]) -> Self {
+        MatrixMN(matrix.map(VectorN::new))
+    }
+
+    /// Convert this matrix into a column-major set of arrays, as used by most graphics APIs
+    ///
+    /// Acts as inverse to [`MatrixMN::from_column_major`]
+    #[inline]
+    pub fn to_column_major(self) -> [[T; M]; N] {
+        self.0.map(VectorN::to_array)
+    }
+}
+
+impl<T: Copy, const M: usize, const N: usize, B: Basis<M>> MatrixMN<T, M, N, B> {
+    /// Construct a new matrix from a row-major set of arrays
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix`: Matrix data, `M` rows of `N` elements
+    ///
+    /// returns: MatrixMN<T, M, N, B>
+    ///
+    /// # Examples
+    /// ```
+    /// use unifiedgeometry::matrix::MatrixMN;
+    ///
+    /// let matrix: MatrixMN<f64, 2, 3, ()> = MatrixMN::from_row_major([
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0]
+    /// ]);
+    /// assert_eq!(matrix[(1, 0)], 4.0);
+    /// ```
+    #[inline]
+    pub fn from_row_major(matrix: [[T; N]; M]) -> Self {
+        MatrixMN(std::array::from_fn(|column| VectorN::new(std::array::from_fn(|row| matrix[row][column]))))
+    }
+
+    /// Convert this matrix into a row-major set of arrays
+    ///
+    /// Acts as inverse to [`MatrixMN::from_row_major`]
+    #[inline]
+    pub fn to_row_major(self) -> [[T; N]; M] {
+        std::array::from_fn(|row| std::array::from_fn(|column| self.0[column].array[row]))
+    }
+
+    /// Column vector at the specified index
+    ///
+    /// Panics if `column` >= N
+    #[inline]
+    pub fn column(self, column: usize) -> VectorN<T, M, B> {
+        self.0[column]
+    }
+}
+
+/// Element access by `(row, column)`
+impl<T, const M: usize, const N: usize, B: Basis<M>> Index<(usize, usize)> for MatrixMN<T, M, N, B> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.0[column].array[row]
+    }
+}
+
+impl<T, const M: usize, const N: usize, B: Basis<M>> IndexMut<(usize, usize)> for MatrixMN<T, M, N, B> {
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self.0[column].array[row]
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize, B: Basis<M>> MatrixMN<T, M, N, B> {
+    /// Matrix with all elements zero
+    #[inline]
+    pub fn zero() -> Self {
+        MatrixMN([VectorN::new([T::ZERO; M]); N])
+    }
+
+    /// Transpose of this matrix
+    #[inline]
+    pub fn transpose(self) -> MatrixMN<T, N, M, B> where B: Basis<N> {
+        MatrixMN::from_row_major(self.to_column_major())
+    }
+
+    /// Least-squares solution `x` minimizing `|self · x - b|`, for overdetermined systems with more rows than columns
+    ///
+    /// Solved through the normal equations `(Aᵀ · A) · x = Aᵀ · b`. These square the condition number of the matrix; For ill-conditioned systems precision will be lost.
+    ///
+    /// Returns `None` if the columns of this matrix are linearly dependent, in which case there is no unique solution
+    pub fn solve_least_squares(self, b: VectorN<T, M, B>) -> Option<VectorN<T, N, B>> where B: Basis<N> {
+        let transpose = self.transpose();
+        (transpose * self).solve(transpose * b)
+    }
+}
+
+impl<T: Scalar, const N: usize, B: Basis<N>> MatrixN<T, N, B> {
+    /// Identity matrix
+    #[inline]
+    pub fn identity() -> Self {
+        MatrixMN(std::array::from_fn(|column| VectorN::new(std::array::from_fn(|row| if row == column { T::i(1) } else { T::ZERO }))))
+    }
+
+    /// LU decomposition with partial pivoting
+    ///
+    /// Returns `None` if this matrix is singular. Singularity is detected by an exactly zero pivot; Nearly singular matrices decompose successfully but produce results with large errors.
+    pub fn lu(self) -> Option<LuDecomposition<T, N, B>> {
+        LuDecomposition::new(self)
+    }
+
+    /// Determinant of this matrix
+    pub fn determinant(self) -> T {
+        self.lu().map_or(T::ZERO, LuDecomposition::determinant)
+    }
+
+    /// Inverse of this matrix
+    ///
+    /// Returns `None` if this matrix is singular
+    pub fn inverse(self) -> Option<Self> {
+        self.lu().map(LuDecomposition::inverse)
+    }
+
+    /// Solve the linear system `self · x = b` for `x`
+    ///
+    /// Returns `None` if this matrix is singular. Where multiple systems with the same matrix are solved, [`MatrixMN::lu`] may be used to decompose the matrix only once.
+    pub fn solve(self, b: VectorN<T, N, B>) -> Option<VectorN<T, N, B>> {
+        self.lu().map(|lu| lu.solve(b))
+    }
+
+    /// Eigenvalues and eigenvectors of this matrix, which must be symmetric, through the cyclic Jacobi method
+    ///
+    /// Returns the eigenvalues in descending order, and a matrix of the corresponding unit length eigenvectors as its columns.
+    /// The eigenvector matrix is orthogonal, though it may be a reflection rather than a rotation. Only the upper triangle is read.
+    pub fn symmetric_eigen(self) -> ([T; N], Self) {
+        const MAX_SWEEPS: usize = 64;
+        let mut a: [[T; N]; N] = std::array::from_fn(|row| std::array::from_fn(|column| self[(row.min(column), row.max(column))]));
+        let mut v = Self::identity().to_row_major();
+        let norm_squared = a.iter().flatten().fold(T::ZERO, |sum, &x| sum + x * x);
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal = (0..N).flat_map(|p| (p + 1..N).map(move |q| (p, q))).fold(T::ZERO, |sum, (p, q)| sum + a[p][q] * a[p][q]);
+            if off_diagonal <= T::EPSILON * T::EPSILON * norm_squared {
+                break;
+            }
+            for p in 0..N {
+                for q in p + 1..N {
+                    if a[p][q] == T::ZERO {
+                        continue;
+                    }
+                    // Rotation in the (p, q) plane which zeroes a[p][q]; The smaller root of t² + 2θt - 1 = 0 keeps the rotation angle at most π/4
+                    let theta = (a[q][q] - a[p][p]) / (T::i(2) * a[p][q]);
+                    let t = T::ONE / (theta.abs() + (theta * theta + T::ONE).sqrt());
+                    let t = if theta < T::ZERO { -t } else { t };
+                    let c = T::ONE / (t * t + T::ONE).sqrt();
+                    let s = t * c;
+                    for row in a.iter_mut().chain(v.iter_mut()) {
+                        let (kp, kq) = (row[p], row[q]);
+                        row[p] = c * kp - s * kq;
+                        row[q] = s * kp + c * kq;
+                    }
+                    let (row_p, row_q) = (a[p], a[q]);
+                    a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+                    a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+                }
+            }
+        }
+        let mut order: [usize; N] = std::array::from_fn(|index| index);
+        order.sort_by(|&lhs, &rhs| a[rhs][rhs].partial_cmp(&a[lhs][lhs]).unwrap_or(std::cmp::Ordering::Equal));
+        let vectors = MatrixMN(order.map(|column| VectorN::new(std::array::from_fn(|row| v[row][column]))));
+        (order.map(|index| a[index][index]), vectors)
+    }
+}
+
+/// LU decomposition with partial pivoting of a square matrix, such that `P · A = L · U`
+///
+/// Created through [`MatrixMN::lu`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LuDecomposition<T, const N: usize, B: Basis<N>> {
+    /// Row-major combined L and U factors; L has an implied unit diagonal
+    lu: [[T; N]; N],
+    /// Row permutation; Row `i` of `P · A` is row `permutation[i]` of `A`
+    permutation: [usize; N],
+    /// True if the permutation consists of an odd number of row swaps
+    odd_permutation: bool,
+    basis: std::marker::PhantomData<B>,
+}
+
+impl<T: Scalar, const N: usize, B: Basis<N>> LuDecomposition<T, N, B> {
+    fn new(matrix: MatrixN<T, N, B>) -> Option<Self> {
+        let mut lu = matrix.to_row_major();
+        let mut permutation: [usize; N] = std::array::from_fn(|index| index);
+        let mut odd_permutation = false;
+
+        for column in 0..N {
+            // Partial pivoting: Use the row with the largest magnitude in this column to limit growth of rounding errors
+            let pivot_row = (column..N)
+                .fold(column, |best, row| if lu[row][column].abs() > lu[best][column].abs() { row } else { best });
+            if lu[pivot_row][column] == T::ZERO || !lu[pivot_row][column].is_finite() {
+                return None;
+            }
+            if pivot_row != column {
+                lu.swap(pivot_row, column);
+                permutation.swap(pivot_row, column);
+                odd_permutation = !odd_permutation;
+            }
+
+            let pivot_values = lu[column];
+            for row in lu.iter_mut().skip(column + 1) {
+                let factor = row[column] / pivot_values[column];
+                row[column] = factor;
+                row.iter_mut()
+                    .zip(pivot_values)
+                    .skip(column + 1)
+                    .for_each(|(value, pivot_value)| *value -= factor * pivot_value);
+            }
+        }
+
+        Some(LuDecomposition { lu, permutation, odd_permutation, basis: std::marker::PhantomData })
+    }
+
+    /// Determinant of the decomposed matrix
+    pub fn determinant(self) -> T {
+        let product = (0..N).fold(T::i(1), |product, index| product * self.lu[index][index]);
+        if self.odd_permutation { -product } else { product }
+    }
+
+    /// Solve the linear system `A · x = b` for `x`, where `A` is the decomposed matrix
+    pub fn solve(self, b: VectorN<T, N, B>) -> VectorN<T, N, B> {
+        let b = b.to_array();
+        let mut x: [T; N] = std::array::from_fn(|index| b[self.permutation[index]]);
+
+        // Forward substitution with L
+        for row in 0..N {
+            for column in 0..row {
+                let subtrahend = self.lu[row][column] * x[column];
+                x[row] -= subtrahend;
+            }
+        }
+        // Back substitution with U
+        for row in (0..N).rev() {
+            for column in (row + 1)..N {
+                let subtrahend = self.lu[row][column] * x[column];
+                x[row] -= subtrahend;
+            }
+            x[row] /= self.lu[row][row];
+        }
+
+        VectorN::new(x)
+    }
+
+    /// Inverse of the decomposed matrix
+    pub fn inverse(self) -> MatrixN<T, N, B> {
+        MatrixMN(MatrixN::<T, N, B>::identity().0.map(|column| self.solve(column)))
+    }
+}
+
+impl<T: ApproxEq<Epsilon=T>, const M: usize, const N: usize, B: Basis<M>> ApproxEq for MatrixMN<T, M, N, B> {
+    impl_approx_eq_fields!(T; 0);
+}
+
+impl<T: Scalar, const M: usize, const N: usize, B: Basis<M>> Neg for MatrixMN<T, M, N, B> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        MatrixMN(self.0.map(VectorN::neg))
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize, B: Basis<M>> Add for MatrixMN<T, M, N, B> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize, B: Basis<M>> AddAssign for MatrixMN<T, M, N, B> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.iter_mut()
+            .zip(rhs.0)
+            .for_each(|(lhs, rhs)| *lhs += rhs)
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize, B: Basis<M>> Sub for MatrixMN<T, M, N, B> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize, B: Basis<M>> SubAssign for MatrixMN<T, M, N, B> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.iter_mut()
+            .zip(rhs.0)
+            .for_each(|(lhs, rhs)| *lhs -= rhs)
+    }
+}
+
+// Matrix-Scalar arithmetic
+impl<T: Scalar, const M: usize, const N: usize, B: Basis<M>> Mul<T> for MatrixMN<T, M, N, B> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        MatrixMN(self.0.map(|column| column * rhs))
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize, B: Basis<M>> MulAssign<T> for MatrixMN<T, M, N, B> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0.iter_mut().for_each(|column| *column *= rhs)
+    }
+}
+
+// Matrix-Vector multiplication
+impl<T: Scalar, const M: usize, const N: usize, B: Basis<M> + Basis<N>> Mul<VectorN<T, N, B>> for MatrixMN<T, M, N, B> {
+    type Output = VectorN<T, M, B>;
+
+    #[inline]
+    fn mul(self, rhs: VectorN<T, N, B>) -> Self::Output {
+        self.0.into_iter()
+            .zip(rhs)
+            .fold(VectorN::new([T::ZERO; M]), |sum, (column, scalar)| sum + (column * scalar))
+    }
+}
+
+// Matrix-Matrix multiplication
+impl<T: Scalar, const M: usize, const N: usize, const P: usize, B: Basis<M> + Basis<N>> Mul<MatrixMN<T, N, P, B>> for MatrixMN<T, M, N, B> {
+    type Output = MatrixMN<T, M, P, B>;
+
+    #[inline]
+    fn mul(self, rhs: MatrixMN<T, N, P, B>) -> Self::Output {
+        MatrixMN(rhs.0.map(|column| self * column))
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> From<RotationMatrix<T, B>> for MatrixN<T, 3, B> {
+    fn from(rotation: RotationMatrix<T, B>) -> Self {
+        MatrixMN::from_row_major(rotation.to_row_major())
+    }
+}
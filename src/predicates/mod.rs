@@ -0,0 +1,337 @@
+//! Robust geometric predicates
+//!
+//! Orientation and in-circle/in-sphere tests, which return the exact sign of their determinant for floating point input.
+//! Naive floating point evaluation of these determinants can return the wrong sign for nearly degenerate input, which breaks the consistency algorithms such as triangulation rely on.
+//!
+//! Following Shewchuk ("Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric Predicates", 1997), each predicate is evaluated in floating point first, with a bound on the rounding error.
+//! Only where the result is too close to zero to determine its sign, the determinant is re-evaluated exactly using floating point expansions.
+//!
+//! Inputs must be finite, and intermediate products must neither overflow nor underflow; For `insphere`, these are products of up to five coordinate differences.
+//!
+//! ```
+//! use std::cmp::Ordering;
+//! use unifiedgeometry::geometry2d::Point2D;
+//! use unifiedgeometry::predicates::orient2d;
+//!
+//! let a = Point2D::<f64, ()>::new([0.5, 0.5]);
+//! let b = Point2D::new([12.0, 12.0]);
+//! let c = Point2D::new([24.0, 24.0]);
+//! assert_eq!(orient2d(a, b, c), Ordering::Equal);
+//! // The next representable value above 0.5 lies just left of the line through b and c
+//! assert_eq!(orient2d(Point2D::new([0.5, 0.5f64.next_up()]), b, c), Ordering::Greater);
+//! ```
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Sub};
+use crate::basis::{Basis, Handedness};
+use crate::geometry2d::Point2D;
+use crate::geometry3d::Point3D;
+use crate::scalar::Scalar;
+
+/// Floating point types supported by the robust predicates
+pub trait RobustFloat: Scalar {
+    /// Unit roundoff; Half of [`Scalar::EPSILON`], the largest relative error of a single rounded operation
+    const ROUNDOFF: Self;
+
+    /// Product `a * b` as an exact sum `high + low`
+    fn two_product(a: Self, b: Self) -> (Self, Self);
+}
+
+impl RobustFloat for f32 {
+    const ROUNDOFF: Self = f32::EPSILON / 2.0;
+
+    #[inline]
+    fn two_product(a: Self, b: Self) -> (Self, Self) {
+        let high = a * b;
+        (high, a.mul_add(b, -high))
+    }
+}
+
+impl RobustFloat for f64 {
+    const ROUNDOFF: Self = f64::EPSILON / 2.0;
+
+    #[inline]
+    fn two_product(a: Self, b: Self) -> (Self, Self) {
+        let high = a * b;
+        (high, a.mul_add(b, -high))
+    }
+}
+
+/// Orientation of `c` relative to the directed line from `a` to `b`
+///
+/// Returns `Greater` if `a`, `b` and `c` are in counter-clockwise order (`c` lies to the left of the line), `Less` if clockwise, and `Equal` if collinear;
+/// Counter-clockwise refers to a basis where the Y axis is counter-clockwise from the X axis.
+/// This is the sign of the determinant
+/// ```text
+/// | ax - cx   ay - cy |
+/// | bx - cx   by - cy |
+/// ```
+pub fn orient2d<T: RobustFloat, B: Basis<2>>(a: Point2D<T, B>, b: Point2D<T, B>, c: Point2D<T, B>) -> Ordering {
+    let ([ax, ay], [bx, by], [cx, cy]) = (a.to_array(), b.to_array(), c.to_array());
+    let left = (ax - cx) * (by - cy);
+    let right = (ay - cy) * (bx - cx);
+    let determinant = left - right;
+
+    let sum = if left > T::ZERO && right > T::ZERO {
+        left + right
+    } else if left < T::ZERO && right < T::ZERO {
+        -left - right
+    } else {
+        return sign(determinant);   // Terms of opposite sign (or zero) are subtracted without cancellation
+    };
+    let bound = error_bound::<T>(3, 16) * sum;
+    if determinant.abs() > bound {
+        return sign(determinant);
+    }
+
+    let [ax, ay, bx, by, cx, cy] = [ax, ay, bx, by, cx, cy].map(Expansion::from);
+    ((ax.clone() - cx.clone()) * (by - cy.clone()) - (ay - cy) * (bx - cx)).sign()
+}
+
+/// Orientation of `d` relative to the plane through `a`, `b` and `c`
+///
+/// Returns `Greater` if `d` lies below the plane, `Less` if above, and `Equal` if all four points are coplanar.
+/// "Above" is the direction of the [cross product](crate::geometry3d::Vector3D::cross_product) `(b - a) × (c - a)`; Viewed from above, `a`, `b` and `c` appear counter-clockwise.
+/// For bases of unknown handedness, this is the sign of the determinant
+/// ```text
+/// | ax - dx   ay - dy   az - dz |
+/// | bx - dx   by - dy   bz - dz |
+/// | cx - dx   cy - dy   cz - dz |
+/// ```
+/// which is negated for left-handed bases, consistent with the cross product.
+pub fn orient3d<T: RobustFloat, B: Basis<3>>(a: Point3D<T, B>, b: Point3D<T, B>, c: Point3D<T, B>, d: Point3D<T, B>) -> Ordering {
+    let [d_x, d_y, d_z] = d.to_array();
+    let [[adx, ady, adz], [bdx, bdy, bdz], [cdx, cdy, cdz]] = [a, b, c].map(|point| {
+        let [x, y, z] = point.to_array();
+        [x - d_x, y - d_y, z - d_z]
+    });
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let determinant = adz * (bdxcdy - cdxbdy) + bdz * (cdxady - adxcdy) + cdz * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * adz.abs()
+        + (cdxady.abs() + adxcdy.abs()) * bdz.abs()
+        + (adxbdy.abs() + bdxady.abs()) * cdz.abs();
+
+    let result = if determinant.abs() > error_bound::<T>(7, 56) * permanent {
+        sign(determinant)
+    } else {
+        let [a, b, c, d] = [a, b, c, d].map(|point| point.to_array().map(Expansion::from));
+        let [[adx, ady, adz], [bdx, bdy, bdz], [cdx, cdy, cdz]] = [a, b, c].map(|point| {
+            let [x, y, z] = point;
+            [x - d[0].clone(), y - d[1].clone(), z - d[2].clone()]
+        });
+        determinant3(
+            [adx, ady, adz],
+            [bdx, bdy, bdz],
+            [cdx, cdy, cdz],
+        ).sign()
+    };
+    oriented::<3, B>(result)
+}
+
+/// Position of `d` relative to the circle through `a`, `b` and `c`
+///
+/// If `a`, `b` and `c` are in counter-clockwise order (see [`orient2d`]), returns `Greater` if `d` lies inside the circle, `Less` if outside, and `Equal` if all four points are cocircular.
+/// For clockwise order, the result is reversed. This is the sign of the determinant
+/// ```text
+/// | ax - dx   ay - dy   (ax - dx)² + (ay - dy)² |
+/// | bx - dx   by - dy   (bx - dx)² + (by - dy)² |
+/// | cx - dx   cy - dy   (cx - dx)² + (cy - dy)² |
+/// ```
+pub fn incircle<T: RobustFloat, B: Basis<2>>(a: Point2D<T, B>, b: Point2D<T, B>, c: Point2D<T, B>, d: Point2D<T, B>) -> Ordering {
+    let [d_x, d_y] = d.to_array();
+    let [[adx, ady], [bdx, bdy], [cdx, cdy]] = [a, b, c].map(|point| {
+        let [x, y] = point.to_array();
+        [x - d_x, y - d_y]
+    });
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let (alift, blift, clift) = (adx * adx + ady * ady, bdx * bdx + bdy * bdy, cdx * cdx + cdy * cdy);
+    let determinant = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+    if determinant.abs() > error_bound::<T>(10, 96) * permanent {
+        return sign(determinant);
+    }
+
+    let [a, b, c, d] = [a, b, c, d].map(|point| point.to_array().map(Expansion::from));
+    let [a, b, c] = [a, b, c].map(|point| {
+        let [x, y] = point;
+        let (x, y) = (x - d[0].clone(), y - d[1].clone());
+        let lift = x.clone() * x.clone() + y.clone() * y.clone();
+        [x, y, lift]
+    });
+    determinant3(a, b, c).sign()
+}
+
+/// Position of `e` relative to the sphere through `a`, `b`, `c` and `d`
+///
+/// If [`orient3d`] of `a`, `b`, `c` and `d` is `Greater`, returns `Greater` if `e` lies inside the sphere, `Less` if outside, and `Equal` if all five points are cospherical.
+/// Otherwise, the result is reversed. For bases of unknown handedness, this is the sign of the determinant
+/// ```text
+/// | ax - ex   ay - ey   az - ez   (ax - ex)² + (ay - ey)² + (az - ez)² |
+/// | bx - ex   by - ey   bz - ez   (bx - ex)² + (by - ey)² + (bz - ez)² |
+/// | cx - ex   cy - ey   cz - ez   (cx - ex)² + (cy - ey)² + (cz - ez)² |
+/// | dx - ex   dy - ey   dz - ez   (dx - ex)² + (dy - ey)² + (dz - ez)² |
+/// ```
+/// which is negated for left-handed bases, consistent with [`orient3d`].
+pub fn insphere<T: RobustFloat, B: Basis<3>>(a: Point3D<T, B>, b: Point3D<T, B>, c: Point3D<T, B>, d: Point3D<T, B>, e: Point3D<T, B>) -> Ordering {
+    let [e_x, e_y, e_z] = e.to_array();
+    let [[aex, aey, aez], [bex, bey, bez], [cex, cey, cez], [dex, dey, dez]] = [a, b, c, d].map(|point| {
+        let [x, y, z] = point.to_array();
+        [x - e_x, y - e_y, z - e_z]
+    });
+    let (aexbey, bexaey) = (aex * bey, bex * aey);
+    let (bexcey, cexbey) = (bex * cey, cex * bey);
+    let (cexdey, dexcey) = (cex * dey, dex * cey);
+    let (dexaey, aexdey) = (dex * aey, aex * dey);
+    let (aexcey, cexaey) = (aex * cey, cex * aey);
+    let (bexdey, dexbey) = (bex * dey, dex * bey);
+    let (ab, bc, cd, da) = (aexbey - bexaey, bexcey - cexbey, cexdey - dexcey, dexaey - aexdey);
+    let (ac, bd) = (aexcey - cexaey, bexdey - dexbey);
+
+    let abc = aez * bc - bez * ac + cez * ab;
+    let bcd = bez * cd - cez * bd + dez * bc;
+    let cda = cez * da + dez * ac + aez * cd;
+    let dab = dez * ab + aez * bd + bez * da;
+    let alift = aex * aex + aey * aey + aez * aez;
+    let blift = bex * bex + bey * bey + bez * bez;
+    let clift = cex * cex + cey * cey + cez * cez;
+    let dlift = dex * dex + dey * dey + dez * dez;
+    let determinant = (dlift * abc - clift * dab) + (blift * cda - alift * bcd);
+
+    let (aez, bez, cez, dez) = (aez.abs(), bez.abs(), cez.abs(), dez.abs());
+    let (ab, bc, cd, da) = (aexbey.abs() + bexaey.abs(), bexcey.abs() + cexbey.abs(), cexdey.abs() + dexcey.abs(), dexaey.abs() + aexdey.abs());
+    let (ac, bd) = (aexcey.abs() + cexaey.abs(), bexdey.abs() + dexbey.abs());
+    let permanent = (cd * bez + bd * cez + bc * dez) * alift
+        + (da * cez + ac * dez + cd * aez) * blift
+        + (ab * dez + bd * aez + da * bez) * clift
+        + (bc * aez + ac * bez + ab * cez) * dlift;
+
+    let result = if determinant.abs() > error_bound::<T>(16, 224) * permanent {
+        sign(determinant)
+    } else {
+        let [a, b, c, d, e] = [a, b, c, d, e].map(|point| point.to_array().map(Expansion::from));
+        let [a, b, c, d] = [a, b, c, d].map(|point| {
+            let [x, y, z] = point;
+            let (x, y, z) = (x - e[0].clone(), y - e[1].clone(), z - e[2].clone());
+            let lift = x.clone() * x.clone() + y.clone() * y.clone() + z.clone() * z.clone();
+            [x, y, z, lift]
+        });
+        // Cofactor expansion along the lift column
+        let minor = |rows: [&[Expansion<T>; 4]; 3]| determinant3(
+            [rows[0][0].clone(), rows[0][1].clone(), rows[0][2].clone()],
+            [rows[1][0].clone(), rows[1][1].clone(), rows[1][2].clone()],
+            [rows[2][0].clone(), rows[2][1].clone(), rows[2][2].clone()],
+        );
+        let determinant = d[3].clone() * minor([&a, &b, &c]) - c[3].clone() * minor([&a, &b, &d])
+            + b[3].clone() * minor([&a, &c, &d]) - a[3].clone() * minor([&b, &c, &d]);
+        determinant.sign()
+    };
+    oriented::<3, B>(result)
+}
+
+/// Shewchuk's error bound coefficient `(a + bε)ε` for a determinant evaluated in floating point
+#[inline]
+fn error_bound<T: RobustFloat>(a: i32, b: i32) -> T {
+    (T::i(a) + T::i(b) * T::ROUNDOFF) * T::ROUNDOFF
+}
+
+#[inline]
+fn sign<T: RobustFloat>(value: T) -> Ordering {
+    value.partial_cmp(&T::ZERO).unwrap_or(Ordering::Equal)
+}
+
+/// Reverses the sign of a determinant for left-handed bases
+#[inline]
+fn oriented<const N: usize, B: Basis<N>>(sign: Ordering) -> Ordering {
+    match B::HANDEDNESS {
+        Some(Handedness::Left) => sign.reverse(),
+        _ => sign
+    }
+}
+
+/// Determinant of a 3x3 matrix of rows, expanded along the last column
+fn determinant3<T: RobustFloat>([ax, ay, az]: [Expansion<T>; 3], [bx, by, bz]: [Expansion<T>; 3], [cx, cy, cz]: [Expansion<T>; 3]) -> Expansion<T> {
+    az * (bx.clone() * cy.clone() - cx.clone() * by.clone())
+        + bz * (cx * ay.clone() - ax.clone() * cy)
+        + cz * (ax * by - bx * ay)
+}
+
+/// Exact sum of floating point components, without overlapping bits, in order of increasing magnitude
+///
+/// Zero components are eliminated, so the sign of the sum is the sign of the last component.
+#[derive(Clone, Debug)]
+struct Expansion<T>(Vec<T>);
+
+impl<T: RobustFloat> Expansion<T> {
+    /// Sum `a + b` as an exact sum `high + low`
+    #[inline]
+    fn two_sum(a: T, b: T) -> (T, T) {
+        let high = a + b;
+        let b_virtual = high - a;
+        let a_virtual = high - b_virtual;
+        (high, (a - a_virtual) + (b - b_virtual))
+    }
+
+    /// Exact sum of this expansion and `value`
+    fn grow(mut self, value: T) -> Self {
+        let mut carry = value;
+        let mut components = Vec::with_capacity(self.0.len() + 1);
+        for component in self.0.drain(..) {
+            let (high, low) = Self::two_sum(carry, component);
+            if low != T::ZERO {
+                components.push(low);
+            }
+            carry = high;
+        }
+        if carry != T::ZERO {
+            components.push(carry);
+        }
+        Expansion(components)
+    }
+
+    fn sign(&self) -> Ordering {
+        self.0.last().map_or(Ordering::Equal, |&last| sign(last))
+    }
+}
+
+impl<T: RobustFloat> From<T> for Expansion<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Expansion(Vec::new()).grow(value)
+    }
+}
+
+impl<T: RobustFloat> Add for Expansion<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        rhs.0.into_iter().fold(self, Expansion::grow)
+    }
+}
+
+impl<T: RobustFloat> Sub for Expansion<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        rhs.0.into_iter().fold(self, |sum, component| sum.grow(-component))
+    }
+}
+
+impl<T: RobustFloat> Mul for Expansion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut product = Expansion(Vec::new());
+        for &lhs in &self.0 {
+            for &rhs in &rhs.0 {
+                let (high, low) = T::two_product(lhs, rhs);
+                product = product.grow(low).grow(high);
+            }
+        }
+        product
+    }
+}
@@ -0,0 +1,364 @@
+//! Dual numbers for forward-mode automatic differentiation
+//!
+//! A dual number carries a value, and the gradient of that value with respect to `K` input variables.
+//! As [`DualN`] implements [`Scalar`], any computation generic over `Scalar` can be evaluated with dual numbers to obtain exact derivatives alongside the result.
+//!
+//! ```
+//! use unifiedgeometry::scalar::dual::Dual;
+//! use unifiedgeometry::scalar::Scalar;
+//!
+//! fn f<T: Scalar>(x: T) -> T {
+//!     x.sin() * x
+//! }
+//!
+//! let [x] = Dual::variables([2.0f64]);
+//! let result = f(x);
+//! assert_eq!(result.value(), 2.0f64.sin() * 2.0);
+//! assert_eq!(result.derivative(), 2.0f64.cos() * 2.0 + 2.0f64.sin());
+//! ```
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::approx::ApproxEq;
+use crate::scalar::{Field, Ring, Scalar};
+
+/// Dual number with a gradient of `K` components
+///
+/// Comparisons ([`PartialEq`], [`PartialOrd`]) consider only the value, such that generic code takes the same branches as it would for the plain value.
+/// At points where a function is not differentiable (e.g. [`Scalar::abs`] at zero, or the branches of [`Scalar::min`]), the derivative of one of the adjacent branches is used.
+#[derive(Copy, Clone, Debug)]
+pub struct DualN<T, const K: usize> {
+    value: T,
+    gradient: [T; K],
+}
+
+/// Dual number with a single derivative
+pub type Dual<T> = DualN<T, 1>;
+
+impl<T: Scalar, const K: usize> DualN<T, K> {
+    /// Create a new dual number from a value and its gradient
+    #[inline]
+    pub fn new(value: T, gradient: [T; K]) -> Self {
+        DualN { value, gradient }
+    }
+
+    /// Constant; All derivatives zero
+    #[inline]
+    pub fn constant(value: T) -> Self {
+        DualN { value, gradient: [T::ZERO; K] }
+    }
+
+    /// Input variable `index`; The derivative with respect to itself is one, all other derivatives are zero
+    ///
+    /// # Panics
+    /// If `index >= K`
+    #[inline]
+    pub fn variable(value: T, index: usize) -> Self {
+        let mut gradient = [T::ZERO; K];
+        gradient[index] = T::ONE;
+        DualN { value, gradient }
+    }
+
+    /// Input variables for all `K` gradient components, in order
+    #[inline]
+    pub fn variables(values: [T; K]) -> [Self; K] {
+        let mut index = 0;
+        values.map(|value| {
+            index += 1;
+            DualN::variable(value, index - 1)
+        })
+    }
+
+    /// Value, without derivatives
+    #[inline]
+    pub fn value(self) -> T {
+        self.value
+    }
+
+    /// Derivatives with respect to each input variable
+    #[inline]
+    pub fn gradient(self) -> [T; K] {
+        self.gradient
+    }
+
+    /// Applies the chain rule for a function `f` with `f(value) = value` and `f'(value) = derivative`
+    #[inline]
+    fn chain(value: T, gradient: [T; K], derivative: T) -> Self {
+        DualN { value, gradient: gradient.map(|component| component * derivative) }
+    }
+
+    /// True if any derivative is non-zero
+    #[inline]
+    fn is_variable(self) -> bool {
+        self.gradient.into_iter().any(|component| component != T::ZERO)
+    }
+}
+
+impl<T: Scalar> DualN<T, 1> {
+    /// Derivative with respect to the single input variable
+    #[inline]
+    pub fn derivative(self) -> T {
+        self.gradient[0]
+    }
+}
+
+impl<T: Scalar, const K: usize> PartialEq for DualN<T, K> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Scalar, const K: usize> PartialOrd for DualN<T, K> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+/// Compares the value and every derivative, unlike [`PartialEq`]; Only the values of the tolerances are used
+impl<T: Scalar + ApproxEq<Epsilon=T>, const K: usize> ApproxEq for DualN<T, K> {
+    type Epsilon = Self;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon { DualN::constant(T::default_epsilon()) }
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon { DualN::constant(T::default_max_relative()) }
+    #[inline]
+    fn default_max_ulps() -> u32 { T::default_max_ulps() }
+
+    #[inline]
+    fn abs_diff_eq(self, other: Self, epsilon: Self::Epsilon) -> bool {
+        self.value.abs_diff_eq(other.value, epsilon.value) && self.gradient.abs_diff_eq(other.gradient, epsilon.value)
+    }
+
+    #[inline]
+    fn relative_eq(self, other: Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self.value.relative_eq(other.value, epsilon.value, max_relative.value) && self.gradient.relative_eq(other.gradient, epsilon.value, max_relative.value)
+    }
+
+    #[inline]
+    fn ulps_eq(self, other: Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq(other.value, epsilon.value, max_ulps) && self.gradient.ulps_eq(other.gradient, epsilon.value, max_ulps)
+    }
+}
+
+impl<T: Scalar, const K: usize> Neg for DualN<T, K> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DualN { value: -self.value, gradient: self.gradient.map(T::neg) }
+    }
+}
+
+impl<T: Scalar, const K: usize> Add for DualN<T, K> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut gradient = self.gradient;
+        gradient.iter_mut().zip(rhs.gradient).for_each(|(lhs, rhs)| *lhs += rhs);
+        DualN { value: self.value + rhs.value, gradient }
+    }
+}
+
+impl<T: Scalar, const K: usize> Sub for DualN<T, K> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<T: Scalar, const K: usize> Mul for DualN<T, K> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]    // Product rule
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut gradient = self.gradient;
+        gradient.iter_mut().zip(rhs.gradient)
+            .for_each(|(lhs, rhs_component)| *lhs = (*lhs * rhs.value) + (self.value * rhs_component));
+        DualN { value: self.value * rhs.value, gradient }
+    }
+}
+
+impl<T: Scalar, const K: usize> Div for DualN<T, K> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]    // Quotient rule
+    fn div(self, rhs: Self) -> Self::Output {
+        let value = self.value / rhs.value;
+        let mut gradient = self.gradient;
+        gradient.iter_mut().zip(rhs.gradient)
+            .for_each(|(lhs, rhs_component)| *lhs = (*lhs - (value * rhs_component)) / rhs.value);
+        DualN { value, gradient }
+    }
+}
+
+impl<T: Scalar, const K: usize> AddAssign for DualN<T, K> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<T: Scalar, const K: usize> SubAssign for DualN<T, K> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<T: Scalar, const K: usize> MulAssign for DualN<T, K> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<T: Scalar, const K: usize> DivAssign for DualN<T, K> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<T: Scalar, const K: usize> Ring for DualN<T, K> {
+    const ZERO: Self = DualN { value: T::ZERO, gradient: [T::ZERO; K] };
+    const ONE: Self = DualN { value: T::ONE, gradient: [T::ZERO; K] };
+
+    fn i(literal: i32) -> Self {
+        DualN::constant(T::i(literal))
+    }
+}
+
+impl<T: Scalar, const K: usize> Field for DualN<T, K> {}
+
+impl<T: Scalar, const K: usize> Scalar for DualN<T, K> {
+    fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+        DualN::chain(value, self.gradient, T::ONE / (T::i(2) * value))
+    }
+
+    fn pow(self, exponent: Self) -> Self {
+        let value = self.value.pow(exponent.value);
+        let base_derivative = if exponent.value == T::ZERO { T::ZERO } else { exponent.value * self.value.pow(exponent.value - T::ONE) };
+        let result = DualN::chain(value, self.gradient, base_derivative);
+        if exponent.is_variable() {
+            // The logarithm of the base is only required (and only defined for positive bases) where the exponent varies
+            result + DualN::chain(T::ZERO, exponent.gradient, value * self.value.ln())
+        } else {
+            result
+        }
+    }
+
+    fn powi(self, exponent: i32) -> Self {
+        let derivative = if exponent == 0 { T::ZERO } else { T::i(exponent) * self.value.powi(exponent - 1) };
+        DualN::chain(self.value.powi(exponent), self.gradient, derivative)
+    }
+
+    fn powf(self, exponent: f64) -> Self {
+        let derivative = if exponent == 0.0 { T::ZERO } else { T::f(exponent) * self.value.powf(exponent - 1.0) };
+        DualN::chain(self.value.powf(exponent), self.gradient, derivative)
+    }
+
+    fn is_finite(self) -> bool {
+        self.value.is_finite() && self.gradient.into_iter().all(T::is_finite)
+    }
+
+    const PI: Self = DualN { value: T::PI, gradient: [T::ZERO; K] };
+
+    fn f(literal: f64) -> Self {
+        DualN::constant(T::f(literal))
+    }
+
+    fn sin(self) -> Self {
+        DualN::chain(self.value.sin(), self.gradient, self.value.cos())
+    }
+
+    fn cos(self) -> Self {
+        DualN::chain(self.value.cos(), self.gradient, -self.value.sin())
+    }
+
+    fn acos(self) -> Option<Self> {
+        let value = self.value.acos()?;
+        Some(DualN::chain(value, self.gradient, -T::ONE / (T::ONE - self.value.powi(2)).sqrt()))
+    }
+
+    fn asin(self) -> Option<Self> {
+        let value = self.value.asin()?;
+        Some(DualN::chain(value, self.gradient, T::ONE / (T::ONE - self.value.powi(2)).sqrt()))
+    }
+
+    fn tan(self) -> Self {
+        let value = self.value.tan();
+        DualN::chain(value, self.gradient, T::ONE + value.powi(2))
+    }
+
+    fn atan(self) -> Self {
+        DualN::chain(self.value.atan(), self.gradient, T::ONE / (T::ONE + self.value.powi(2)))
+    }
+
+    fn atan2(self, x: Self) -> Self {
+        let value = self.value.atan2(x.value);
+        let radius_squared = self.value.powi(2) + x.value.powi(2);
+        if radius_squared == T::ZERO {
+            return DualN::constant(value);  // Undefined at the origin
+        }
+        // ∂/∂y = x / r², ∂/∂x = -y / r²
+        DualN::chain(value, self.gradient, x.value / radius_squared)
+            + DualN::chain(T::ZERO, x.gradient, -self.value / radius_squared)
+    }
+
+    fn exp(self) -> Self {
+        let value = self.value.exp();
+        DualN::chain(value, self.gradient, value)
+    }
+
+    fn ln(self) -> Self {
+        DualN::chain(self.value.ln(), self.gradient, T::ONE / self.value)
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    const EPSILON: Self = DualN { value: T::EPSILON, gradient: [T::ZERO; K] };
+
+    fn abs(self) -> Self {
+        if self.value < T::ZERO { -self } else { self }
+    }
+
+    fn min(self, other: Self) -> Self {
+        // Follows `T::min` for NaN handling
+        if self.value.min(other.value) == self.value { self } else { other }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if self.value.max(other.value) == self.value { self } else { other }
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        let value = self.value.hypot(other.value);
+        if value == T::ZERO {
+            return DualN::constant(value);  // Not differentiable at the origin
+        }
+        DualN::chain(value, self.gradient, self.value / value)
+            + DualN::chain(T::ZERO, other.gradient, other.value / value)
+    }
+
+    fn floor(self) -> Self {
+        DualN::constant(self.value.floor())
+    }
+
+    fn ceil(self) -> Self {
+        DualN::constant(self.value.ceil())
+    }
+
+    fn signum(self) -> Self {
+        DualN::constant(self.value.signum())
+    }
+}
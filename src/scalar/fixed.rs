@@ -0,0 +1,555 @@
+//! Deterministic fixed-point numbers
+//!
+//! [`Fixed`] implements [`Scalar`] using integer arithmetic only, such that results are bit-identical on every platform.
+//! This makes it suitable for lockstep simulations, where all machines must reproduce the same geometry.
+//!
+//! ```
+//! use unifiedgeometry::scalar::fixed::Q32_32;
+//! use unifiedgeometry::scalar::Scalar;
+//!
+//! let x = Q32_32::f(0.5);
+//! assert_eq!(x.sin().to_raw(), 2059117009);   // Identical on every platform
+//! assert!((x.sin().to_f64() - 0.5f64.sin()).abs() <= 1.0 / 2f64.powi(32));
+//! ```
+//!
+//! # Accuracy
+//! Transcendental functions are evaluated at an internal precision of 60 fractional bits, and rounded to nearest.
+//! For `FRAC <= 48`, [`Scalar::sqrt`], [`Scalar::sin`], [`Scalar::cos`], [`Scalar::asin`], [`Scalar::acos`], [`Scalar::atan`], [`Scalar::atan2`], [`Scalar::ln`] and [`Scalar::hypot`] are within one unit in the last place (`2^-FRAC`) of the exact result.
+//! [`Scalar::tan`], [`Scalar::exp`], [`Scalar::pow`] and [`Scalar::powf`] amplify the internal error with the magnitude of the result, and are additionally within a relative error of `2^-50`.
+//! Basic arithmetic is rounded to nearest, except for repeated multiplication in [`Scalar::powi`].
+//!
+//! # Out of range values
+//! There are no infinities or NaN; [`Scalar::is_finite`] is always true.
+//! * Arithmetic saturates at the bounds of the representable range, including division by zero (`0 / 0` is zero)
+//! * [`Scalar::sqrt`] of negative values is zero, and [`Scalar::ln`] of non-positive values is the minimum value
+//! * [`Scalar::signum`] of zero is zero
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::approx::ApproxEq;
+use crate::scalar::{Field, Ring, Scalar};
+
+/// Fixed-point number with integer representation `I` and `FRAC` fractional bits
+///
+/// The value is `raw / 2^FRAC`. Implemented for `i32` and `i64`; `FRAC` must be less than the number of value bits of `I`, and at most 60.
+/// Other values of `FRAC` fail to compile wherever a value is constructed:
+///
+/// ```compile_fail
+/// use unifiedgeometry::scalar::fixed::Fixed;
+/// use unifiedgeometry::scalar::Ring;
+///
+/// let x = Fixed::<i32, 31>::i(1);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Fixed<I, const FRAC: u32>(I);
+
+/// Fixed-point number with 32 integer bits (including sign) and 32 fractional bits
+#[allow(non_camel_case_types)]
+pub type Q32_32 = Fixed<i64, 32>;
+
+/// Fixed-point number with 16 integer bits (including sign) and 16 fractional bits
+#[allow(non_camel_case_types)]
+pub type Q16_16 = Fixed<i32, 16>;
+
+/// Internal working precision, in fractional bits
+const W: u32 = 60;
+/// One, at working precision
+const ONE_W: i128 = 1 << W;
+/// PI, at working precision
+const PI_W: i128 = 3622009729038561421;
+/// PI / 2, at working precision, and the following 60 bits
+const HALF_PI_W: (i128, i128) = (1811004864519280710, 687235196273145913);
+/// Natural logarithm of 2, at working precision, and the following 60 bits
+const LN2_W: (i128, i128) = (799144290325165978, 849460365225095926);
+
+/// `value / 2^shift`, rounded to nearest
+#[inline]
+const fn round_shift(value: i128, shift: u32) -> i128 {
+    if shift == 0 { value } else { (value + (1 << (shift - 1))) >> shift }
+}
+
+/// `numerator / denominator`, rounded to nearest; Ties round away from zero
+#[inline]
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    let (quotient, remainder) = (numerator / denominator, numerator % denominator);
+    if remainder.unsigned_abs() * 2 >= denominator.unsigned_abs() {
+        quotient + if (numerator < 0) == (denominator < 0) { 1 } else { -1 }
+    } else {
+        quotient
+    }
+}
+
+/// Square root of a non-negative integer, rounded to nearest
+#[inline]
+fn sqrt_round(value: u128) -> u128 {
+    let root = value.isqrt();
+    // (root + 1/2)² = root² + root + 1/4
+    if value - root * root > root { root + 1 } else { root }
+}
+
+/// Product at working precision
+#[inline]
+fn mul_w(lhs: i128, rhs: i128) -> i128 {
+    round_shift(lhs * rhs, W)
+}
+
+/// Square root at working precision; Zero for negative values
+#[inline]
+fn sqrt_w(value: i128) -> i128 {
+    if value <= 0 { 0 } else { sqrt_round((value as u128) << W) as i128 }
+}
+
+/// Sine and cosine at working precision
+fn sin_cos_w(x: i128) -> (i128, i128) {
+    // Reduce to `[-π/4, π/4]` around the nearest multiple of π/2, using the extended constant for accuracy with large arguments
+    let quadrant = (x + HALF_PI_W.0 / 2).div_euclid(HALF_PI_W.0);
+    let reduced = x - quadrant * HALF_PI_W.0 - round_shift(quadrant * (HALF_PI_W.1 >> 30), 30);
+    let square = mul_w(reduced, reduced);
+
+    let (mut sin, mut cos) = (reduced, ONE_W);
+    let (mut sin_term, mut cos_term) = (reduced, ONE_W);
+    let mut n = 1;
+    while sin_term != 0 || cos_term != 0 {
+        sin_term = -mul_w(sin_term, square) / ((2 * n) * (2 * n + 1));
+        cos_term = -mul_w(cos_term, square) / ((2 * n - 1) * (2 * n));
+        sin += sin_term;
+        cos += cos_term;
+        n += 1;
+    }
+    match quadrant.rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// Arc-tangent of a value in `[-1, 1]` at working precision
+fn atan_unit_w(t: i128) -> i128 {
+    // atan(t) = 2 atan(t / (1 + √(1 + t²))); Applied twice to reduce to |t| <= tan(π/16) for fast convergence
+    let mut reduced = t;
+    for _ in 0..2 {
+        reduced = div_round(reduced << W, ONE_W + sqrt_w(ONE_W + mul_w(reduced, reduced)));
+    }
+    let square = mul_w(reduced, reduced);
+    let (mut sum, mut power) = (reduced, reduced);
+    let mut n = 1;
+    while power != 0 {
+        power = -mul_w(power, square);
+        sum += power / (2 * n + 1);
+        n += 1;
+    }
+    sum * 4
+}
+
+/// Four-quadrant arc-tangent of `y / x` at working precision; `y` and `x` may be at any common scale below `2^66`
+fn atan2_w(y: i128, x: i128) -> i128 {
+    if x == 0 && y == 0 {
+        0
+    } else if y.unsigned_abs() <= x.unsigned_abs() {
+        let angle = atan_unit_w(div_round(y << W, x));
+        match (x > 0, y >= 0) {
+            (true, _) => angle,
+            (false, true) => angle + PI_W,
+            (false, false) => angle - PI_W,
+        }
+    } else {
+        let angle = atan_unit_w(div_round(x << W, y));
+        if y > 0 { HALF_PI_W.0 - angle } else { -HALF_PI_W.0 - angle }
+    }
+}
+
+/// Natural logarithm of a positive value `raw / 2^frac`, at working precision
+fn ln_w(raw: i128, frac: u32) -> i128 {
+    // raw / 2^frac = m 2^exponent with m in [1, 2); ln(m) = 2 atanh((m - 1) / (m + 1))
+    let top_bit = 127 - raw.leading_zeros();
+    let exponent = top_bit as i128 - frac as i128;
+    let mantissa = if top_bit > W { round_shift(raw, top_bit - W) } else { raw << (W - top_bit) };
+    let z = div_round((mantissa - ONE_W) << W, mantissa + ONE_W);
+    let square = mul_w(z, z);
+    let (mut sum, mut power) = (z, z);
+    let mut n = 1;
+    while power != 0 {
+        power = mul_w(power, square);
+        sum += power / (2 * n + 1);
+        n += 1;
+    }
+    2 * sum + exponent * LN2_W.0 + round_shift(exponent * LN2_W.1, W)
+}
+
+/// Exponential function of a working precision value, as a raw value with `frac` fractional bits, saturating at `maximum`
+fn exp_w(x: i128, frac: u32, maximum: i128) -> i128 {
+    // e^x = e^r 2^k, with r in [0, ln 2)
+    let k = x.div_euclid(LN2_W.0);
+    if k >= 127 - W as i128 {
+        return maximum;
+    } else if k < -(frac as i128) - 2 {
+        return 0;
+    }
+    let reduced = x - k * LN2_W.0 - round_shift(k * LN2_W.1, W);
+    let (mut sum, mut term) = (ONE_W, ONE_W);
+    let mut n = 1;
+    while term != 0 {
+        term = mul_w(term, reduced) / n;
+        sum += term;
+        n += 1;
+    }
+    // Scale from working precision to `frac` fractional bits, and by 2^k
+    let shift = k + frac as i128 - W as i128;
+    if shift >= 0 {
+        if sum.leading_zeros() as i128 <= shift + 1 { maximum } else { (sum << shift).min(maximum) }
+    } else {
+        round_shift(sum, (-shift) as u32)
+    }
+}
+
+macro_rules! impl_fixed {
+    ($integer:ident) => {
+        impl<const FRAC: u32> Fixed<$integer, FRAC> {
+            /// Raw representation of one
+            const ONE_RAW: $integer = 1 << FRAC;
+
+            /// Compile-time validation of `FRAC`; Evaluated by [`from_raw`](Self::from_raw), through which every value is constructed
+            const VALID: () = {
+                assert!(FRAC < $integer::BITS - 1, "Fractional bits must leave room for the sign bit");
+                assert!(FRAC <= W, "Fractional bits must not exceed the working precision");
+            };
+
+            /// Create a fixed-point number from its raw representation `value * 2^FRAC`
+            #[inline]
+            pub const fn from_raw(raw: $integer) -> Self {
+                let () = Self::VALID;
+                Fixed(raw)
+            }
+
+            /// Raw representation `value * 2^FRAC`
+            #[inline]
+            pub const fn to_raw(self) -> $integer {
+                self.0
+            }
+
+            /// Nearest `f64` value
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / (1u64 << FRAC) as f64
+            }
+
+            /// Smallest representable value
+            pub const MIN: Self = Self::from_raw($integer::MIN);
+
+            /// Largest representable value
+            pub const MAX: Self = Self::from_raw($integer::MAX);
+
+            /// Value at working precision
+            #[inline]
+            fn to_w(self) -> i128 {
+                (self.0 as i128) << (W - FRAC)
+            }
+
+            /// Rounds and saturates a working precision value
+            #[inline]
+            fn from_w(value: i128) -> Self {
+                Self::saturate(round_shift(value, W - FRAC))
+            }
+
+            /// Saturates a raw value
+            #[inline]
+            fn saturate(raw: i128) -> Self {
+                Self::from_raw(raw.clamp($integer::MIN as i128, $integer::MAX as i128) as $integer)
+            }
+
+            /// `√(1 - self²)` at working precision, for `|self| <= 1`
+            #[inline]
+            fn complement_w(self) -> i128 {
+                let x = self.to_w();
+                sqrt_w(ONE_W - mul_w(x, x))
+            }
+        }
+
+        impl<const FRAC: u32> Display for Fixed<$integer, FRAC> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.to_f64(), f)
+            }
+        }
+
+        /// Compares raw representations exactly; A unit in the last place is [`Scalar::EPSILON`]
+        impl<const FRAC: u32> ApproxEq for Fixed<$integer, FRAC> {
+            type Epsilon = Self;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon { Self::EPSILON }
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon { Self::EPSILON }
+            #[inline]
+            fn default_max_ulps() -> u32 { 4 }
+
+            #[inline]
+            fn abs_diff_eq(self, other: Self, epsilon: Self::Epsilon) -> bool {
+                self == other || (self.0 as i128 - other.0 as i128).abs() <= epsilon.0 as i128
+            }
+
+            #[inline]
+            fn relative_eq(self, other: Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                // Both sides scaled by 2^FRAC, which fits as `FRAC` is at most 60
+                let largest = (self.0 as i128).abs().max((other.0 as i128).abs());
+                self.abs_diff_eq(other, epsilon) || (self.0 as i128 - other.0 as i128).abs() << FRAC <= largest * max_relative.0 as i128
+            }
+
+            #[inline]
+            fn ulps_eq(self, other: Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                self.abs_diff_eq(other, epsilon) || (self.0 as i128 - other.0 as i128).abs() <= max_ulps as i128
+            }
+        }
+
+        impl<const FRAC: u32> Neg for Fixed<$integer, FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self::from_raw(self.0.saturating_neg())
+            }
+        }
+
+        impl<const FRAC: u32> Add for Fixed<$integer, FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::from_raw(self.0.saturating_add(rhs.0))
+            }
+        }
+
+        impl<const FRAC: u32> Sub for Fixed<$integer, FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self::from_raw(self.0.saturating_sub(rhs.0))
+            }
+        }
+
+        impl<const FRAC: u32> Mul for Fixed<$integer, FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self::saturate(round_shift(self.0 as i128 * rhs.0 as i128, FRAC))
+            }
+        }
+
+        impl<const FRAC: u32> Div for Fixed<$integer, FRAC> {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]    // Rescaling of the dividend
+            fn div(self, rhs: Self) -> Self::Output {
+                match (rhs.0, self.0.cmp(&0)) {
+                    (0, Ordering::Less) => Self::MIN,
+                    (0, Ordering::Equal) => Self::ZERO,
+                    (0, Ordering::Greater) => Self::MAX,
+                    _ => Self::saturate(div_round((self.0 as i128) << FRAC, rhs.0 as i128)),
+                }
+            }
+        }
+
+        impl<const FRAC: u32> AddAssign for Fixed<$integer, FRAC> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs
+            }
+        }
+
+        impl<const FRAC: u32> SubAssign for Fixed<$integer, FRAC> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs
+            }
+        }
+
+        impl<const FRAC: u32> MulAssign for Fixed<$integer, FRAC> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs
+            }
+        }
+
+        impl<const FRAC: u32> DivAssign for Fixed<$integer, FRAC> {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs
+            }
+        }
+
+        impl<const FRAC: u32> Ring for Fixed<$integer, FRAC> {
+            const ZERO: Self = Self::from_raw(0);
+            const ONE: Self = Self::from_raw(Self::ONE_RAW);
+
+            #[inline]
+            fn i(literal: i32) -> Self {
+                Self::saturate((literal as i128) << FRAC)
+            }
+        }
+
+        impl<const FRAC: u32> Field for Fixed<$integer, FRAC> {}
+
+        impl<const FRAC: u32> Scalar for Fixed<$integer, FRAC> {
+            fn sqrt(self) -> Self {
+                if self.0 <= 0 {
+                    return Self::ZERO;
+                }
+                // √(raw / 2^FRAC) 2^FRAC = √(raw 2^FRAC)
+                Self::saturate(sqrt_round((self.0 as u128) << FRAC) as i128)
+            }
+
+            fn pow(self, exponent: Self) -> Self {
+                if exponent.0 & (Self::ONE_RAW - 1) == 0 && (exponent.0 >> FRAC) as i128 == (exponent.0 >> FRAC) as i32 as i128 {
+                    return self.powi((exponent.0 >> FRAC) as i32);
+                } else if self.0 == 0 {
+                    return if exponent.0 > 0 { Self::ZERO } else { Self::MAX };
+                } else if self.0 < 0 {
+                    return Self::ZERO;  // Negative bases are only defined for integer exponents
+                }
+                // e^(exponent ln(self)), at working precision
+                let logarithm = ln_w(self.0 as i128, FRAC);
+                match logarithm.checked_mul(exponent.0 as i128) {
+                    Some(product) => Self::from_raw(exp_w(round_shift(product, FRAC), FRAC, $integer::MAX as i128) as $integer),
+                    None if (logarithm > 0) == (exponent.0 > 0) => Self::MAX,
+                    None => Self::ZERO,
+                }
+            }
+
+            fn powi(self, exponent: i32) -> Self {
+                let mut result = Self::ONE;
+                let mut base = self;
+                let mut remaining = exponent.unsigned_abs();
+                while remaining != 0 {
+                    if remaining & 1 == 1 {
+                        result *= base;
+                    }
+                    base *= base;
+                    remaining >>= 1;
+                }
+                if exponent < 0 { Self::ONE / result } else { result }
+            }
+
+            fn powf(self, exponent: f64) -> Self {
+                self.pow(Self::f(exponent))
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                true
+            }
+
+            const PI: Self = Self::from_raw(round_shift(PI_W, W - FRAC) as $integer);
+
+            /// Nearest representable value; Deterministic, as conversion only scales by a power of two and rounds
+            #[inline]
+            fn f(literal: f64) -> Self {
+                Self::from_raw((literal * (1u64 << FRAC) as f64).round() as $integer)
+            }
+
+            fn sin(self) -> Self {
+                Self::from_w(sin_cos_w(self.to_w()).0)
+            }
+
+            fn cos(self) -> Self {
+                Self::from_w(sin_cos_w(self.to_w()).1)
+            }
+
+            fn acos(self) -> Option<Self> {
+                if self.0.unsigned_abs() > Self::ONE_RAW as _ {
+                    return None;
+                }
+                Some(Self::from_w(atan2_w(self.complement_w(), self.to_w())))
+            }
+
+            fn asin(self) -> Option<Self> {
+                if self.0.unsigned_abs() > Self::ONE_RAW as _ {
+                    return None;
+                }
+                Some(Self::from_w(atan2_w(self.to_w(), self.complement_w())))
+            }
+
+            fn tan(self) -> Self {
+                let (sin, cos) = sin_cos_w(self.to_w());
+                if cos == 0 {
+                    return if sin < 0 { Self::MIN } else { Self::MAX };
+                }
+                Self::saturate(div_round(sin << FRAC, cos))
+            }
+
+            fn atan(self) -> Self {
+                Self::from_w(atan2_w(self.0 as i128, Self::ONE_RAW as i128))
+            }
+
+            fn atan2(self, x: Self) -> Self {
+                Self::from_w(atan2_w(self.0 as i128, x.0 as i128))
+            }
+
+            fn exp(self) -> Self {
+                Self::from_raw(exp_w(self.to_w(), FRAC, $integer::MAX as i128) as $integer)
+            }
+
+            fn ln(self) -> Self {
+                if self.0 <= 0 {
+                    return Self::MIN;
+                }
+                Self::from_w(ln_w(self.0 as i128, FRAC))
+            }
+
+            fn log(self, base: Self) -> Self {
+                if self.0 <= 0 || base.0 <= 0 {
+                    return Self::MIN;
+                }
+                let (value, base) = (ln_w(self.0 as i128, FRAC), ln_w(base.0 as i128, FRAC));
+                if base == 0 {
+                    return Self::from_w(value) / Self::ZERO;   // Saturated, with the sign of the logarithm
+                }
+                Self::saturate(div_round(value << FRAC, base))
+            }
+
+            /// Smallest representable positive value
+            const EPSILON: Self = Self::from_raw(1);
+
+            #[inline]
+            fn abs(self) -> Self {
+                Self::from_raw(self.0.saturating_abs())
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                Ord::min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                Ord::max(self, other)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                // Exact sum of squares of the raw values, which shares the scale of the result
+                let sum = self.0.unsigned_abs() as u128 * self.0.unsigned_abs() as u128 + other.0.unsigned_abs() as u128 * other.0.unsigned_abs() as u128;
+                Self::saturate(sqrt_round(sum).min(i128::MAX as u128) as i128)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                Self::from_raw(self.0 & !(Self::ONE_RAW - 1))
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                Self::saturate(((self.0 as i128 + Self::ONE_RAW as i128 - 1) >> FRAC) << FRAC)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                Self::i(self.0.signum() as i32)
+            }
+        }
+    };
+}
+
+impl_fixed!(i32);
+impl_fixed!(i64);
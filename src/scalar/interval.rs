@@ -0,0 +1,648 @@
+//! Interval arithmetic for guaranteed error bounds
+//!
+//! An [`Interval`] encloses the exact real result of a computation; Every operation rounds its lower bound down and its upper bound up.
+//! As [`Interval`] implements [`Scalar`], any computation generic over `Scalar` can be evaluated with intervals to obtain a certified enclosure of the exact result.
+//!
+//! ```
+//! use unifiedgeometry::scalar::interval::Interval;
+//! use unifiedgeometry::scalar::Scalar;
+//!
+//! let tenth = Interval::<f64>::f(0.1);
+//! let sum = tenth + tenth + tenth;
+//! assert!(sum.contains(0.1 + 0.1 + 0.1));
+//! assert!(sum.contains(0.3));
+//! assert!(sum.lower() < sum.upper());
+//! ```
+//!
+//! Basic arithmetic and [`Scalar::sqrt`] are rounded exactly to the adjacent representable values.
+//! Other functions are evaluated with the platform implementation and widened by two units in the last place, which assumes the platform is accurate to within one unit in the last place.
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::approx::ApproxEq;
+use crate::scalar::{Field, Ring, Scalar};
+
+/// Floating point types usable as [`Interval`] bounds
+pub trait IntervalBound: Scalar {
+    /// Positive infinity
+    const INFINITY: Self;
+    /// Not-a-number
+    const NAN: Self;
+    /// Largest representable value below PI
+    const PI_LOWER: Self;
+    /// Smallest representable value above PI
+    const PI_UPPER: Self;
+
+    /// Next larger representable value, equivalent to [`f64::next_up`]
+    fn next_up(self) -> Self;
+    /// Next smaller representable value, equivalent to [`f64::next_down`]
+    fn next_down(self) -> Self;
+
+    /// Tightest bounds enclosing `value`
+    fn enclose_f64(value: f64) -> (Self, Self);
+    /// Nearest `f64` value
+    fn to_f64(self) -> f64;
+
+    /// `self + rhs`, rounded down and up
+    fn add_rounded(self, rhs: Self) -> (Self, Self);
+    /// `self * rhs`, rounded down and up
+    fn mul_rounded(self, rhs: Self) -> (Self, Self);
+    /// `self / rhs`, rounded down and up
+    fn div_rounded(self, rhs: Self) -> (Self, Self);
+    /// `self.sqrt()`, rounded down and up
+    fn sqrt_rounded(self) -> (Self, Self);
+}
+
+/// Rounds `result` down and up, given the sign of the exact `error` with respect to the rounded result
+///
+/// Non-finite errors occur on overflow, where the result is conservatively widened in both directions
+#[inline]
+fn round_by_error<T: IntervalBound>(result: T, error: T) -> (T, T) {
+    if error < T::ZERO {
+        (result.next_down(), result)
+    } else if error > T::ZERO {
+        (result, result.next_up())
+    } else if error == T::ZERO {
+        (result, result)
+    } else {
+        (result.next_down(), result.next_up())
+    }
+}
+
+macro_rules! impl_interval_bound {
+    ($float:ident) => {
+        impl IntervalBound for $float {
+            const INFINITY: Self = $float::INFINITY;
+            const NAN: Self = $float::NAN;
+            const PI_LOWER: Self = if (std::$float::consts::PI as f64) < std::f64::consts::PI {
+                std::$float::consts::PI
+            } else {
+                $float::from_bits(std::$float::consts::PI.to_bits() - 1)
+            };
+            const PI_UPPER: Self = if (std::$float::consts::PI as f64) > std::f64::consts::PI {
+                std::$float::consts::PI
+            } else {
+                $float::from_bits(std::$float::consts::PI.to_bits() + 1)
+            };
+
+            #[inline]
+            fn next_up(self) -> Self { $float::next_up(self) }
+            #[inline]
+            fn next_down(self) -> Self { $float::next_down(self) }
+
+            #[inline]
+            fn enclose_f64(value: f64) -> (Self, Self) {
+                let rounded = value as $float;
+                // Only the sign of the error is required, which may not survive conversion to a narrower type
+                let error = if rounded as f64 == value { 0.0 } else { (value - rounded as f64).signum() };
+                round_by_error(rounded, error as $float)
+            }
+            #[inline]
+            fn to_f64(self) -> f64 { self as f64 }
+
+            #[inline]
+            fn add_rounded(self, rhs: Self) -> (Self, Self) {
+                // Knuth's TwoSum; The rounding error of a sum is exactly representable
+                let sum = self + rhs;
+                let rhs_part = sum - self;
+                let error = (self - (sum - rhs_part)) + (rhs - rhs_part);
+                round_by_error(sum, error)
+            }
+            #[inline]
+            fn mul_rounded(self, rhs: Self) -> (Self, Self) {
+                let product = self * rhs;
+                round_by_error(product, self.mul_add(rhs, -product))
+            }
+            #[inline]
+            fn div_rounded(self, rhs: Self) -> (Self, Self) {
+                // The remainder `self - quotient * rhs` is exact, and has the sign of the error times the sign of `rhs`
+                let quotient = self / rhs;
+                let remainder = -quotient.mul_add(rhs, -self);
+                round_by_error(quotient, if rhs < 0.0 { -remainder } else { remainder })
+            }
+            #[inline]
+            fn sqrt_rounded(self) -> (Self, Self) {
+                let root = self.sqrt();
+                round_by_error(root, -root.mul_add(root, -self))
+            }
+        }
+    };
+}
+
+impl_interval_bound!(f32);
+impl_interval_bound!(f64);
+
+/// Closed interval `[lower, upper]` of real numbers
+///
+/// Comparisons are *certain*: `a < b` only if every value in `a` is less than every value in `b`, and `a == b` only if both are the same single value.
+/// Comparisons that cannot be decided ([`PartialOrd::partial_cmp`] returns `None`) are false, such that generic code branching on them takes the same path as it would for NaN.
+/// Enclosures are only certified for the path taken; Use [`Interval::lower`] and [`Interval::upper`] where both outcomes of a comparison matter.
+///
+/// Functions with a restricted domain ([`Scalar::sqrt`], [`Scalar::ln`], [`Scalar::acos`], [`Scalar::asin`], ...) are evaluated on the part of the interval inside their domain.
+/// An interval entirely outside the domain results in NaN bounds, or `None` for [`Scalar::acos`] and [`Scalar::asin`].
+/// Division by an interval containing zero results in the entire real line.
+#[derive(Copy, Clone, Debug)]
+pub struct Interval<T> {
+    lower: T,
+    upper: T,
+}
+
+impl<T: IntervalBound> Interval<T> {
+    /// Create a new interval from its bounds
+    ///
+    /// Returns `None` if `lower > upper`, or either bound is NaN
+    #[inline]
+    pub fn new(lower: T, upper: T) -> Option<Self> {
+        if lower <= upper { Some(Interval { lower, upper }) } else { None }
+    }
+
+    /// Interval containing only `value`
+    #[inline]
+    pub fn point(value: T) -> Self {
+        Interval { lower: value, upper: value }
+    }
+
+    /// Interval containing the entire real line
+    #[inline]
+    pub fn entire() -> Self {
+        Interval { lower: -T::INFINITY, upper: T::INFINITY }
+    }
+
+    /// Lower bound
+    #[inline]
+    pub fn lower(self) -> T {
+        self.lower
+    }
+
+    /// Upper bound
+    #[inline]
+    pub fn upper(self) -> T {
+        self.upper
+    }
+
+    /// Width `upper - lower`, rounded up
+    #[inline]
+    pub fn width(self) -> T {
+        self.upper.add_rounded(-self.lower).1
+    }
+
+    /// Midpoint, rounded to nearest; Not necessarily the exact midpoint, but always contained in the interval
+    #[inline]
+    pub fn midpoint(self) -> T {
+        (self.lower / T::i(2) + self.upper / T::i(2)).max(self.lower).min(self.upper)
+    }
+
+    /// True if `value` lies within this interval, bounds inclusive
+    #[inline]
+    pub fn contains(self, value: T) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+
+    /// True if this interval contains only a single value
+    #[inline]
+    pub fn is_point(self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// True if both intervals share at least one value
+    #[inline]
+    pub fn overlaps(self, other: Self) -> bool {
+        self.lower <= other.upper && other.lower <= self.upper
+    }
+
+    /// Smallest interval containing both intervals
+    #[inline]
+    pub fn hull(self, other: Self) -> Self {
+        Interval { lower: self.lower.min(other.lower), upper: self.upper.max(other.upper) }
+    }
+
+    /// Interval of values contained in both intervals
+    ///
+    /// Returns `None` if the intervals do not overlap
+    #[inline]
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        Interval::new(self.lower.max(other.lower), self.upper.min(other.upper))
+    }
+
+    /// Interval with NaN bounds, the result of operations entirely outside of their domain
+    #[inline]
+    fn nan() -> Self {
+        Interval { lower: T::NAN, upper: T::NAN }
+    }
+
+    /// Enclosure of a monotonically increasing function with bounds `f(lower)` and `f(upper)` computed by the platform
+    #[inline]
+    fn widened(lower: T, upper: T) -> Self {
+        Interval { lower: lower.next_down().next_down(), upper: upper.next_up().next_up() }
+    }
+
+    /// Part of this interval where `minimum <= x`
+    #[inline]
+    fn clamp_lower(self, minimum: T) -> Option<Self> {
+        if self.upper >= minimum {
+            Some(Interval { lower: self.lower.max(minimum), upper: self.upper })
+        } else {
+            None
+        }
+    }
+
+    /// Checks which extrema of a periodic function with period 2π this interval may contain, where the maxima are at `2kπ + offset·π` and the minima at `(2k + 1)π + offset·π`
+    ///
+    /// Returns `None` if the interval may span a full period. Errs towards reporting extrema, as including an extremum only widens the enclosure
+    fn periodic_extrema(self, offset: T) -> Option<(bool, bool)> {
+        let slack = |value: T| (value.abs() + T::ONE) * T::i(4) * T::EPSILON;
+        let start = self.lower / T::PI - offset;
+        let end = self.upper / T::PI - offset;
+        let (start, end) = (start - slack(start), end + slack(end));
+        if !start.is_finite() || !end.is_finite() || end - start >= T::i(2) {
+            return None;
+        }
+        let (mut maximum, mut minimum) = (false, false);
+        let mut multiple = start.ceil();
+        while multiple <= end {
+            if (multiple / T::i(2)).floor() * T::i(2) == multiple {
+                maximum = true;
+            } else {
+                minimum = true;
+            }
+            multiple += T::ONE;
+        }
+        Some((maximum, minimum))
+    }
+
+    /// Enclosure of a function with period 2π and range `[-1, 1]`, see [`Interval::periodic_extrema`]
+    fn periodic(self, offset: T, f: impl Fn(T) -> T) -> Self {
+        let Some((maximum, minimum)) = self.periodic_extrema(offset) else {
+            return Interval { lower: -T::ONE, upper: T::ONE };
+        };
+        let (at_lower, at_upper) = (f(self.lower), f(self.upper));
+        let bounds = Interval::widened(at_lower.min(at_upper), at_lower.max(at_upper));
+        Interval {
+            lower: if minimum { -T::ONE } else { bounds.lower.max(-T::ONE) },
+            upper: if maximum { T::ONE } else { bounds.upper.min(T::ONE) },
+        }
+    }
+
+    /// `base^exponent` for `base >= 0`, rounded down or up
+    fn powu_rounded(base: T, exponent: u32, up: bool) -> T {
+        // All partial products are non-negative, so rounding each step in the same direction is conservative
+        let mut result = T::ONE;
+        for _ in 0..exponent {
+            let (down, rounded_up) = result.mul_rounded(base);
+            result = if up { rounded_up } else { down };
+        }
+        result
+    }
+
+    /// `self^exponent` for a positive exponent
+    fn powu(self, exponent: u32) -> Self {
+        if exponent % 2 == 1 {
+            // Odd powers are monotonically increasing
+            let bound = |value: T, up: bool| if value < T::ZERO {
+                -Interval::powu_rounded(-value, exponent, !up)
+            } else {
+                Interval::powu_rounded(value, exponent, up)
+            };
+            Interval { lower: bound(self.lower, false), upper: bound(self.upper, true) }
+        } else {
+            let magnitude = self.abs();
+            Interval {
+                lower: Interval::powu_rounded(magnitude.lower, exponent, false),
+                upper: Interval::powu_rounded(magnitude.upper, exponent, true),
+            }
+        }
+    }
+
+    /// Enclosure of a function which is monotonic in each argument separately, over the box `self × other`, from the values at its corners
+    fn corners(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        let values = [f(self.lower, other.lower), f(self.lower, other.upper), f(self.upper, other.lower), f(self.upper, other.upper)];
+        let lower = values.into_iter().fold(values[0], T::min);
+        let upper = values.into_iter().fold(values[0], T::max);
+        Interval::widened(lower, upper)
+    }
+}
+
+impl<T: IntervalBound> From<T> for Interval<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Interval::point(value)
+    }
+}
+
+impl<T: IntervalBound> PartialEq for Interval<T> {
+    /// Certainly equal; Both intervals are the same single value
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.lower == self.upper && self.lower == other.lower && self.upper == other.upper
+    }
+}
+
+impl<T: IntervalBound> PartialOrd for Interval<T> {
+    /// `Less` if every value of `self` is less than every value of `other`, `Greater` if every value is greater, `Equal` if both are the same single value, and `None` otherwise
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.upper < other.lower {
+            Some(Ordering::Less)
+        } else if self.lower > other.upper {
+            Some(Ordering::Greater)
+        } else if self == other {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+/// Compares the lower and upper bounds, unlike the certain comparison of [`PartialEq`]; Only the upper bounds of the tolerances are used
+impl<T: IntervalBound + ApproxEq<Epsilon=T>> ApproxEq for Interval<T> {
+    type Epsilon = Self;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon { Interval::point(T::default_epsilon()) }
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon { Interval::point(T::default_max_relative()) }
+    #[inline]
+    fn default_max_ulps() -> u32 { T::default_max_ulps() }
+
+    #[inline]
+    fn abs_diff_eq(self, other: Self, epsilon: Self::Epsilon) -> bool {
+        self.lower.abs_diff_eq(other.lower, epsilon.upper) && self.upper.abs_diff_eq(other.upper, epsilon.upper)
+    }
+
+    #[inline]
+    fn relative_eq(self, other: Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self.lower.relative_eq(other.lower, epsilon.upper, max_relative.upper) && self.upper.relative_eq(other.upper, epsilon.upper, max_relative.upper)
+    }
+
+    #[inline]
+    fn ulps_eq(self, other: Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.lower.ulps_eq(other.lower, epsilon.upper, max_ulps) && self.upper.ulps_eq(other.upper, epsilon.upper, max_ulps)
+    }
+}
+
+impl<T: IntervalBound> Neg for Interval<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Interval { lower: -self.upper, upper: -self.lower }
+    }
+}
+
+impl<T: IntervalBound> Add for Interval<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Interval { lower: self.lower.add_rounded(rhs.lower).0, upper: self.upper.add_rounded(rhs.upper).1 }
+    }
+}
+
+impl<T: IntervalBound> Sub for Interval<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<T: IntervalBound> Mul for Interval<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let products = [
+            self.lower.mul_rounded(rhs.lower),
+            self.lower.mul_rounded(rhs.upper),
+            self.upper.mul_rounded(rhs.lower),
+            self.upper.mul_rounded(rhs.upper),
+        ];
+        Interval {
+            lower: products.into_iter().fold(products[0].0, |lower, (down, _)| lower.min(down)),
+            upper: products.into_iter().fold(products[0].1, |upper, (_, up)| upper.max(up)),
+        }
+    }
+}
+
+impl<T: IntervalBound> Div for Interval<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.contains(T::ZERO) {
+            return Interval::entire();
+        }
+        let quotients = [
+            self.lower.div_rounded(rhs.lower),
+            self.lower.div_rounded(rhs.upper),
+            self.upper.div_rounded(rhs.lower),
+            self.upper.div_rounded(rhs.upper),
+        ];
+        Interval {
+            lower: quotients.into_iter().fold(quotients[0].0, |lower, (down, _)| lower.min(down)),
+            upper: quotients.into_iter().fold(quotients[0].1, |upper, (_, up)| upper.max(up)),
+        }
+    }
+}
+
+impl<T: IntervalBound> AddAssign for Interval<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<T: IntervalBound> SubAssign for Interval<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<T: IntervalBound> MulAssign for Interval<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<T: IntervalBound> DivAssign for Interval<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<T: IntervalBound> Ring for Interval<T> {
+    const ZERO: Self = Interval { lower: T::ZERO, upper: T::ZERO };
+    const ONE: Self = Interval { lower: T::ONE, upper: T::ONE };
+
+    fn i(literal: i32) -> Self {
+        let (lower, upper) = T::enclose_f64(literal as f64);
+        Interval { lower, upper }
+    }
+}
+
+impl<T: IntervalBound> Field for Interval<T> {}
+
+impl<T: IntervalBound> Scalar for Interval<T> {
+    fn sqrt(self) -> Self {
+        let Some(domain) = self.clamp_lower(T::ZERO) else {
+            return Interval::nan();
+        };
+        Interval { lower: domain.lower.sqrt_rounded().0, upper: domain.upper.sqrt_rounded().1 }
+    }
+
+    fn pow(self, exponent: Self) -> Self {
+        if exponent.is_point() {
+            return self.powf(exponent.lower.to_f64());
+        }
+        // Negative bases are only defined for integer exponents
+        let Some(domain) = self.clamp_lower(T::ZERO) else {
+            return Interval::nan();
+        };
+        domain.corners(exponent, T::pow).clamp_lower(T::ZERO).unwrap_or_else(Interval::nan)
+    }
+
+    fn powi(self, exponent: i32) -> Self {
+        match exponent {
+            0 => Interval::ONE,
+            1.. => self.powu(exponent as u32),
+            _ => Interval::ONE / self.powu(exponent.unsigned_abs()),
+        }
+    }
+
+    fn powf(self, exponent: f64) -> Self {
+        if exponent.fract() == 0.0 && exponent.abs() <= i32::MAX as f64 {
+            return self.powi(exponent as i32);
+        }
+        let Some(domain) = self.clamp_lower(T::ZERO) else {
+            return Interval::nan();
+        };
+        let result = if exponent > 0.0 {
+            Interval::widened(domain.lower.powf(exponent), domain.upper.powf(exponent))
+        } else {
+            Interval::widened(domain.upper.powf(exponent), domain.lower.powf(exponent))
+        };
+        result.clamp_lower(T::ZERO).unwrap_or(result)
+    }
+
+    fn is_finite(self) -> bool {
+        self.lower.is_finite() && self.upper.is_finite()
+    }
+
+    const PI: Self = Interval { lower: T::PI_LOWER, upper: T::PI_UPPER };
+
+    /// Encloses the `f64` value of `literal`; Decimal literals such as `0.1` are rounded to `f64` before this conversion
+    fn f(literal: f64) -> Self {
+        let (lower, upper) = T::enclose_f64(literal);
+        Interval { lower, upper }
+    }
+
+    fn sin(self) -> Self {
+        self.periodic(T::ONE / T::i(2), T::sin)
+    }
+
+    fn cos(self) -> Self {
+        self.periodic(T::ZERO, T::cos)
+    }
+
+    fn acos(self) -> Option<Self> {
+        let domain = self.clamp_lower(-T::ONE)?;
+        if domain.lower > T::ONE {
+            return None;
+        }
+        let widened = Interval::widened(domain.upper.min(T::ONE).acos()?, domain.lower.acos()?);
+        Some(Interval { lower: widened.lower.max(T::ZERO), upper: widened.upper.min(T::PI_UPPER) })
+    }
+
+    fn asin(self) -> Option<Self> {
+        let domain = self.clamp_lower(-T::ONE)?;
+        if domain.lower > T::ONE {
+            return None;
+        }
+        let widened = Interval::widened(domain.lower.asin()?, domain.upper.min(T::ONE).asin()?);
+        let half_pi = T::PI_UPPER / T::i(2);
+        Some(Interval { lower: widened.lower.max(-half_pi), upper: widened.upper.min(half_pi) })
+    }
+
+    fn tan(self) -> Self {
+        // Monotonically increasing between the poles at `(k + 1/2)π`
+        match self.periodic_extrema(T::ONE / T::i(2)) {
+            Some((false, false)) => Interval::widened(self.lower.tan(), self.upper.tan()),
+            _ => Interval::entire(),
+        }
+    }
+
+    fn atan(self) -> Self {
+        let half_pi = T::PI_UPPER / T::i(2);
+        let widened = Interval::widened(self.lower.atan(), self.upper.atan());
+        Interval { lower: widened.lower.max(-half_pi), upper: widened.upper.min(half_pi) }
+    }
+
+    fn atan2(self, x: Self) -> Self {
+        let full = Interval { lower: -T::PI_UPPER, upper: T::PI_UPPER };
+        // Discontinuous at the origin, and across the negative X axis; Positive zero `y` lies on the `π` side of the branch cut
+        if (self.contains(T::ZERO) && x.contains(T::ZERO)) || (x.lower < T::ZERO && self.lower < T::ZERO && self.upper >= T::ZERO) {
+            return full;
+        }
+        let y = Interval { lower: self.lower + T::ZERO, upper: self.upper };
+        let widened = y.corners(x, T::atan2);
+        Interval { lower: widened.lower.max(full.lower), upper: widened.upper.min(full.upper) }
+    }
+
+    fn exp(self) -> Self {
+        let widened = Interval::widened(self.lower.exp(), self.upper.exp());
+        Interval { lower: widened.lower.max(T::ZERO), upper: widened.upper }
+    }
+
+    fn ln(self) -> Self {
+        match self.clamp_lower(T::ZERO) {
+            Some(domain) => Interval::widened(domain.lower.ln(), domain.upper.ln()),
+            None => Interval::nan()
+        }
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    const EPSILON: Self = Interval { lower: T::EPSILON, upper: T::EPSILON };
+
+    fn abs(self) -> Self {
+        if self.lower >= T::ZERO {
+            self
+        } else if self.upper <= T::ZERO {
+            -self
+        } else {
+            Interval { lower: T::ZERO, upper: self.upper.max(-self.lower) }
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        Interval { lower: self.lower.min(other.lower), upper: self.upper.min(other.upper) }
+    }
+
+    fn max(self, other: Self) -> Self {
+        Interval { lower: self.lower.max(other.lower), upper: self.upper.max(other.upper) }
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        // Monotonically increasing in the magnitude of both arguments
+        let (x, y) = (self.abs(), other.abs());
+        let widened = Interval::widened(x.lower.hypot(y.lower), x.upper.hypot(y.upper));
+        Interval { lower: widened.lower.max(x.lower).max(y.lower), upper: widened.upper }
+    }
+
+    fn floor(self) -> Self {
+        Interval { lower: self.lower.floor(), upper: self.upper.floor() }
+    }
+
+    fn ceil(self) -> Self {
+        Interval { lower: self.lower.ceil(), upper: self.upper.ceil() }
+    }
+
+    fn signum(self) -> Self {
+        Interval { lower: self.lower.signum(), upper: self.upper.signum() }
+    }
+}
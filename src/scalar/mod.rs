@@ -1,7 +1,18 @@
+pub mod dual;
+pub mod fixed;
+pub mod interval;
+pub mod rational;
+
 use std::fmt::Debug;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-/// Trait for Real number "scalar" types; Those that implement addition/subtraction/multiplication/division, as well as exponentiation.
+pub use rational::Rational;
+/// Real number layer of the scalar trait hierarchy, see [`Scalar`]
+pub use Scalar as Real;
+
+/// Trait for ring scalar types; Those that implement addition/subtraction/multiplication, such as integers
+///
+/// Sufficient for exact computations which do not divide, such as dot products, cross products and orientation tests.
 ///
 /// Additionally requires the following other traits:
 /// * Sized         (Used a component type)
@@ -11,9 +22,7 @@ use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssi
 /// * Neg           (Multiplication by integer `-1`)
 ///
 /// Addition and multiplication **must** be commutative
-///
-/// Does not support non-real scalars/complex vector spaces
-pub trait Scalar:
+pub trait Ring:
     Sized
     + Debug
     + Copy
@@ -26,12 +35,28 @@ pub trait Scalar:
     + SubAssign<Self>
     + Mul<Self, Output=Self>
     + MulAssign<Self>
-    + Div<Self, Output=Self>
-    + DivAssign<Self>
 {
     /// Constant value zero
     const ZERO: Self;
+    /// Constant value one
+    const ONE: Self;
 
+    /// Integer literal
+    fn i(literal: i32) -> Self;
+}
+
+/// Trait for field scalar types; [`Ring`]s that additionally implement division, such as [`Rational`]
+///
+/// Division by zero is defined by the implementing type; Floating point types produce infinities or NaN, exact types panic
+pub trait Field: Ring + Div<Self, Output=Self> + DivAssign<Self> {}
+
+/// Trait for Real number "scalar" types; [`Field`]s that additionally implement exponentiation, trigonometry and other transcendental functions
+///
+/// This is the `Real` layer of the [`Ring`] → [`Field`] → [`Real`] hierarchy, and is available under both names.
+/// Most geometry requires this layer, as lengths and angles involve square roots and trigonometry.
+///
+/// Does not support non-real scalars/complex vector spaces
+pub trait Scalar: Field {
     /// Square root, equivalent to [`f64::sqrt`]
     fn sqrt(self) -> Self;
 
@@ -53,8 +78,6 @@ pub trait Scalar:
     /// Constant value PI
     const PI: Self;
 
-    /// Integer literal
-    fn i(literal: i32) -> Self;
     /// Floating point literal
     fn f(literal: f64) -> Self;
 
@@ -68,11 +91,52 @@ pub trait Scalar:
     /// Arc-sine
     /// Returns `None` if input is out of range
     fn asin(self) -> Option<Self>;
+    /// Tangent, equivalent to [`f64::tan`]
+    fn tan(self) -> Self;
+    /// Arc-tangent, in the range `[-PI/2, PI/2]`, equivalent to [`f64::atan`]
+    fn atan(self) -> Self;
+    /// Four-quadrant arc-tangent of `self / x` (`self` being the Y coordinate), in the range `[-PI, PI]`, equivalent to [`f64::atan2`]
+    fn atan2(self, x: Self) -> Self;
+
+    /// Exponential function `e^self`, equivalent to [`f64::exp`]
+    fn exp(self) -> Self;
+    /// Natural logarithm, equivalent to [`f64::ln`]
+    fn ln(self) -> Self;
+    /// Logarithm with arbitrary base, equivalent to [`f64::log`]
+    fn log(self, base: Self) -> Self;
+
+    /// Difference between `1` and the next larger representable value, equivalent to [`f64::EPSILON`]
+    ///
+    /// For exact types, the smallest representable positive value
+    const EPSILON: Self;
+
+    /// Absolute value, equivalent to [`f64::abs`]
+    fn abs(self) -> Self;
+    /// Minimum of two values, equivalent to [`f64::min`]
+    fn min(self, other: Self) -> Self;
+    /// Maximum of two values, equivalent to [`f64::max`]
+    fn max(self, other: Self) -> Self;
+    /// Length of the hypotenuse `√(self² + other²)`, without intermediate overflow or underflow, equivalent to [`f64::hypot`]
+    fn hypot(self, other: Self) -> Self;
+    /// Largest integer less than or equal to `self`, equivalent to [`f64::floor`]
+    fn floor(self) -> Self;
+    /// Smallest integer greater than or equal to `self`, equivalent to [`f64::ceil`]
+    fn ceil(self) -> Self;
+    /// Sign of `self`; `1` for positive values and `-1` for negative values, equivalent to [`f64::signum`]
+    fn signum(self) -> Self;
 }
 
-impl Scalar for f32 {
+impl Ring for f32 {
     const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
 
+    #[inline]
+    fn i(literal: i32) -> Self { literal as f32 }
+}
+
+impl Field for f32 {}
+
+impl Scalar for f32 {
     #[inline]
     fn sqrt(self) -> Self { self.sqrt() }
     #[inline]
@@ -86,8 +150,6 @@ impl Scalar for f32 {
 
     const PI: Self = std::f32::consts::PI;
 
-    #[inline]
-    fn i(literal: i32) -> Self { literal as f32 }
     #[inline]
     fn f(literal: f64) -> Self { literal as f32 }
     #[inline]
@@ -100,11 +162,49 @@ impl Scalar for f32 {
     fn asin(self) -> Option<Self> {
         Some(f32::asin(self)).filter(|f| !f.is_nan())
     }
+    #[inline]
+    fn tan(self) -> Self { f32::tan(self) }
+    #[inline]
+    fn atan(self) -> Self { f32::atan(self) }
+    #[inline]
+    fn atan2(self, x: Self) -> Self { f32::atan2(self, x) }
+
+    #[inline]
+    fn exp(self) -> Self { f32::exp(self) }
+    #[inline]
+    fn ln(self) -> Self { f32::ln(self) }
+    #[inline]
+    fn log(self, base: Self) -> Self { f32::log(self, base) }
+
+    const EPSILON: Self = f32::EPSILON;
+
+    #[inline]
+    fn abs(self) -> Self { f32::abs(self) }
+    #[inline]
+    fn min(self, other: Self) -> Self { f32::min(self, other) }
+    #[inline]
+    fn max(self, other: Self) -> Self { f32::max(self, other) }
+    #[inline]
+    fn hypot(self, other: Self) -> Self { f32::hypot(self, other) }
+    #[inline]
+    fn floor(self) -> Self { f32::floor(self) }
+    #[inline]
+    fn ceil(self) -> Self { f32::ceil(self) }
+    #[inline]
+    fn signum(self) -> Self { f32::signum(self) }
 }
 
-impl Scalar for f64 {
+impl Ring for f64 {
     const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
 
+    #[inline]
+    fn i(literal: i32) -> Self { literal as f64 }
+}
+
+impl Field for f64 {}
+
+impl Scalar for f64 {
     #[inline]
     fn sqrt(self) -> Self { self.sqrt() }
     #[inline]
@@ -118,8 +218,6 @@ impl Scalar for f64 {
 
     const PI: Self = std::f64::consts::PI;
 
-    #[inline]
-    fn i(literal: i32) -> Self { literal as f64 }
     #[inline]
     fn f(literal: f64) -> Self { literal }
     #[inline]
@@ -132,4 +230,50 @@ impl Scalar for f64 {
     fn asin(self) -> Option<Self> {
         Some(f64::asin(self)).filter(|f| !f.is_nan())
     }
+    #[inline]
+    fn tan(self) -> Self { f64::tan(self) }
+    #[inline]
+    fn atan(self) -> Self { f64::atan(self) }
+    #[inline]
+    fn atan2(self, x: Self) -> Self { f64::atan2(self, x) }
+
+    #[inline]
+    fn exp(self) -> Self { f64::exp(self) }
+    #[inline]
+    fn ln(self) -> Self { f64::ln(self) }
+    #[inline]
+    fn log(self, base: Self) -> Self { f64::log(self, base) }
+
+    const EPSILON: Self = f64::EPSILON;
+
+    #[inline]
+    fn abs(self) -> Self { f64::abs(self) }
+    #[inline]
+    fn min(self, other: Self) -> Self { f64::min(self, other) }
+    #[inline]
+    fn max(self, other: Self) -> Self { f64::max(self, other) }
+    #[inline]
+    fn hypot(self, other: Self) -> Self { f64::hypot(self, other) }
+    #[inline]
+    fn floor(self) -> Self { f64::floor(self) }
+    #[inline]
+    fn ceil(self) -> Self { f64::ceil(self) }
+    #[inline]
+    fn signum(self) -> Self { f64::signum(self) }
 }
+
+macro_rules! impl_ring_integer {
+    ($integer:ty) => {
+        impl Ring for $integer {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            #[inline]
+            fn i(literal: i32) -> Self { literal as $integer }
+        }
+    };
+}
+
+impl_ring_integer!(i32);
+impl_ring_integer!(i64);
+impl_ring_integer!(i128);
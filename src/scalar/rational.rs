@@ -0,0 +1,293 @@
+//! Exact rational numbers
+//!
+//! [`Rational`] implements [`Field`], such that computations that only add, subtract, multiply and divide are exact.
+//!
+//! ```
+//! use unifiedgeometry::scalar::Rational;
+//!
+//! let third = Rational::<i128>::new(1, 3).unwrap();
+//! assert_eq!(third + third + third, Rational::from(1i128));
+//! assert_eq!(Rational::<i128>::new(2, -6), Some(-third));
+//! ```
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::approx::ApproxEq;
+use crate::scalar::{Field, Ring};
+
+/// Rational number `numer / denom`, with integer type `T`
+///
+/// Always stored in lowest terms, with a positive denominator; Equal values therefore have equal representations.
+///
+/// # Panics
+/// Arithmetic panics if the numerator or denominator of a result overflows `T`, or on division by zero.
+/// Intermediate results are reduced, so overflow only occurs where the reduced result does not fit.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Rational<T> {
+    numer: T,
+    denom: T,
+}
+
+const OVERFLOW: &str = "rational arithmetic overflowed";
+
+macro_rules! impl_rational {
+    ($integer:ty, $unsigned:ty) => {
+        impl Rational<$integer> {
+            /// Create a new rational from a numerator and denominator, reducing to lowest terms
+            ///
+            /// Returns `None` if `denom` is zero, or if the reduced value is not representable
+            pub fn new(numer: $integer, denom: $integer) -> Option<Self> {
+                if denom == 0 {
+                    return None;
+                }
+                // Reduced on the magnitudes, as the divisor or either magnitude may not fit in the signed type (e.g. for `MIN / MIN`)
+                let (numer_magnitude, denom_magnitude) = (numer.unsigned_abs(), denom.unsigned_abs());
+                let divisor = Self::gcd(numer_magnitude, denom_magnitude);
+                let (numer_magnitude, denom_magnitude) = (numer_magnitude / divisor, denom_magnitude / divisor);
+                let numer = if (numer < 0) != (denom < 0) {
+                    <$integer>::checked_sub_unsigned(0, numer_magnitude)?
+                } else {
+                    <$integer>::try_from(numer_magnitude).ok()?
+                };
+                Some(Rational { numer, denom: <$integer>::try_from(denom_magnitude).ok()? })
+            }
+
+            /// Numerator, in lowest terms; Carries the sign of this rational
+            #[inline]
+            pub fn numer(self) -> $integer {
+                self.numer
+            }
+
+            /// Denominator, in lowest terms; Always positive
+            #[inline]
+            pub fn denom(self) -> $integer {
+                self.denom
+            }
+
+            /// True if the denominator is one
+            #[inline]
+            pub fn is_integer(self) -> bool {
+                self.denom == 1
+            }
+
+            /// Reciprocal `1 / self`
+            ///
+            /// Returns `None` if `self` is zero
+            #[inline]
+            pub fn recip(self) -> Option<Self> {
+                Self::new(self.denom, self.numer)
+            }
+
+            /// Absolute value
+            #[inline]
+            pub fn abs(self) -> Self {
+                Rational { numer: self.numer.checked_abs().expect(OVERFLOW), denom: self.denom }
+            }
+
+            /// Largest integer less than or equal to `self`
+            #[inline]
+            pub fn floor(self) -> $integer {
+                self.numer.div_euclid(self.denom)
+            }
+
+            /// Nearest `f64` value; Not necessarily correctly rounded
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.numer as f64 / self.denom as f64
+            }
+
+            fn gcd(mut a: $unsigned, mut b: $unsigned) -> $unsigned {
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            /// Reduces `numer / denom`, panicking on overflow
+            #[inline]
+            fn reduced(numer: Option<$integer>, denom: Option<$integer>) -> Self {
+                Self::new(numer.expect(OVERFLOW), denom.expect(OVERFLOW)).expect(OVERFLOW)
+            }
+        }
+
+        impl From<$integer> for Rational<$integer> {
+            #[inline]
+            fn from(value: $integer) -> Self {
+                Rational { numer: value, denom: 1 }
+            }
+        }
+
+        impl Ord for Rational<$integer> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                // Compares integer parts, then the reciprocals of the fractional parts in reverse; Unlike cross-multiplication, this cannot overflow
+                let (mut lhs, mut rhs) = ((self.numer, self.denom), (other.numer, other.denom));
+                let mut reversed = false;
+                loop {
+                    let ordering = lhs.0.div_euclid(lhs.1).cmp(&rhs.0.div_euclid(rhs.1));
+                    if ordering != Ordering::Equal {
+                        return if reversed { ordering.reverse() } else { ordering };
+                    }
+                    let (lhs_remainder, rhs_remainder) = (lhs.0.rem_euclid(lhs.1), rhs.0.rem_euclid(rhs.1));
+                    match (lhs_remainder == 0, rhs_remainder == 0) {
+                        (true, true) => return Ordering::Equal,
+                        (true, false) => return if reversed { Ordering::Greater } else { Ordering::Less },
+                        (false, true) => return if reversed { Ordering::Less } else { Ordering::Greater },
+                        (false, false) => {
+                            (lhs, rhs) = ((lhs.1, lhs_remainder), (rhs.1, rhs_remainder));
+                            reversed = !reversed;
+                        }
+                    }
+                }
+            }
+        }
+
+        impl PartialOrd for Rational<$integer> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        /// Exact by default; Rationals have no units in the last place, so ULPs comparisons only accept differences within `epsilon`
+        ///
+        /// Like subtraction, comparisons of unequal values panic if their difference is not representable
+        impl ApproxEq for Rational<$integer> {
+            type Epsilon = Self;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon { Self::ZERO }
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon { Self::ZERO }
+            #[inline]
+            fn default_max_ulps() -> u32 { 0 }
+
+            #[inline]
+            fn abs_diff_eq(self, other: Self, epsilon: Self::Epsilon) -> bool {
+                self == other || (self - other).abs() <= epsilon
+            }
+
+            fn relative_eq(self, other: Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                if self == other {
+                    return true;
+                }
+                let difference = (self - other).abs();
+                difference <= epsilon || difference <= self.abs().max(other.abs()) * max_relative
+            }
+
+            #[inline]
+            fn ulps_eq(self, other: Self, epsilon: Self::Epsilon, _: u32) -> bool {
+                self.abs_diff_eq(other, epsilon)
+            }
+        }
+
+        impl Neg for Rational<$integer> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Rational { numer: self.numer.checked_neg().expect(OVERFLOW), denom: self.denom }
+            }
+        }
+
+        impl Add for Rational<$integer> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                // a/b + c/d = (a·(d/g) + c·(b/g)) / (b·(d/g)), with g = gcd(b, d)
+                let divisor = Self::gcd(self.denom.unsigned_abs(), rhs.denom.unsigned_abs()) as $integer;
+                let (lhs_factor, rhs_factor) = (rhs.denom / divisor, self.denom / divisor);
+                let numer = self.numer.checked_mul(lhs_factor)
+                    .zip(rhs.numer.checked_mul(rhs_factor))
+                    .and_then(|(lhs, rhs)| lhs.checked_add(rhs));
+                Self::reduced(numer, self.denom.checked_mul(lhs_factor))
+            }
+        }
+
+        impl Sub for Rational<$integer> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                self + (-rhs)
+            }
+        }
+
+        impl Mul for Rational<$integer> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                // Cancelling common factors first keeps intermediate values small
+                let lhs_divisor = Self::gcd(self.numer.unsigned_abs(), rhs.denom.unsigned_abs()).max(1) as $integer;
+                let rhs_divisor = Self::gcd(rhs.numer.unsigned_abs(), self.denom.unsigned_abs()).max(1) as $integer;
+                Self::reduced(
+                    (self.numer / lhs_divisor).checked_mul(rhs.numer / rhs_divisor),
+                    (self.denom / rhs_divisor).checked_mul(rhs.denom / lhs_divisor),
+                )
+            }
+        }
+
+        impl Div for Rational<$integer> {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]    // Multiplication by the reciprocal
+            fn div(self, rhs: Self) -> Self::Output {
+                self * rhs.recip().expect("attempt to divide by zero")
+            }
+        }
+
+        impl AddAssign for Rational<$integer> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs
+            }
+        }
+
+        impl SubAssign for Rational<$integer> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs
+            }
+        }
+
+        impl MulAssign for Rational<$integer> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs
+            }
+        }
+
+        impl DivAssign for Rational<$integer> {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs
+            }
+        }
+
+        impl Ring for Rational<$integer> {
+            const ZERO: Self = Rational { numer: 0, denom: 1 };
+            const ONE: Self = Rational { numer: 1, denom: 1 };
+
+            #[inline]
+            fn i(literal: i32) -> Self {
+                Self::from(literal as $integer)
+            }
+        }
+
+        impl Field for Rational<$integer> {}
+    };
+}
+
+impl_rational!(i32, u32);
+impl_rational!(i64, u64);
+impl_rational!(i128, u128);
+
+impl<T: Display + PartialEq + From<u8>> Display for Rational<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.denom == T::from(1) {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
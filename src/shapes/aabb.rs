@@ -0,0 +1,205 @@
+use std::fmt::{Display, Formatter};
+use crate::approx::ApproxEq;
+use crate::basis::Basis;
+use crate::matrix::MatrixN;
+use crate::scalar::Scalar;
+use crate::vector::{PointN, VectorN};
+
+/// Axis-aligned bounding box, spanning from a minimum to a maximum corner
+///
+/// Boxes are closed; Points on the boundary are contained, and boxes with a width of zero along some axes (such as the box of a single point) are valid
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AabbN<T, const N: usize, B: Basis<N>> {
+    min: PointN<T, N, B>,
+    max: PointN<T, N, B>,
+}
+
+/// Shapes with an axis-aligned bounding box
+pub trait Bounded<T, const N: usize, B: Basis<N>> {
+    /// Smallest axis-aligned box containing this shape
+    fn bounding_box(&self) -> AabbN<T, N, B>;
+}
+
+impl<T: Display, const N: usize, B: Basis<N>> Display for AabbN<T, N, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Aabb{{{}, {}}}", self.min, self.max)
+    }
+}
+
+impl<T: ApproxEq<Epsilon=T>, const N: usize, B: Basis<N>> ApproxEq for AabbN<T, N, B> {
+    impl_approx_eq_fields!(T; min, max);
+}
+
+/// Apply `f` to each pair of coordinates
+#[inline]
+fn zip_with<T: Scalar, const N: usize, B: Basis<N>, F: Fn(T, T) -> T>(lhs: PointN<T, N, B>, rhs: PointN<T, N, B>, f: F) -> PointN<T, N, B> {
+    let (lhs, rhs) = (lhs.to_array(), rhs.to_array());
+    PointN::new(std::array::from_fn(|axis| f(lhs[axis], rhs[axis])))
+}
+
+impl<T: Scalar, const N: usize, B: Basis<N>> AabbN<T, N, B> {
+    /// Box spanning two opposite corners, in any order
+    #[inline]
+    pub fn new(corner: PointN<T, N, B>, opposite: PointN<T, N, B>) -> Self {
+        AabbN { min: zip_with(corner, opposite, T::min), max: zip_with(corner, opposite, T::max) }
+    }
+
+    /// Smallest box containing all points; Returns `None` if there are no points
+    pub fn from_points(points: &[PointN<T, N, B>]) -> Option<Self> {
+        let (&first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Self::new(first, first), |aabb, &point| aabb.union_point(point)))
+    }
+
+    /// Corner with the smallest coordinates
+    #[inline]
+    pub fn min(self) -> PointN<T, N, B> {
+        self.min
+    }
+
+    /// Corner with the largest coordinates
+    #[inline]
+    pub fn max(self) -> PointN<T, N, B> {
+        self.max
+    }
+
+    /// Center point, halfway between the corners
+    #[inline]
+    pub fn center(self) -> PointN<T, N, B> {
+        self.min.lerp(self.max, T::f(0.5))
+    }
+
+    /// Size along each axis, from the minimum to the maximum corner
+    #[inline]
+    pub fn extents(self) -> VectorN<T, N, B> {
+        self.max - self.min
+    }
+
+    /// Product of the extents; The length, area or volume for 1, 2 and 3 dimensions respectively
+    #[inline]
+    pub fn volume(self) -> T {
+        self.extents().into_iter().fold(T::ONE, |product, extent| product * extent)
+    }
+
+    /// Smallest box containing both boxes
+    #[inline]
+    pub fn union(self, other: Self) -> Self {
+        AabbN { min: zip_with(self.min, other.min, T::min), max: zip_with(self.max, other.max, T::max) }
+    }
+
+    /// Smallest box containing this box and `point`
+    #[inline]
+    pub fn union_point(self, point: PointN<T, N, B>) -> Self {
+        AabbN { min: zip_with(self.min, point, T::min), max: zip_with(self.max, point, T::max) }
+    }
+
+    /// Box contained in both boxes; Returns `None` if they do not overlap
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let intersection = AabbN { min: zip_with(self.min, other.min, T::max), max: zip_with(self.max, other.max, T::min) };
+        intersection.min.into_iter().zip(intersection.max).all(|(min, max)| min <= max).then_some(intersection)
+    }
+
+    /// True if `point` lies inside or on the boundary of the box
+    #[inline]
+    pub fn contains(self, point: PointN<T, N, B>) -> bool {
+        self.min.into_iter().zip(self.max).zip(point).all(|((min, max), coordinate)| min <= coordinate && coordinate <= max)
+    }
+
+    /// True if `other` lies entirely inside this box
+    #[inline]
+    pub fn contains_box(self, other: Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// True if the boxes intersect or touch
+    #[inline]
+    pub fn overlaps(self, other: Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Point in the box closest to `point`; `point` itself if it is contained
+    #[inline]
+    pub fn closest_point(self, point: PointN<T, N, B>) -> PointN<T, N, B> {
+        zip_with(zip_with(point, self.min, T::max), self.max, T::min)
+    }
+
+    /// Distance from `point` to the box; Zero inside the box
+    #[inline]
+    pub fn distance_to(self, point: PointN<T, N, B>) -> T {
+        (point - self.closest_point(point)).magnitude()
+    }
+
+    /// Parameters `(entry, exit)` where the ray from `origin` along `direction` enters and exits the box, with `entry ≤ exit`, through the slab method
+    ///
+    /// Where the ray starts inside the box, `entry` is negative.
+    /// Returns `None` if the ray misses the box, or the box lies entirely behind its origin.
+    /// Rays parallel to a face are handled explicitly, including those lying in the plane of a face
+    pub fn ray_intersection(self, origin: PointN<T, N, B>, direction: VectorN<T, N, B>) -> Option<(T, T)> {
+        let mut interval: Option<(T, T)> = None;
+        for (((min, max), origin), direction) in self.min.into_iter().zip(self.max).zip(origin).zip(direction) {
+            if direction == T::ZERO {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+            let (near, far) = ((min - origin) / direction, (max - origin) / direction);
+            let (near, far) = if near <= far { (near, far) } else { (far, near) };
+            let (entry, exit) = interval.map_or((near, far), |(entry, exit)| (entry.max(near), exit.min(far)));
+            if entry > exit {
+                return None;
+            }
+            interval = Some((entry, exit));
+        }
+        // `None` for a zero direction
+        interval.filter(|&(_, exit)| exit >= T::ZERO)
+    }
+
+    /// Smallest box containing this box after mapping it through `linear` followed by `translation`
+    ///
+    /// Each output axis is bounded by summing the smaller and larger products of each matrix element with the input bounds (Arvo's method),
+    /// which is exact for the box of the transformed box without transforming every corner
+    pub fn transform(self, linear: MatrixN<T, N, B>, translation: VectorN<T, N, B>) -> Self {
+        let (min, max, translation) = (self.min.to_array(), self.max.to_array(), translation.to_array());
+        let mut output_min = translation;
+        let mut output_max = translation;
+        for row in 0..N {
+            for column in 0..N {
+                let (a, b) = (linear[(row, column)] * min[column], linear[(row, column)] * max[column]);
+                output_min[row] += a.min(b);
+                output_max[row] += a.max(b);
+            }
+        }
+        AabbN { min: PointN::new(output_min), max: PointN::new(output_max) }
+    }
+}
+
+impl<T: Scalar, B: Basis<2>> AabbN<T, 2, B> {
+    /// Area of the box
+    #[inline]
+    pub fn area(self) -> T {
+        self.volume()
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> AabbN<T, 3, B> {
+    /// Total area of the 6 faces
+    #[inline]
+    pub fn surface_area(self) -> T {
+        let [x, y, z] = self.extents().to_array();
+        T::i(2) * (x * y + y * z + z * x)
+    }
+}
+
+impl<T: Scalar, const N: usize, B: Basis<N>> Bounded<T, N, B> for AabbN<T, N, B> {
+    #[inline]
+    fn bounding_box(&self) -> AabbN<T, N, B> {
+        *self
+    }
+}
+
+impl<T: Scalar, const N: usize, B: Basis<N>> Bounded<T, N, B> for PointN<T, N, B> {
+    #[inline]
+    fn bounding_box(&self) -> AabbN<T, N, B> {
+        AabbN::new(*self, *self)
+    }
+}
@@ -0,0 +1,122 @@
+//! Enclosing balls (circles and spheres) of point sets, in any dimension
+//!
+//! Both constructions return `None` if there are no points, or any point is not finite
+use crate::basis::Basis;
+use crate::matrix::MatrixN;
+use crate::scalar::Scalar;
+use crate::utility::SplitMix64;
+use crate::vector::{PointN, VectorN};
+
+/// Seed for [`minimum_enclosing_ball`] where none is given
+pub(crate) const DEFAULT_SEED: u64 = 0x853C49E6748FEA9B;
+
+/// Ball as a center and radius
+type Ball<T, const N: usize, B> = (PointN<T, N, B>, T);
+
+/// Smallest ball containing all points, through Welzl's algorithm
+///
+/// The points are shuffled with the given seed, which gives an expected linear running time for any input order
+pub(crate) fn minimum_enclosing_ball<T: Scalar, const N: usize, B: Basis<N>>(points: &[PointN<T, N, B>], seed: u64) -> Option<Ball<T, N, B>> {
+    if points.is_empty() || !points.iter().all(|point| point.is_finite()) {
+        return None;
+    }
+    let mut points = points.to_vec();
+    SplitMix64::new(seed).shuffle(&mut points);
+    welzl(&points, &mut Vec::with_capacity(N + 1))
+}
+
+/// Smallest ball containing `points`, with all of `support` on its boundary
+///
+/// Iterative over `points` and recursive over the support set, which holds at most `N + 1` points
+fn welzl<T: Scalar, const N: usize, B: Basis<N>>(points: &[PointN<T, N, B>], support: &mut Vec<PointN<T, N, B>>) -> Option<Ball<T, N, B>> {
+    let mut ball = ball_through(support);
+    if support.len() == N + 1 {
+        return ball;
+    }
+    for (index, &point) in points.iter().enumerate() {
+        if !ball.is_some_and(|ball| contains(ball, point)) {
+            support.push(point);
+            ball = welzl(&points[..index], support);
+            support.pop();
+        }
+    }
+    ball
+}
+
+/// Containment, with a relative tolerance for rounding
+#[inline]
+fn contains<T: Scalar, const N: usize, B: Basis<N>>((center, radius): Ball<T, N, B>, point: PointN<T, N, B>) -> bool {
+    (point - center).magnitude() <= radius * (T::ONE + T::i(8) * T::EPSILON)
+}
+
+/// Smallest ball with all points on its boundary, or where they are affinely dependent, the smallest ball through a subset which contains all of them
+fn ball_through<T: Scalar, const N: usize, B: Basis<N>>(points: &[PointN<T, N, B>]) -> Option<Ball<T, N, B>> {
+    if let Some(ball) = circumball(points) {
+        return Some(ball);
+    }
+    (0..points.len())
+        .filter_map(|skip| {
+            let subset: Vec<_> = points.iter().enumerate().filter(|&(index, _)| index != skip).map(|(_, &point)| point).collect();
+            ball_through(&subset)
+        })
+        .filter(|&ball| points.iter().all(|&point| contains(ball, point)))
+        .min_by(|(_, lhs), (_, rhs)| lhs.partial_cmp(rhs).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+/// Smallest ball with up to `N + 1` affinely independent points on its boundary
+///
+/// The center lies in the affine hull of the points, at `p₀ + Σ λᵢ·uᵢ` with `uᵢ = pᵢ - p₀`, solving the Gram system `(uᵢ · uⱼ)·λ = |uᵢ|² / 2`.
+/// Returns `None` for no points, or points which are (within rounding) affinely dependent
+fn circumball<T: Scalar, const N: usize, B: Basis<N>>(points: &[PointN<T, N, B>]) -> Option<Ball<T, N, B>> {
+    let (&first, rest) = points.split_first()?;
+    debug_assert!(rest.len() <= N, "More than N + 1 points cannot be affinely independent");
+    let offsets: Vec<_> = rest.iter().map(|&point| point - first).collect();
+    // The Gram system padded to N×N with the identity, which leaves the determinant and solution of the used block unchanged
+    let mut gram = MatrixN::<T, N, ()>::identity();
+    let mut rhs = [T::ZERO; N];
+    for (i, &offset) in offsets.iter().enumerate() {
+        for (j, &other) in offsets.iter().enumerate() {
+            gram[(i, j)] = offset.dot(other);
+        }
+        rhs[i] = T::f(0.5) * offset.dot(offset);
+    }
+    // Gram matrices are positive semi-definite, so the determinant is at most the product of the diagonal (Hadamard's inequality);
+    // Relative to it, a determinant at rounding level means the offsets are linearly dependent
+    let lu = gram.lu()?;
+    let scale = (0..offsets.len()).fold(T::ONE, |product, i| product * gram[(i, i)]);
+    if lu.determinant() <= T::i(8) * T::EPSILON * scale {
+        return None;
+    }
+    let weights = lu.solve(VectorN::new(rhs)).to_array();
+    let displacement = offsets.iter().zip(weights).fold(PointN::<T, N, B>::origin().to_vector(), |sum, (&offset, weight)| sum + offset * weight);
+    let center = first + displacement;
+    let radius = points.iter().map(|&point| (point - center).magnitude()).fold(T::ZERO, T::max);
+    Some((center, radius))
+}
+
+/// Approximate enclosing ball through Ritter's algorithm in linear time; Typically a few percent larger than the minimum
+pub(crate) fn approximate_enclosing_ball<T: Scalar, const N: usize, B: Basis<N>>(points: &[PointN<T, N, B>]) -> Option<Ball<T, N, B>> {
+    if points.is_empty() || !points.iter().all(|point| point.is_finite()) {
+        return None;
+    }
+    let farthest = |from: PointN<T, N, B>| points.iter().copied()
+        .fold((from, T::ZERO), |(best, best_distance), point| {
+            let distance = (point - from).magnitude();
+            if distance > best_distance { (point, distance) } else { (best, best_distance) }
+        });
+    // Initial ball spanning two far apart points
+    let (first, _) = farthest(points[0]);
+    let (second, diameter) = farthest(first);
+    let mut center = first.lerp(second, T::f(0.5));
+    let mut radius = T::f(0.5) * diameter;
+    // Grow the ball to include each outside point, keeping the far side of the current ball
+    for &point in points {
+        let distance = (point - center).magnitude();
+        if distance > radius {
+            let grown = T::f(0.5) * (radius + distance);
+            center = center + (point - center) * ((grown - radius) / distance);
+            radius = grown;
+        }
+    }
+    Some((center, radius))
+}
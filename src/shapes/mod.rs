@@ -1,3 +1,4 @@
 #[macro_use]
 pub mod triangle;
-
+pub mod aabb;
+pub(crate) mod enclosing;
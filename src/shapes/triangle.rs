@@ -2,17 +2,37 @@
 #![allow(non_camel_case_types)]
 
 use std::fmt::Debug;
+use crate::angle::Angle;
+use crate::approx::ApproxEq;
 use crate::basis::Basis;
+use crate::geometry3d::{Point3D, Vector3D};
+use crate::geometry3d::shapes::{Plane3D, Ray3D, Segment3D};
 use crate::scalar::{Scalar};
+use crate::shapes::aabb::{AabbN, Bounded};
 use crate::shapes::triangle::formulas::triangle_area;
 use crate::utility::MaybeTwo;
-use crate::vector::{PointN};
+use crate::vector::{PointN, VectorN};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum InvalidTriangleError {
     InvalidLength,
     InvalidAngle,
     AngleTooLarge,
+    /// Two or more points of a [`Triangle`] are equal
+    CoincidentPoints,
+    /// The points of a [`Triangle`] lie on a line, within tolerance
+    CollinearPoints,
+    /// A point of a [`Triangle`] has an infinite or NaN coordinate
+    NonFinitePoint,
+}
+
+/// Whether points on the boundary of a triangle are considered contained, see [`Triangle::contains`]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EdgeInclusion {
+    /// Points on the edges and vertices are contained
+    Inclusive,
+    /// Only points strictly inside are contained
+    Exclusive,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
@@ -23,11 +43,31 @@ pub struct Triangle<T: Scalar, const N: usize, B: Basis<N>> {
 }
 
 impl<T: Scalar, const N: usize, B: Basis<N>> Triangle<T, N, B> {
+    /// Create a new triangle from 3 points, rejecting degenerate triangles
+    ///
+    /// Uses [`with_tolerance`](Self::with_tolerance) with a tolerance of `8 * T::EPSILON`, which rejects triangles whose area is indistinguishable from zero through rounding
     pub fn new(A: PointN<T, N, B>, B: PointN<T, N, B>, C: PointN<T, N, B>) -> Result<Self, InvalidTriangleError> {
-        if A == B || B == C || C == A || !A.is_finite() || !B.is_finite() || !C.is_finite() {
-            Err(InvalidTriangleError::InvalidLength)
+        Self::with_tolerance(A, B, C, T::i(8) * T::EPSILON)
+    }
+
+    /// Create a new triangle from 3 points, with a tolerance for collinearity
+    ///
+    /// The points are considered collinear if the altitude onto the longest side is at most `tolerance` times the length of that side;
+    /// This is independent of the scale of the triangle, and for small tolerances approximately the smallest angle in radians.
+    /// A tolerance of zero only rejects triangles with an area of exactly zero.
+    pub fn with_tolerance(A: PointN<T, N, B>, B: PointN<T, N, B>, C: PointN<T, N, B>, tolerance: T) -> Result<Self, InvalidTriangleError> {
+        if !A.is_finite() || !B.is_finite() || !C.is_finite() {
+            return Err(InvalidTriangleError::NonFinitePoint);
+        }
+        if A == B || B == C || C == A {
+            return Err(InvalidTriangleError::CoincidentPoints);
+        }
+        let triangle = Self { A, B, C };
+        let (base, altitude) = triangle.longest_side_altitude();
+        if !altitude.is_finite() || altitude <= tolerance * base {
+            Err(InvalidTriangleError::CollinearPoints)
         } else {
-            Ok(Self { A, B, C })
+            Ok(triangle)
         }
     }
 
@@ -42,6 +82,510 @@ impl<T: Scalar, const N: usize, B: Basis<N>> Triangle<T, N, B> {
     pub fn C(self) -> PointN<T, N, B> {
         self.C
     }
+
+    /// Centroid; The intersection of the medians, and the mean of the vertices
+    #[inline]
+    pub fn centroid(self) -> PointN<T, N, B> {
+        self.A + ((self.B - self.A) + (self.C - self.A)) / T::i(3)
+    }
+
+    /// Incenter; The center of the incircle, and the intersection of the angle bisectors
+    #[inline]
+    pub fn incenter(self) -> PointN<T, N, B> {
+        let [a, b, c] = self.sides();
+        self.A + ((self.B - self.A) * b + (self.C - self.A) * c) / (a + b + c)
+    }
+
+    /// Circumcenter; The center of the circumcircle, and the intersection of the perpendicular bisectors of the sides
+    ///
+    /// Lies outside the triangle if it is obtuse
+    pub fn circumcenter(self) -> PointN<T, N, B> {
+        // Solves for O = A + s·u + t·v, equidistant from all vertices; The determinant of the system is the squared double area
+        let (u, v) = (self.B - self.A, self.C - self.A);
+        let (uu, vv, uv) = (u.dot(u), v.dot(v), u.dot(v));
+        let (base, altitude) = self.longest_side_altitude();
+        let determinant = T::i(2) * (base * altitude).powi(2);
+        self.A + (u * (vv * (uu - uv)) + v * (uu * (vv - uv))) / determinant
+    }
+
+    /// Orthocenter; The intersection of the altitudes
+    ///
+    /// Lies outside the triangle if it is obtuse
+    #[inline]
+    pub fn orthocenter(self) -> PointN<T, N, B> {
+        // H = A + B + C - 2·O
+        let circumcenter = self.circumcenter();
+        circumcenter + (self.A - circumcenter) + (self.B - circumcenter) + (self.C - circumcenter)
+    }
+
+    /// Nine-point center; The center of the circle through the midpoints of the sides, and midpoint of the circumcenter and orthocenter
+    #[inline]
+    pub fn nine_point_center(self) -> PointN<T, N, B> {
+        let circumcenter = self.circumcenter();
+        circumcenter + ((self.A - circumcenter) + (self.B - circumcenter) + (self.C - circumcenter)) * T::f(0.5)
+    }
+
+    /// Euler line, through the circumcenter, centroid, nine-point center and orthocenter
+    ///
+    /// Returns the circumcenter and the unit direction towards the orthocenter,
+    /// or `None` for (nearly) equilateral triangles, where these centers coincide and the line is undefined;
+    /// The centers are considered to coincide where their distance is at most `8 * T::EPSILON` times the circumradius.
+    pub fn euler_line(self) -> Option<(PointN<T, N, B>, VectorN<T, N, B>)> {
+        let circumcenter = self.circumcenter();
+        let direction = (self.A - circumcenter) + (self.B - circumcenter) + (self.C - circumcenter);
+        let distance = direction.magnitude();
+        if distance > T::i(8) * T::EPSILON * (self.A - circumcenter).magnitude() {
+            Some((circumcenter, direction / distance))
+        } else {
+            None
+        }
+    }
+
+    /// Barycentric coordinates `[u, v, w]` of `point`, the weights of `A`, `B` and `C` respectively, which sum to one
+    ///
+    /// For `N > 2`, these are the coordinates of the projection of `point` onto the plane of the triangle.
+    /// Coordinates are all positive inside the triangle, and a coordinate is negative where `point` lies beyond the edge opposite its vertex.
+    pub fn barycentric(self, point: PointN<T, N, B>) -> [T; 3] {
+        let (u, v, offset) = (self.B - self.A, self.C - self.A, point - self.A);
+        let (uu, vv, uv) = (u.dot(u), v.dot(v), u.dot(v));
+        let (offset_u, offset_v) = (offset.dot(u), offset.dot(v));
+        let determinant = uu * vv - uv * uv;
+        let weight_B = (vv * offset_u - uv * offset_v) / determinant;
+        let weight_C = (uu * offset_v - uv * offset_u) / determinant;
+        [T::ONE - weight_B - weight_C, weight_B, weight_C]
+    }
+
+    /// Point with barycentric coordinates `[u, v, w]`, the weights of `A`, `B` and `C` respectively
+    ///
+    /// Coordinates are expected to sum to one; Only `v` and `w` are used, with `u` implied as `1 - v - w`.
+    #[inline]
+    pub fn from_barycentric(self, [_, v, w]: [T; 3]) -> PointN<T, N, B> {
+        self.A + (self.B - self.A) * v + (self.C - self.A) * w
+    }
+
+    /// True if `point` lies inside the triangle, with points on the boundary included according to `edges`
+    ///
+    /// For `N > 2`, `point` must also lie in the plane of the triangle, up to rounding error relative to the largest coordinate
+    pub fn contains(self, point: PointN<T, N, B>, edges: EdgeInclusion) -> bool {
+        let coordinates = self.barycentric(point);
+        if N > 2 {
+            let [_, v, w] = coordinates;
+            let perpendicular = (point - self.A) - (self.B - self.A) * v - (self.C - self.A) * w;
+            let scale = [self.A, self.B, self.C, point].into_iter().flatten().map(T::abs).fold(T::ZERO, T::max);
+            if perpendicular.magnitude() > T::i(8) * T::EPSILON * scale {
+                return false;
+            }
+        }
+        match edges {
+            EdgeInclusion::Inclusive => coordinates.into_iter().all(|coordinate| coordinate >= T::ZERO),
+            EdgeInclusion::Exclusive => coordinates.into_iter().all(|coordinate| coordinate > T::ZERO),
+        }
+    }
+
+    /// Point on the triangle (including its interior) closest to `point`
+    ///
+    /// Determines which Voronoi region of the vertices, edges or face `point` lies in, and projects it onto that feature
+    pub fn closest_point(self, point: PointN<T, N, B>) -> PointN<T, N, B> {
+        let (AB, AC, AP) = (self.B - self.A, self.C - self.A, point - self.A);
+        let (d1, d2) = (AB.dot(AP), AC.dot(AP));
+        if d1 <= T::ZERO && d2 <= T::ZERO {
+            return self.A;
+        }
+
+        let BP = point - self.B;
+        let (d3, d4) = (AB.dot(BP), AC.dot(BP));
+        if d3 >= T::ZERO && d4 <= d3 {
+            return self.B;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= T::ZERO && d1 >= T::ZERO && d3 <= T::ZERO {
+            return self.A + AB * (d1 / (d1 - d3));
+        }
+
+        let CP = point - self.C;
+        let (d5, d6) = (AB.dot(CP), AC.dot(CP));
+        if d6 >= T::ZERO && d5 <= d6 {
+            return self.C;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= T::ZERO && d2 >= T::ZERO && d6 <= T::ZERO {
+            return self.A + AC * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= T::ZERO && (d4 - d3) >= T::ZERO && (d5 - d6) >= T::ZERO {
+            return self.B + (self.C - self.B) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        // Inside the face region; Barycentric coordinates from the region tests
+        let denominator = va + vb + vc;
+        self.A + AB * (vb / denominator) + AC * (vc / denominator)
+    }
+
+    /// Distance from `point` to the nearest point on the triangle (including its interior)
+    #[inline]
+    pub fn distance_to(self, point: PointN<T, N, B>) -> T {
+        (point - self.closest_point(point)).magnitude()
+    }
+
+    /// Same triangle with the opposite winding order, by swapping `B` and `C`; For 3D triangles, this reverses the [normal](Triangle::normal)
+    #[inline]
+    pub fn flip_winding(self) -> Self {
+        Triangle { A: self.A, B: self.C, C: self.B }
+    }
+
+    /// Length of the longest side, and the altitude onto it
+    ///
+    /// The altitude is the component of another side perpendicular to the longest side, which unlike Heron's formula does not suffer cancellation for thin triangles.
+    /// The side is projected onto the unit direction of the base, so that no squared lengths overflow or underflow
+    fn longest_side_altitude(self) -> (T, T) {
+        let (a, b, c) = (self.length_a(), self.length_b(), self.length_c());
+        let (length, base, side) = if a >= b && a >= c {
+            (a, self.C - self.B, self.A - self.B)
+        } else if b >= c {
+            (b, self.A - self.C, self.B - self.C)
+        } else {
+            (c, self.B - self.A, self.C - self.A)
+        };
+        let direction = base / length;
+        let perpendicular = side - direction * side.dot(direction);
+        (length, perpendicular.magnitude())
+    }
+}
+
+impl<T: Scalar, const N: usize, B: Basis<N>> Bounded<T, N, B> for Triangle<T, N, B> {
+    #[inline]
+    fn bounding_box(&self) -> AabbN<T, N, B> {
+        AabbN::new(self.A, self.B).union_point(self.C)
+    }
+}
+
+/// Angle between two non-zero vectors
+///
+/// Uses `2·atan2(|u·|v| - v·|u||, |u·|v| + v·|u||)`, which is accurate for all angles and cannot leave the domain of an inverse trigonometric function
+#[inline]
+fn angle_between<T: Scalar, const N: usize, B: Basis<N>>(u: VectorN<T, N, B>, v: VectorN<T, N, B>) -> Angle<T> {
+    let (u, v) = (u * v.magnitude(), v * u.magnitude());
+    Angle::atan2((u - v).magnitude(), (u + v).magnitude()) * T::i(2)
+}
+
+/// Faces of a triangle which a ray can hit, see [`Triangle::ray_intersection`]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Culling {
+    /// Both faces can be hit
+    DoubleSided,
+    /// Only the front face can be hit; The face from which `A`, `B` and `C` appear counter-clockwise, following the right-hand rule of the basis
+    BackFace,
+}
+
+/// Intersection of a ray or segment with a triangle
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TriangleHit<T> {
+    /// Parameter of the intersection along the ray or segment
+    pub t: T,
+    /// Barycentric coordinates `[u, v, w]` of the intersection, see [`Triangle::barycentric`]
+    pub barycentric: [T; 3],
+}
+
+/// Part of a triangle in front of a plane, see [`Triangle::clip`]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ClippedTriangle<T, B: Basis<3>> {
+    vertices: [Point3D<T, B>; 4],
+    count: usize,
+}
+
+impl<T, B: Basis<3>> ClippedTriangle<T, B> {
+    /// Vertices of the clipped polygon, in the winding order of the triangle; Either none, 3 or 4
+    #[inline]
+    pub fn vertices(&self) -> &[Point3D<T, B>] {
+        &self.vertices[..self.count]
+    }
+}
+
+/// Apparent order of the vertices `A`, `B` and `C` of a triangle, see [`Triangle::winding`]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Winding {
+    CounterClockwise,
+    Clockwise,
+    /// Seen edge-on, from within the plane of the triangle
+    EdgeOn,
+}
+
+impl<T: Scalar, B: Basis<2>> Triangle<T, 2, B> {
+    /// Order of the vertices, in a basis where the Y axis is counter-clockwise from the X axis
+    #[inline]
+    pub fn winding(self) -> Winding {
+        let orientation = orientation_2d(self.A.to_array(), self.B.to_array(), self.C.to_array());
+        winding_from_sign(orientation)
+    }
+}
+
+impl<T: Scalar, B: Basis<3>> Triangle<T, 3, B> {
+    /// Normal vector `(B - A) × (C - A)`, with a length of twice the area
+    ///
+    /// Follows the right-hand rule of the basis through [`cross_product`](Vector3D::cross_product); Viewed from the side it points towards, the vertices appear counter-clockwise
+    #[inline]
+    pub fn normal(self) -> Vector3D<T, B> {
+        (self.B - self.A).cross_product(self.C - self.A)
+    }
+
+    /// Unit length [normal](Self::normal)
+    #[inline]
+    pub fn unit_normal(self) -> Vector3D<T, B> {
+        self.normal().with_unit_length()
+    }
+
+    /// Plane containing the triangle, with the normal along [`normal`](Self::normal)
+    ///
+    /// Returns `None` only where computing the normal overflows or underflows
+    #[inline]
+    pub fn plane(self) -> Option<Plane3D<T, B>> {
+        Plane3D::from_point_normal(self.A, self.normal())
+    }
+
+    /// Order in which the vertices appear when viewed from `viewpoint`
+    #[inline]
+    pub fn winding(self, viewpoint: Point3D<T, B>) -> Winding {
+        winding_from_sign(self.normal().dot(viewpoint - self.A))
+    }
+
+    /// Intersection of `ray` with the triangle, through the Möller–Trumbore algorithm
+    ///
+    /// Returns `None` if the ray misses, is parallel to the triangle, or hits a back face with [`Culling::BackFace`].
+    /// Intersections exactly on an edge are hits.
+    #[inline]
+    pub fn ray_intersection(self, ray: Ray3D<T, B>, culling: Culling) -> Option<TriangleHit<T>> {
+        self.moller_trumbore(ray.origin, ray.direction, culling)
+            .filter(|hit| hit.t >= T::ZERO)
+    }
+
+    /// Intersection of `segment` with the triangle, with `t` in `[0, 1]` from the start to the end of the segment
+    ///
+    /// Returns `None` if the segment misses or is parallel to the triangle
+    #[inline]
+    pub fn segment_intersection(self, segment: Segment3D<T, B>) -> Option<TriangleHit<T>> {
+        self.moller_trumbore(segment.start, segment.direction(), Culling::DoubleSided)
+            .filter(|hit| hit.t >= T::ZERO && hit.t <= T::ONE)
+    }
+
+    /// True if the triangles intersect or touch, through Möller's interval overlap test
+    ///
+    /// Coplanar triangles are tested for overlap within their shared plane
+    pub fn overlaps(self, other: Self) -> bool {
+        let (other_normal, distances) = other.plane_distances([self.A, self.B, self.C]);
+        if same_side(distances) {
+            return false;
+        }
+        let (normal, other_distances) = self.plane_distances([other.A, other.B, other.C]);
+        if same_side(other_distances) {
+            return false;
+        }
+        if distances.into_iter().all(|distance| distance == T::ZERO) {
+            return self.coplanar_overlaps(other, normal);
+        }
+
+        // Both triangles cross the line where the planes intersect; Compare the intervals they cover on it, projected onto its largest axis
+        let axis = largest_axis(normal.cross_product(other_normal));
+        let project = |triangle: Self| [triangle.A, triangle.B, triangle.C].map(|point| point.to_array()[axis]);
+        match (line_interval(project(self), distances), line_interval(project(other), other_distances)) {
+            (Some((min, max)), Some((other_min, other_max))) => min <= other_max && other_min <= max,
+            _ => self.coplanar_overlaps(other, normal)
+        }
+    }
+
+    /// Part of the triangle in front of `plane` (where the signed distance is non-negative), through Sutherland–Hodgman clipping
+    ///
+    /// The result is empty if the triangle lies behind the plane, or only touches it at a vertex or edge
+    pub fn clip(self, plane: Plane3D<T, B>) -> ClippedTriangle<T, B> {
+        let points = [self.A, self.B, self.C];
+        let distances = points.map(|point| plane.signed_distance(point));
+        let mut clipped = ClippedTriangle { vertices: [self.A; 4], count: 0 };
+        for i in 0..3 {
+            let j = (i + 1) % 3;
+            if distances[i] >= T::ZERO {
+                clipped.vertices[clipped.count] = points[i];
+                clipped.count += 1;
+            }
+            if crosses(distances[i], distances[j]) {
+                clipped.vertices[clipped.count] = points[i].lerp(points[j], distances[i] / (distances[i] - distances[j]));
+                clipped.count += 1;
+            }
+        }
+        if clipped.count < 3 {
+            clipped.count = 0;
+        }
+        clipped
+    }
+
+    /// Segment where the triangle intersects `plane`
+    ///
+    /// Returns `None` if the triangle lies entirely on one side of the plane, or within it.
+    /// Where the triangle only touches the plane at a vertex, the start and end of the segment are equal.
+    pub fn plane_intersection(self, plane: Plane3D<T, B>) -> Option<Segment3D<T, B>> {
+        let points = [self.A, self.B, self.C];
+        let distances = points.map(|point| plane.signed_distance(point));
+        if distances.into_iter().all(|distance| distance == T::ZERO) {
+            return None;
+        }
+        let mut found = None;
+        for i in 0..3 {
+            let j = (i + 1) % 3;
+            let point = if distances[i] == T::ZERO {
+                points[i]
+            } else if crosses(distances[i], distances[j]) {
+                points[i].lerp(points[j], distances[i] / (distances[i] - distances[j]))
+            } else {
+                continue;
+            };
+            found = match found {
+                None => Some(Segment3D { start: point, end: point }),
+                Some(segment) => return Some(Segment3D { start: segment.start, end: point }),
+            };
+        }
+        found
+    }
+
+    fn moller_trumbore(self, origin: Point3D<T, B>, direction: Vector3D<T, B>, culling: Culling) -> Option<TriangleHit<T>> {
+        let (AB, AC) = (self.B - self.A, self.C - self.A);
+        // Using the handedness-aware cross product, the determinant is positive for front faces in any basis
+        let p = direction.cross_product(AC);
+        let determinant = AB.dot(p);
+        // Parallel within rounding, relative to the magnitude of the determinant's terms
+        let threshold = T::EPSILON.powi(2) * AB.dot(AB) * AC.dot(AC) * direction.dot(direction);
+        let facing = match culling {
+            Culling::DoubleSided => determinant.powi(2) > threshold,
+            Culling::BackFace => determinant > T::ZERO && determinant.powi(2) > threshold,
+        };
+        if !facing {
+            return None;
+        }
+
+        let offset = origin - self.A;
+        let weight_B = offset.dot(p) / determinant;
+        if weight_B < T::ZERO || weight_B > T::ONE {
+            return None;
+        }
+        let q = offset.cross_product(AB);
+        let weight_C = direction.dot(q) / determinant;
+        if weight_C < T::ZERO || weight_B + weight_C > T::ONE {
+            return None;
+        }
+        Some(TriangleHit {
+            t: AC.dot(q) / determinant,
+            barycentric: [T::ONE - weight_B - weight_C, weight_B, weight_C],
+        })
+    }
+
+    /// Normal of the plane of the triangle, and the signed distances of `points` from it in multiples of the normal's length
+    ///
+    /// Distances within rounding error of zero are snapped to zero
+    fn plane_distances(self, points: [Point3D<T, B>; 3]) -> (Vector3D<T, B>, [T; 3]) {
+        let normal = (self.B - self.A).cross_product(self.C - self.A);
+        let extent = points.into_iter().map(|point| (point - self.A).magnitude()).fold(T::ZERO, T::max);
+        let tolerance = T::i(8) * T::EPSILON * normal.magnitude() * extent;
+        (normal, points.map(|point| {
+            let distance = normal.dot(point - self.A);
+            if distance.abs() <= tolerance { T::ZERO } else { distance }
+        }))
+    }
+
+    /// Overlap of triangles in the same plane, tested in 2D by dropping the axis along which the normal is largest
+    fn coplanar_overlaps(self, other: Self, normal: Vector3D<T, B>) -> bool {
+        let axis = largest_axis(normal);
+        let (first, second) = ((axis + 1) % 3, (axis + 2) % 3);
+        let project = |triangle: Self| [triangle.A, triangle.B, triangle.C].map(|point| {
+            let coordinates = point.to_array();
+            [coordinates[first], coordinates[second]]
+        });
+        let (points, other_points) = (project(self), project(other));
+        for i in 0..3 {
+            for j in 0..3 {
+                if segments_intersect_2d([points[i], points[(i + 1) % 3]], [other_points[j], other_points[(j + 1) % 3]]) {
+                    return true;
+                }
+            }
+        }
+        // Without intersecting edges, either contains the other entirely, or they are disjoint
+        contains_2d(points, other_points[0]) || contains_2d(other_points, points[0])
+    }
+}
+
+#[inline]
+fn winding_from_sign<T: Scalar>(sign: T) -> Winding {
+    if sign > T::ZERO {
+        Winding::CounterClockwise
+    } else if sign < T::ZERO {
+        Winding::Clockwise
+    } else {
+        Winding::EdgeOn
+    }
+}
+
+/// True if all distances are non-zero with the same sign
+#[inline]
+fn same_side<T: Scalar>(distances: [T; 3]) -> bool {
+    distances.into_iter().all(|distance| distance > T::ZERO) || distances.into_iter().all(|distance| distance < T::ZERO)
+}
+
+/// True if the distances are non-zero with opposite signs
+#[inline]
+fn crosses<T: Scalar>(from: T, to: T) -> bool {
+    (from > T::ZERO && to < T::ZERO) || (from < T::ZERO && to > T::ZERO)
+}
+
+#[inline]
+fn largest_axis<T: Scalar, B: Basis<3>>(vector: Vector3D<T, B>) -> usize {
+    let [x, y, z] = vector.to_array().map(T::abs);
+    if x >= y && x >= z { 0 } else if y >= z { 1 } else { 2 }
+}
+
+/// Interval covered on the intersection line of two planes by a triangle, from the projections of its vertices onto the line, and their signed distances from the other plane
+///
+/// Returns `None` if the triangle lies in the other plane
+fn line_interval<T: Scalar>(projections: [T; 3], distances: [T; 3]) -> Option<(T, T)> {
+    let [d0, d1, d2] = distances;
+    // Index of the vertex alone on its side of the plane
+    let alone = if d0 * d1 > T::ZERO {
+        2
+    } else if d0 * d2 > T::ZERO {
+        1
+    } else if d1 * d2 > T::ZERO || d0 != T::ZERO {
+        0
+    } else if d1 != T::ZERO {
+        1
+    } else if d2 != T::ZERO {
+        2
+    } else {
+        return None;
+    };
+    let (i, j) = ((alone + 1) % 3, (alone + 2) % 3);
+    let crossing = |other: usize| projections[alone] + (projections[other] - projections[alone]) * distances[alone] / (distances[alone] - distances[other]);
+    let (first, second) = (crossing(i), crossing(j));
+    Some((first.min(second), first.max(second)))
+}
+
+#[inline]
+fn orientation_2d<T: Scalar>(a: [T; 2], b: [T; 2], c: [T; 2]) -> T {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// True if the closed segments intersect or touch
+fn segments_intersect_2d<T: Scalar>([p, q]: [[T; 2]; 2], [r, s]: [[T; 2]; 2]) -> bool {
+    let (o1, o2) = (orientation_2d(p, q, r), orientation_2d(p, q, s));
+    let (o3, o4) = (orientation_2d(r, s, p), orientation_2d(r, s, q));
+    if o1 == T::ZERO && o2 == T::ZERO {
+        // Collinear; The segments intersect if their extents overlap along both axes
+        return (0..2).all(|axis| p[axis].min(q[axis]) <= r[axis].max(s[axis]) && r[axis].min(s[axis]) <= p[axis].max(q[axis]));
+    }
+    o1 * o2 <= T::ZERO && o3 * o4 <= T::ZERO
+}
+
+/// True if `point` lies inside or on the boundary of the triangle
+fn contains_2d<T: Scalar>([a, b, c]: [[T; 2]; 3], point: [T; 2]) -> bool {
+    let orientations = [orientation_2d(a, b, point), orientation_2d(b, c, point), orientation_2d(c, a, point)];
+    orientations.into_iter().all(|orientation| orientation >= T::ZERO) || orientations.into_iter().all(|orientation| orientation <= T::ZERO)
 }
 
 impl<T: Scalar, const N: usize, B: Basis<N>> AbstractTriangle<T> for Triangle<T, N, B> {
@@ -57,32 +601,33 @@ impl<T: Scalar, const N: usize, B: Basis<N>> AbstractTriangle<T> for Triangle<T,
     #[inline]
     fn length_c(self) -> T { (self.A - self.B).magnitude() }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
     #[inline]
-    fn angle_alpha(self) -> T {
-        law_of_cosines::alpha_from_abc(self.length_a(), self.length_b(), self.length_c())
-            .expect("triangle constructed from points should always be valid!")
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
+    #[inline]
+    fn angle_alpha(self) -> Angle<T> {
+        angle_between(self.B - self.A, self.C - self.A)
     }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
-    fn angle_beta(self) -> T {
-        law_of_cosines::beta_from_abc(self.length_a(), self.length_b(), self.length_c())
-            .expect("triangle constructed from points should always be valid!")
+    fn angle_beta(self) -> Angle<T> {
+        angle_between(self.A - self.B, self.C - self.B)
     }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
-    fn angle_gamma(self) -> T {
-        law_of_cosines::gamma_from_abc(self.length_a(), self.length_b(), self.length_c())
-            .expect("triangle constructed from points should always be valid!")
+    fn angle_gamma(self) -> Angle<T> {
+        angle_between(self.A - self.C, self.B - self.C)
     }
 
     type area_solutions = T;
     #[inline]
     fn area(self) -> Self::area_solutions {
-        triangle_area(self.length_a(), self.length_b(), self.length_c())
-            .expect("triangle constructed from points should always be valid!")
+        let (base, altitude) = self.longest_side_altitude();
+        T::f(0.5) * base * altitude
     }
 
     type altitude_a_solutions = T;
@@ -104,9 +649,23 @@ impl<T: Scalar, const N: usize, B: Basis<N>> AbstractTriangle<T> for Triangle<T,
     }
 }
 
+impl<T: Scalar + ApproxEq<Epsilon=T>, const N: usize, B: Basis<N>> ApproxEq for Triangle<T, N, B> {
+    impl_approx_eq_fields!(T; A, B, C);
+}
+
 /// Macro for instantiating abstract triangles
 ///
-/// Parameters in order of lengths then angles, each in alphabetical order
+/// Parameters in order of lengths then angles, each in alphabetical order. Angles are given as [`Angle`](crate::angle::Angle)
+///
+/// ```
+/// use unifiedgeometry::abstract_triangle;
+/// use unifiedgeometry::angle::Angle;
+/// use unifiedgeometry::shapes::triangle::AbstractTriangle;
+///
+/// let triangle = abstract_triangle!{ a: 1.0, alpha: Angle::from_degrees(30.0), beta: Angle::from_degrees(90.0) }.unwrap();
+/// assert!((triangle.length_b() - 2.0f64).abs() < 1e-12);
+/// assert!((triangle.angle_gamma().degrees() - 60.0f64).abs() < 1e-12);
+/// ```
 #[macro_export]
 macro_rules! abstract_triangle {
     {a: $a:expr, b: $b:expr, c: $c:expr} => { $crate::shapes::triangle::AbstractTriangle_abc::new($a, $b, $c) };
@@ -135,7 +694,7 @@ macro_rules! abstract_triangle {
 }
 
 #[inline]
-fn chain_solution<T, F: Fn(T) -> T>(solution: (T, Option<T>), f: F) -> (T, Option<T>) {
+fn chain_solution<T, U, F: Fn(T) -> U>(solution: (T, Option<T>), f: F) -> (U, Option<U>) {
     (f(solution.0), solution.1.map(f))
 }
 
@@ -144,6 +703,7 @@ fn chain_solution<T, F: Fn(T) -> T>(solution: (T, Option<T>), f: F) -> (T, Optio
 ///
 /// This trait provides methods to calculate the other properties of a triangle. In certain configurations, an abstract triangle has ambiguity about certain properties.
 /// Where this is the case, the respective methods return `(T, Option<T>)`.
+/// Angles are represented as [`Angle<T>`], and lengths and areas as `T`.
 ///
 /// Instances can be created through the [`abstract_triangle!`] macro, which will provide a specific type for each kind
 pub trait AbstractTriangle<T: Scalar>: Copy {
@@ -156,13 +716,13 @@ pub trait AbstractTriangle<T: Scalar>: Copy {
     type len_c_solutions: MaybeTwo<T>;
     fn length_c(self) -> Self::len_c_solutions;
 
-    type angle_alpha_solutions: MaybeTwo<T>;
+    type angle_alpha_solutions: MaybeTwo<Angle<T>>;
     fn angle_alpha(self) -> Self::angle_alpha_solutions;
 
-    type angle_beta_solutions: MaybeTwo<T>;
+    type angle_beta_solutions: MaybeTwo<Angle<T>>;
     fn angle_beta(self) -> Self::angle_beta_solutions;
 
-    type angle_gamma_solutions: MaybeTwo<T>;
+    type angle_gamma_solutions: MaybeTwo<Angle<T>>;
     fn angle_gamma(self) -> Self::angle_gamma_solutions;
 
     type area_solutions: MaybeTwo<T>;   // At most 1 length is ambiguous, so there can only be at most 2 options for an ambiguous area
@@ -176,11 +736,101 @@ pub trait AbstractTriangle<T: Scalar>: Copy {
 
     type altitude_c_solutions: MaybeTwo<T>;
     fn altitude_c(self) -> Self::altitude_c_solutions;
+
+    /// All 3 side lengths, as `[a, b, c]`
+    type sides_solutions: MaybeTwo<[T; 3]>;
+    fn sides(self) -> Self::sides_solutions;
+
+    /// Sum of the side lengths
+    #[inline]
+    fn perimeter(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| a + b + c)
+    }
+
+    /// Half of the [perimeter](Self::perimeter)
+    #[inline]
+    fn semiperimeter(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| T::f(0.5) * (a + b + c))
+    }
+
+    /// Radius of the incircle, which touches all 3 sides from the inside
+    #[inline]
+    fn inradius(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::inradius(a, b, c))
+    }
+
+    /// Radius of the circumcircle, which passes through all 3 vertices
+    #[inline]
+    fn circumradius(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::circumradius(a, b, c))
+    }
+
+    /// Radius of the excircle opposite vertex A, which touches side `a` and the extensions of sides `b` and `c`
+    #[inline]
+    fn exradius_a(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::exradius(a, b, c))
+    }
+
+    /// Radius of the excircle opposite vertex B
+    #[inline]
+    fn exradius_b(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::exradius(b, c, a))
+    }
+
+    /// Radius of the excircle opposite vertex C
+    #[inline]
+    fn exradius_c(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::exradius(c, a, b))
+    }
+
+    /// Length of the median from vertex A to the midpoint of side `a`
+    #[inline]
+    fn median_a(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::median(a, b, c))
+    }
+
+    /// Length of the median from vertex B to the midpoint of side `b`
+    #[inline]
+    fn median_b(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::median(b, c, a))
+    }
+
+    /// Length of the median from vertex C to the midpoint of side `c`
+    #[inline]
+    fn median_c(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::median(c, a, b))
+    }
+
+    /// Length of the bisector of angle `alpha`, from vertex A to side `a`
+    #[inline]
+    fn angle_bisector_a(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::angle_bisector(a, b, c))
+    }
+
+    /// Length of the bisector of angle `beta`, from vertex B to side `b`
+    #[inline]
+    fn angle_bisector_b(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::angle_bisector(b, c, a))
+    }
+
+    /// Length of the bisector of angle `gamma`, from vertex C to side `c`
+    #[inline]
+    fn angle_bisector_c(self) -> SideSolutions<Self, T> {
+        self.sides().map(|[a, b, c]| formulas::angle_bisector(c, a, b))
+    }
 }
 
+/// Solutions of a property derived from all 3 side lengths of the abstract triangle `R`;
+/// `T` where the sides are known, or `(T, Option<T>)` where they are ambiguous, following [`AbstractTriangle::sides_solutions`]
+pub type SideSolutions<R, T> = <<R as AbstractTriangle<T>>::sides_solutions as MaybeTwo<[T; 3]>>::GenericSelf<T>;
+
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct AbstractTriangle_abc<T: Scalar> { a: T, b: T, c: T }
 
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_abc<T> {
+    impl_approx_eq_fields!(T; a, b, c);
+}
+
 impl<T: Scalar> AbstractTriangle_abc<T> {
     #[inline]
     pub fn new(a: T, b: T, c: T) -> Result<Self, InvalidTriangleError> {
@@ -205,19 +855,23 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_abc<T> {
     #[inline]
     fn length_c(self) -> Self::len_c_solutions { self.c }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions {
         law_of_cosines::alpha_from_abc(self.a, self.b, self.c).expect("triangle must be valid")
     }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions {
         law_of_cosines::beta_from_abc(self.a, self.b, self.c).expect("triangle must be valid")
     }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions {
         law_of_cosines::gamma_from_abc(self.a, self.b, self.c).expect("triangle must be valid")
@@ -250,17 +904,21 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_abc<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_abα<T> { a: T, b: T, alpha: T }
+pub struct AbstractTriangle_abα<T> { a: T, b: T, alpha: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_abα<T> {
+    impl_approx_eq_fields!(T; a, b, alpha);
+}
 
 impl<T: Scalar> AbstractTriangle_abα<T> {
     #[inline]
-    pub fn new(a: T, b: T, alpha: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(a: T, b: T, alpha: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if a <= T::ZERO || b <= T::ZERO || !a.is_finite() || !b.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if alpha <= T::ZERO || alpha >= T::PI || !alpha.is_finite() {
+        } else if alpha <= Angle::ZERO || alpha >= Angle::STRAIGHT || !alpha.is_finite() {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
-            if let Some(tangent_angle) = (a/b).asin() { // If A > B, all angles are valid so we don't need to test
+            if let Some(tangent_angle) = Angle::asin(a/b) { // If A > B, all angles are valid so we don't need to test
                 if alpha > tangent_angle {
                     return Err(InvalidTriangleError::AngleTooLarge)
                 }
@@ -285,11 +943,17 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_abα<T> {
         law_of_cosines::c_from_abα(self.a, self.b, self.alpha).expect("triangle must be valid")
     }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = ([T; 3], Option<[T; 3]>);
+    #[inline]
+    fn sides(self) -> Self::sides_solutions {
+        chain_solution(self.length_c(), |c| [self.length_a(), self.length_b(), c])
+    }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions { self.alpha }
 
-    type angle_beta_solutions = (T, Option<T>);
+    type angle_beta_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions {
         chain_solution(
@@ -298,7 +962,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_abα<T> {
         )
     }
 
-    type angle_gamma_solutions = (T, Option<T>);
+    type angle_gamma_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions {
         chain_solution(
@@ -360,16 +1024,20 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_abα<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_acα<T> { a: T, c: T, alpha: T }
+pub struct AbstractTriangle_acα<T> { a: T, c: T, alpha: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_acα<T> {
+    impl_approx_eq_fields!(T; a, c, alpha);
+}
 
 impl<T: Scalar> AbstractTriangle_acα<T> {
-    pub fn new(a: T, c: T, alpha: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(a: T, c: T, alpha: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if a <= T::ZERO || c <= T::ZERO || !a.is_finite() || !c.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if alpha <= T::ZERO || alpha >= T::PI || !alpha.is_finite() {
+        } else if alpha <= Angle::ZERO || alpha >= Angle::STRAIGHT || !alpha.is_finite() {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
-            if let Some(tangent_angle) = (a/c).asin() { // If A > C, all angles are valid so we don't need to test
+            if let Some(tangent_angle) = Angle::asin(a/c) { // If A > C, all angles are valid so we don't need to test
                 if alpha > tangent_angle {
                     return Err(InvalidTriangleError::AngleTooLarge)
                 }
@@ -394,11 +1062,17 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_acα<T> {
     #[inline]
     fn length_c(self) -> Self::len_c_solutions { self.c }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = ([T; 3], Option<[T; 3]>);
+    #[inline]
+    fn sides(self) -> Self::sides_solutions {
+        chain_solution(self.length_b(), |b| [self.length_a(), b, self.length_c()])
+    }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions { self.alpha }
 
-    type angle_beta_solutions = (T, Option<T>);
+    type angle_beta_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions {
         chain_solution(
@@ -407,7 +1081,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_acα<T> {
         )
     }
 
-    type angle_gamma_solutions = (T, Option<T>);
+    type angle_gamma_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions {
         chain_solution(
@@ -469,13 +1143,17 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_acα<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_bcα<T> { b: T, c: T, alpha: T }
+pub struct AbstractTriangle_bcα<T> { b: T, c: T, alpha: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_bcα<T> {
+    impl_approx_eq_fields!(T; b, c, alpha);
+}
 
 impl<T: Scalar> AbstractTriangle_bcα<T> {
-    pub fn new(b: T, c: T, alpha: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(b: T, c: T, alpha: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if b <= T::ZERO || c <= T::ZERO || !b.is_finite() || !c.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if alpha <= T::ZERO || alpha >= T::PI || !alpha.is_finite() {
+        } else if alpha <= Angle::ZERO || alpha >= Angle::STRAIGHT || !alpha.is_finite() {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { b, c, alpha })
@@ -496,17 +1174,21 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bcα<T> {
     #[inline]
     fn length_c(self) -> Self::len_c_solutions { self.c }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions { self.alpha }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions {
         law_of_cosines::beta_from_abc(self.length_a(), self.b, self.c).expect("triangle must be valid")
     }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions {
         law_of_cosines::gamma_from_abc(self.length_a(), self.b, self.c).expect("triangle must be valid")
@@ -539,16 +1221,20 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bcα<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_abβ<T> { a: T, b: T, beta: T }
+pub struct AbstractTriangle_abβ<T> { a: T, b: T, beta: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_abβ<T> {
+    impl_approx_eq_fields!(T; a, b, beta);
+}
 
 impl<T: Scalar> AbstractTriangle_abβ<T> {
-    pub fn new(a: T, b: T, beta: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(a: T, b: T, beta: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if a <= T::ZERO || b <= T::ZERO || !a.is_finite() || !b.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if beta <= T::ZERO || beta >= T::PI || !beta.is_finite() {
+        } else if beta <= Angle::ZERO || beta >= Angle::STRAIGHT || !beta.is_finite() {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
-            if let Some(tangent_angle) = (b/a).asin() { // If B > A, all angles are valid so we don't need to test
+            if let Some(tangent_angle) = Angle::asin(b/a) { // If B > A, all angles are valid so we don't need to test
                 if beta > tangent_angle {
                     return Err(InvalidTriangleError::AngleTooLarge)
                 }
@@ -573,7 +1259,13 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_abβ<T> {
         law_of_cosines::c_from_abβ(self.a, self.b, self.beta).expect("triangle must be valid")
     }
 
-    type angle_alpha_solutions = (T, Option<T>);
+    type sides_solutions = ([T; 3], Option<[T; 3]>);
+    #[inline]
+    fn sides(self) -> Self::sides_solutions {
+        chain_solution(self.length_c(), |c| [self.length_a(), self.length_b(), c])
+    }
+
+    type angle_alpha_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions {
         chain_solution(
@@ -582,11 +1274,11 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_abβ<T> {
         )
     }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions { self.beta }
 
-    type angle_gamma_solutions = (T, Option<T>);
+    type angle_gamma_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions {
         chain_solution(
@@ -648,13 +1340,17 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_abβ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_acβ<T> { a: T, c: T, beta: T }
+pub struct AbstractTriangle_acβ<T> { a: T, c: T, beta: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_acβ<T> {
+    impl_approx_eq_fields!(T; a, c, beta);
+}
 
 impl<T: Scalar> AbstractTriangle_acβ<T> {
-    pub fn new(a: T, c: T, beta: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(a: T, c: T, beta: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if a <= T::ZERO || c <= T::ZERO || !a.is_finite() || !c.is_finite(){
             Err(InvalidTriangleError::InvalidLength)
-        } else if beta <= T::ZERO || beta >= T::PI || !beta.is_finite() {
+        } else if beta <= Angle::ZERO || beta >= Angle::STRAIGHT || !beta.is_finite() {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { a, c, beta })
@@ -677,17 +1373,21 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_acβ<T> {
     #[inline]
     fn length_c(self) -> Self::len_c_solutions { self.c }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions {
         law_of_cosines::alpha_from_abc(self.a, self.length_b(), self.c).expect("triangle must be valid")
     }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions { self.beta }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions {
         law_of_cosines::gamma_from_abc(self.a, self.length_b(), self.c).expect("triangle must be valid")
@@ -720,16 +1420,20 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_acβ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_bcβ<T> { b: T, c: T, beta: T }
+pub struct AbstractTriangle_bcβ<T> { b: T, c: T, beta: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_bcβ<T> {
+    impl_approx_eq_fields!(T; b, c, beta);
+}
 
 impl<T: Scalar> AbstractTriangle_bcβ<T> {
-    pub fn new(b: T, c: T, beta: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(b: T, c: T, beta: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if b <= T::ZERO || c <= T::ZERO || !b.is_finite() || !c.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if beta <= T::ZERO || beta >= T::PI || !beta.is_finite() {
+        } else if beta <= Angle::ZERO || beta >= Angle::STRAIGHT || !beta.is_finite() {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
-            if let Some(tangent_angle) = (b/c).asin() { // If B > C, all angles are valid so we don't need to test
+            if let Some(tangent_angle) = Angle::asin(b/c) { // If B > C, all angles are valid so we don't need to test
                 if beta > tangent_angle {
                     return Err(InvalidTriangleError::AngleTooLarge)
                 }
@@ -754,7 +1458,13 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bcβ<T> {
     #[inline]
     fn length_c(self) -> Self::len_c_solutions { self.c }
 
-    type angle_alpha_solutions = (T, Option<T>);
+    type sides_solutions = ([T; 3], Option<[T; 3]>);
+    #[inline]
+    fn sides(self) -> Self::sides_solutions {
+        chain_solution(self.length_a(), |a| [a, self.length_b(), self.length_c()])
+    }
+
+    type angle_alpha_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions {
         chain_solution(
@@ -763,11 +1473,11 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bcβ<T> {
         )
     }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions { self.beta }
 
-    type angle_gamma_solutions = (T, Option<T>);
+    type angle_gamma_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions {
         chain_solution(
@@ -829,13 +1539,17 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bcβ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_abγ<T> { a: T, b: T, gamma: T }
+pub struct AbstractTriangle_abγ<T> { a: T, b: T, gamma: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_abγ<T> {
+    impl_approx_eq_fields!(T; a, b, gamma);
+}
 
 impl<T: Scalar> AbstractTriangle_abγ<T> {
-    pub fn new(a: T, b: T, gamma: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(a: T, b: T, gamma: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if a <= T::ZERO || b <= T::ZERO || !a.is_finite() || !b.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if gamma <= T::ZERO || gamma >= T::PI || !gamma.is_finite() {
+        } else if gamma <= Angle::ZERO || gamma >= Angle::STRAIGHT || !gamma.is_finite() {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { a, b, gamma })
@@ -858,19 +1572,23 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_abγ<T> {
         law_of_cosines::c_from_abγ(self.a, self.b, self.gamma).expect("triangle must be valid")
     }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions {
         law_of_cosines::alpha_from_abc(self.a, self.b, self.length_c()).expect("triangle must be valid")
     }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions {
         law_of_cosines::beta_from_abc(self.a, self.b, self.length_c()).expect("triangle must be valid")
     }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions { self.gamma }
 
@@ -901,16 +1619,20 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_abγ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_acγ<T> { a: T, c: T, gamma: T }
+pub struct AbstractTriangle_acγ<T> { a: T, c: T, gamma: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_acγ<T> {
+    impl_approx_eq_fields!(T; a, c, gamma);
+}
 
 impl<T: Scalar> AbstractTriangle_acγ<T> {
-    pub fn new(a: T, c: T, gamma: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(a: T, c: T, gamma: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if a <= T::ZERO || c <= T::ZERO || !a.is_finite() || !c.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if gamma <= T::ZERO || gamma >= T::PI || !gamma.is_finite() {
+        } else if gamma <= Angle::ZERO || gamma >= Angle::STRAIGHT || !gamma.is_finite() {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
-            if let Some(tangent_angle) = (c/a).asin() { // If C > A, all angles are valid so we don't need to test
+            if let Some(tangent_angle) = Angle::asin(c/a) { // If C > A, all angles are valid so we don't need to test
                 if gamma > tangent_angle {
                     return Err(InvalidTriangleError::AngleTooLarge)
                 }
@@ -935,7 +1657,13 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_acγ<T> {
     #[inline]
     fn length_c(self) -> Self::len_c_solutions { self.c }
 
-    type angle_alpha_solutions = (T, Option<T>);
+    type sides_solutions = ([T; 3], Option<[T; 3]>);
+    #[inline]
+    fn sides(self) -> Self::sides_solutions {
+        chain_solution(self.length_b(), |b| [self.length_a(), b, self.length_c()])
+    }
+
+    type angle_alpha_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions {
         chain_solution(
@@ -944,7 +1672,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_acγ<T> {
         )
     }
 
-    type angle_beta_solutions = (T, Option<T>);
+    type angle_beta_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions {
         chain_solution(
@@ -953,7 +1681,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_acγ<T> {
         )
     }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions { self.gamma }
 
@@ -1010,16 +1738,20 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_acγ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_bcγ<T> { b: T, c: T, gamma: T }
+pub struct AbstractTriangle_bcγ<T> { b: T, c: T, gamma: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_bcγ<T> {
+    impl_approx_eq_fields!(T; b, c, gamma);
+}
 
 impl<T: Scalar> AbstractTriangle_bcγ<T> {
-    pub fn new(b: T, c: T, gamma: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(b: T, c: T, gamma: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if b <= T::ZERO || c <= T::ZERO || !b.is_finite() || !c.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if gamma <= T::ZERO || gamma >= T::PI || !gamma.is_finite() {
+        } else if gamma <= Angle::ZERO || gamma >= Angle::STRAIGHT || !gamma.is_finite() {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
-            if let Some(tangent_angle) = (c/b).asin() { // If C > B, all angles are valid so we don't need to test
+            if let Some(tangent_angle) = Angle::asin(c/b) { // If C > B, all angles are valid so we don't need to test
                 if gamma > tangent_angle {
                     return Err(InvalidTriangleError::AngleTooLarge)
                 }
@@ -1044,7 +1776,13 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bcγ<T> {
     #[inline]
     fn length_c(self) -> Self::len_c_solutions { self.c }
 
-    type angle_alpha_solutions = (T, Option<T>);
+    type sides_solutions = ([T; 3], Option<[T; 3]>);
+    #[inline]
+    fn sides(self) -> Self::sides_solutions {
+        chain_solution(self.length_a(), |a| [a, self.length_b(), self.length_c()])
+    }
+
+    type angle_alpha_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions {
         chain_solution(
@@ -1053,7 +1791,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bcγ<T> {
         )
     }
 
-    type angle_beta_solutions = (T, Option<T>);
+    type angle_beta_solutions = (Angle<T>, Option<Angle<T>>);
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions {
         chain_solution(
@@ -1062,7 +1800,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bcγ<T> {
         )
     }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions { self.gamma }
 
@@ -1119,17 +1857,19 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bcγ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_aαβ<T> { a: T, alpha: T, beta: T }
+pub struct AbstractTriangle_aαβ<T> { a: T, alpha: Angle<T>, beta: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_aαβ<T> {
+    impl_approx_eq_fields!(T; a, alpha, beta);
+}
 
 impl<T: Scalar> AbstractTriangle_aαβ<T> {
-    pub fn new(a: T, alpha: T, beta: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(a: T, alpha: Angle<T>, beta: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if a <= T::ZERO || !a.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if alpha <= T::ZERO || alpha >= T::PI || !alpha.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if beta <= T::ZERO || beta >= T::PI || !beta.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if (alpha + beta) >= T::PI {
+        } else if alpha <= Angle::ZERO || alpha >= Angle::STRAIGHT || !alpha.is_finite()
+            || beta <= Angle::ZERO || beta >= Angle::STRAIGHT || !beta.is_finite()
+            || (alpha + beta) >= Angle::STRAIGHT {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { a, alpha, beta })
@@ -1151,22 +1891,26 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_aαβ<T> {
     type len_c_solutions = T;
     #[inline]
     fn length_c(self) -> Self::len_c_solutions {
-        let gamma = T::PI - (self.alpha + self.beta);
+        let gamma = Angle::STRAIGHT - (self.alpha + self.beta);
         law_of_sines::c_from_aαγ(self.a, self.alpha, gamma).expect("triangle must be valid")
     }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions { self.alpha }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions { self.beta }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions {
-        T::PI - (self.alpha + self.beta)
+        Angle::STRAIGHT - (self.alpha + self.beta)
     }
 
     type area_solutions = T;
@@ -1196,17 +1940,19 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_aαβ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_bαβ<T> { b: T, alpha: T, beta: T }
+pub struct AbstractTriangle_bαβ<T> { b: T, alpha: Angle<T>, beta: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_bαβ<T> {
+    impl_approx_eq_fields!(T; b, alpha, beta);
+}
 
 impl<T: Scalar> AbstractTriangle_bαβ<T> {
-    pub fn new(b: T, alpha: T, beta: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(b: T, alpha: Angle<T>, beta: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if b <= T::ZERO || !b.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if alpha <= T::ZERO || alpha >= T::PI || !alpha.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if beta <= T::ZERO || beta >= T::PI || !beta.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if (alpha + beta) >= T::PI {
+        } else if alpha <= Angle::ZERO || alpha >= Angle::STRAIGHT || !alpha.is_finite()
+            || beta <= Angle::ZERO || beta >= Angle::STRAIGHT || !beta.is_finite()
+            || (alpha + beta) >= Angle::STRAIGHT {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { b, alpha, beta })
@@ -1226,22 +1972,26 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bαβ<T> {
     type len_c_solutions = T;
     #[inline]
     fn length_c(self) -> Self::len_c_solutions {
-        let gamma = T::PI - (self.alpha + self.beta);
+        let gamma = Angle::STRAIGHT - (self.alpha + self.beta);
         law_of_sines::c_from_bβγ(self.b, self.beta, gamma).expect("triangle must be valid")
     }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions { self.alpha }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions { self.beta }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions {
-        T::PI - (self.alpha + self.beta)
+        Angle::STRAIGHT - (self.alpha + self.beta)
     }
 
     type area_solutions = T;
@@ -1271,17 +2021,19 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bαβ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_cαβ<T> { c: T, alpha: T, beta: T }
+pub struct AbstractTriangle_cαβ<T> { c: T, alpha: Angle<T>, beta: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_cαβ<T> {
+    impl_approx_eq_fields!(T; c, alpha, beta);
+}
 
 impl<T: Scalar> AbstractTriangle_cαβ<T> {
-    pub fn new(c: T, alpha: T, beta: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(c: T, alpha: Angle<T>, beta: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if c <= T::ZERO || !c.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if alpha <= T::ZERO || alpha >= T::PI || !alpha.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if beta <= T::ZERO || beta >= T::PI || !beta.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if (alpha + beta) >= T::PI {
+        } else if alpha <= Angle::ZERO || alpha >= Angle::STRAIGHT || !alpha.is_finite()
+            || beta <= Angle::ZERO || beta >= Angle::STRAIGHT || !beta.is_finite()
+            || (alpha + beta) >= Angle::STRAIGHT {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { c, alpha, beta })
@@ -1293,14 +2045,14 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_cαβ<T> {
     type len_a_solutions = T;
     #[inline]
     fn length_a(self) -> Self::len_a_solutions {
-        let gamma = T::PI - (self.alpha + self.beta);
+        let gamma = Angle::STRAIGHT - (self.alpha + self.beta);
         law_of_sines::a_from_cαγ(self.c, self.alpha, gamma).expect("triangle must be valid")
     }
 
     type len_b_solutions = T;
     #[inline]
     fn length_b(self) -> Self::len_b_solutions {
-        let gamma = T::PI - (self.alpha + self.beta);
+        let gamma = Angle::STRAIGHT - (self.alpha + self.beta);
         law_of_sines::b_from_cβγ(self.c, self.beta, gamma).expect("triangle must be valid")
     }
 
@@ -1308,18 +2060,22 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_cαβ<T> {
     #[inline]
     fn length_c(self) -> Self::len_c_solutions { self.c }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions { self.alpha }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions { self.beta }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions {
-        T::PI - (self.alpha + self.beta)
+        Angle::STRAIGHT - (self.alpha + self.beta)
     }
 
     type area_solutions = T;
@@ -1349,17 +2105,19 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_cαβ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_aαγ<T> { a: T, alpha: T, gamma: T }
+pub struct AbstractTriangle_aαγ<T> { a: T, alpha: Angle<T>, gamma: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_aαγ<T> {
+    impl_approx_eq_fields!(T; a, alpha, gamma);
+}
 
 impl<T: Scalar> AbstractTriangle_aαγ<T> {
-    pub fn new(a: T, alpha: T, gamma: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(a: T, alpha: Angle<T>, gamma: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if a <= T::ZERO || !a.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if alpha <= T::ZERO || alpha >= T::PI || !alpha.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if gamma <= T::ZERO || gamma >= T::PI || !gamma.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if (alpha + gamma) >= T::PI {
+        } else if alpha <= Angle::ZERO || alpha >= Angle::STRAIGHT || !alpha.is_finite()
+            || gamma <= Angle::ZERO || gamma >= Angle::STRAIGHT || !gamma.is_finite()
+            || (alpha + gamma) >= Angle::STRAIGHT {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { a, alpha, gamma })
@@ -1375,7 +2133,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_aαγ<T> {
     type len_b_solutions = T;
     #[inline]
     fn length_b(self) -> Self::len_b_solutions {
-        let beta = T::PI - (self.alpha + self.gamma);
+        let beta = Angle::STRAIGHT - (self.alpha + self.gamma);
         law_of_sines::b_from_aαβ(self.a, self.alpha, beta).expect("triangle must be valid")
     }
 
@@ -1385,17 +2143,21 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_aαγ<T> {
         law_of_sines::c_from_aαγ(self.a, self.alpha, self.gamma).expect("triangle must be valid")
     }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions { self.alpha }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions {
-        T::PI - (self.alpha + self.gamma)
+        Angle::STRAIGHT - (self.alpha + self.gamma)
     }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions { self.gamma }
 
@@ -1426,17 +2188,19 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_aαγ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_bαγ<T> { b: T, alpha: T, gamma: T }
+pub struct AbstractTriangle_bαγ<T> { b: T, alpha: Angle<T>, gamma: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_bαγ<T> {
+    impl_approx_eq_fields!(T; b, alpha, gamma);
+}
 
 impl<T: Scalar> AbstractTriangle_bαγ<T> {
-    pub fn new(b: T, alpha: T, gamma: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(b: T, alpha: Angle<T>, gamma: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if b <= T::ZERO || !b.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if alpha <= T::ZERO || alpha >= T::PI || !alpha.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if gamma <= T::ZERO || gamma >= T::PI || !gamma.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if (alpha + gamma) >= T::PI {
+        } else if alpha <= Angle::ZERO || alpha >= Angle::STRAIGHT || !alpha.is_finite()
+            || gamma <= Angle::ZERO || gamma >= Angle::STRAIGHT || !gamma.is_finite()
+            || (alpha + gamma) >= Angle::STRAIGHT {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { b, alpha, gamma })
@@ -1448,7 +2212,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bαγ<T> {
     type len_a_solutions = T;
     #[inline]
     fn length_a(self) -> Self::len_a_solutions {
-        let beta = T::PI - (self.alpha + self.gamma);
+        let beta = Angle::STRAIGHT - (self.alpha + self.gamma);
         law_of_sines::a_from_bαβ(self.b, self.alpha, beta).expect("triangle must be valid")
     }
 
@@ -1459,21 +2223,25 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bαγ<T> {
     type len_c_solutions = T;
     #[inline]
     fn length_c(self) -> Self::len_c_solutions {
-        let beta = T::PI - (self.alpha + self.gamma);
+        let beta = Angle::STRAIGHT - (self.alpha + self.gamma);
         law_of_sines::c_from_bβγ(self.b, beta, self.gamma).expect("triangle must be valid")
     }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions { self.alpha }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions {
-        T::PI - (self.alpha + self.gamma)
+        Angle::STRAIGHT - (self.alpha + self.gamma)
     }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions { self.gamma }
 
@@ -1504,17 +2272,19 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bαγ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_cαγ<T> { c: T, alpha: T, gamma: T }
+pub struct AbstractTriangle_cαγ<T> { c: T, alpha: Angle<T>, gamma: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_cαγ<T> {
+    impl_approx_eq_fields!(T; c, alpha, gamma);
+}
 
 impl<T: Scalar> AbstractTriangle_cαγ<T> {
-    pub fn new(c: T, alpha: T, gamma: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(c: T, alpha: Angle<T>, gamma: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if c <= T::ZERO || !c.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if alpha <= T::ZERO || alpha >= T::PI || !alpha.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if gamma <= T::ZERO || gamma >= T::PI || !gamma.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if (alpha + gamma) >= T::PI {
+        } else if alpha <= Angle::ZERO || alpha >= Angle::STRAIGHT || !alpha.is_finite()
+            || gamma <= Angle::ZERO || gamma >= Angle::STRAIGHT || !gamma.is_finite()
+            || (alpha + gamma) >= Angle::STRAIGHT {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { c, alpha, gamma })
@@ -1530,7 +2300,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_cαγ<T> {
     type len_b_solutions = T;
     #[inline]
     fn length_b(self) -> Self::len_b_solutions {
-        let beta = T::PI - (self.alpha + self.gamma);
+        let beta = Angle::STRAIGHT - (self.alpha + self.gamma);
         law_of_sines::b_from_cβγ(self.c, beta, self.gamma).expect("triangle must be valid")
     }
 
@@ -1538,17 +2308,21 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_cαγ<T> {
     #[inline]
     fn length_c(self) -> Self::len_c_solutions { self.c }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions { self.alpha }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions {
-        T::PI - (self.alpha + self.gamma)
+        Angle::STRAIGHT - (self.alpha + self.gamma)
     }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions { self.gamma }
 
@@ -1579,17 +2353,19 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_cαγ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_aβγ<T> { a: T, beta: T, gamma: T }
+pub struct AbstractTriangle_aβγ<T> { a: T, beta: Angle<T>, gamma: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_aβγ<T> {
+    impl_approx_eq_fields!(T; a, beta, gamma);
+}
 
 impl<T: Scalar> AbstractTriangle_aβγ<T> {
-    pub fn new(a: T, beta: T, gamma: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(a: T, beta: Angle<T>, gamma: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if a <= T::ZERO || !a.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if beta <= T::ZERO || beta >= T::PI || !beta.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if gamma <= T::ZERO || gamma >= T::PI || !gamma.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if (beta + gamma) >= T::PI {
+        } else if beta <= Angle::ZERO || beta >= Angle::STRAIGHT || !beta.is_finite()
+            || gamma <= Angle::ZERO || gamma >= Angle::STRAIGHT || !gamma.is_finite()
+            || (beta + gamma) >= Angle::STRAIGHT {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { a, beta, gamma })
@@ -1605,28 +2381,32 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_aβγ<T> {
     type len_b_solutions = T;
     #[inline]
     fn length_b(self) -> Self::len_b_solutions {
-        let alpha = T::PI - (self.beta + self.gamma);
+        let alpha = Angle::STRAIGHT - (self.beta + self.gamma);
         law_of_sines::b_from_aαβ(self.a, alpha, self.beta).expect("triangle must be valid")
     }
 
     type len_c_solutions = T;
     #[inline]
     fn length_c(self) -> Self::len_c_solutions {
-        let alpha = T::PI - (self.beta + self.gamma);
+        let alpha = Angle::STRAIGHT - (self.beta + self.gamma);
         law_of_sines::c_from_aαγ(self.a, alpha, self.gamma).expect("triangle must be valid")
     }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions {
-        T::PI - (self.beta + self.gamma)
+        Angle::STRAIGHT - (self.beta + self.gamma)
     }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions { self.beta }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions { self.gamma }
 
@@ -1657,17 +2437,19 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_aβγ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_bβγ<T> { b: T, beta: T, gamma: T }
+pub struct AbstractTriangle_bβγ<T> { b: T, beta: Angle<T>, gamma: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_bβγ<T> {
+    impl_approx_eq_fields!(T; b, beta, gamma);
+}
 
 impl<T: Scalar> AbstractTriangle_bβγ<T> {
-    pub fn new(b: T, beta: T, gamma: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(b: T, beta: Angle<T>, gamma: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if b <= T::ZERO || !b.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if beta <= T::ZERO || beta >= T::PI || !beta.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if gamma <= T::ZERO || gamma >= T::PI || !gamma.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if (beta + gamma) >= T::PI {
+        } else if beta <= Angle::ZERO || beta >= Angle::STRAIGHT || !beta.is_finite()
+            || gamma <= Angle::ZERO || gamma >= Angle::STRAIGHT || !gamma.is_finite()
+            || (beta + gamma) >= Angle::STRAIGHT {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { b, beta, gamma })
@@ -1679,7 +2461,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bβγ<T> {
     type len_a_solutions = T;
     #[inline]
     fn length_a(self) -> Self::len_a_solutions {
-        let alpha = T::PI - (self.beta + self.gamma);
+        let alpha = Angle::STRAIGHT - (self.beta + self.gamma);
         law_of_sines::a_from_bαβ(self.b, alpha, self.beta).expect("triangle must be valid")
     }
 
@@ -1693,17 +2475,21 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bβγ<T> {
         law_of_sines::c_from_bβγ(self.b, self.beta, self.gamma).expect("triangle must be valid")
     }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions {
-        T::PI - (self.beta + self.gamma)
+        Angle::STRAIGHT - (self.beta + self.gamma)
     }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions { self.beta }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions { self.gamma }
 
@@ -1734,17 +2520,19 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_bβγ<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct AbstractTriangle_cβγ<T> { c: T, beta: T, gamma: T }
+pub struct AbstractTriangle_cβγ<T> { c: T, beta: Angle<T>, gamma: Angle<T> }
+
+impl<T: Scalar + ApproxEq<Epsilon=T>> ApproxEq for AbstractTriangle_cβγ<T> {
+    impl_approx_eq_fields!(T; c, beta, gamma);
+}
 
 impl<T: Scalar> AbstractTriangle_cβγ<T> {
-    pub fn new(c: T, beta: T, gamma: T) -> Result<Self, InvalidTriangleError> {
+    pub fn new(c: T, beta: Angle<T>, gamma: Angle<T>) -> Result<Self, InvalidTriangleError> {
         if c <= T::ZERO || !c.is_finite() {
             Err(InvalidTriangleError::InvalidLength)
-        } else if beta <= T::ZERO || beta >= T::PI || !beta.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if gamma <= T::ZERO || gamma >= T::PI || !gamma.is_finite() {
-            Err(InvalidTriangleError::InvalidAngle)
-        } else if (beta + gamma) >= T::PI {
+        } else if beta <= Angle::ZERO || beta >= Angle::STRAIGHT || !beta.is_finite()
+            || gamma <= Angle::ZERO || gamma >= Angle::STRAIGHT || !gamma.is_finite()
+            || (beta + gamma) >= Angle::STRAIGHT {
             Err(InvalidTriangleError::InvalidAngle)
         } else {
             Ok(Self { c, beta, gamma })
@@ -1756,7 +2544,7 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_cβγ<T> {
     type len_a_solutions = T;
     #[inline]
     fn length_a(self) -> Self::len_a_solutions {
-        let alpha = T::PI - (self.beta + self.gamma);
+        let alpha = Angle::STRAIGHT - (self.beta + self.gamma);
         law_of_sines::a_from_cαγ(self.c, alpha, self.gamma).expect("triangle must be valid")
     }
 
@@ -1770,17 +2558,21 @@ impl<T: Scalar> AbstractTriangle<T> for AbstractTriangle_cβγ<T> {
     #[inline]
     fn length_c(self) -> Self::len_c_solutions { self.c }
 
-    type angle_alpha_solutions = T;
+    type sides_solutions = [T; 3];
+    #[inline]
+    fn sides(self) -> Self::sides_solutions { [self.length_a(), self.length_b(), self.length_c()] }
+
+    type angle_alpha_solutions = Angle<T>;
     #[inline]
     fn angle_alpha(self) -> Self::angle_alpha_solutions {
-        T::PI - (self.beta + self.gamma)
+        Angle::STRAIGHT - (self.beta + self.gamma)
     }
 
-    type angle_beta_solutions = T;
+    type angle_beta_solutions = Angle<T>;
     #[inline]
     fn angle_beta(self) -> Self::angle_beta_solutions { self.beta }
 
-    type angle_gamma_solutions = T;
+    type angle_gamma_solutions = Angle<T>;
     #[inline]
     fn angle_gamma(self) -> Self::angle_gamma_solutions { self.gamma }
 
@@ -1820,74 +2612,119 @@ pub mod formulas {
         let A2 = s*(s-a)*(s-b)*(s-c);
         Ok(A2.sqrt())
     }
+
+    /// Area from side lengths, using Kahan's rearrangement of Heron's formula
+    ///
+    /// Accurate for thin triangles, where Heron's formula suffers cancellation
+    #[inline]
+    pub fn triangle_area_stable<T: Scalar>(a: T, b: T, c: T) -> T {
+        // Requires x ≥ y ≥ z
+        let x = a.max(b).max(c);
+        let y = a.min(b).max(a.max(b).min(c));
+        let z = a.min(b).min(c);
+        let product = (x + (y + z)) * (z - (x - y)) * (z + (x - y)) * (x + (y - z));
+        T::f(0.25) * product.max(T::ZERO).sqrt()
+    }
+
+    /// Radius of the incircle, from side lengths
+    #[inline]
+    pub fn inradius<T: Scalar>(a: T, b: T, c: T) -> T {
+        T::i(2) * triangle_area_stable(a, b, c) / (a + b + c)
+    }
+
+    /// Radius of the circumcircle, from side lengths
+    #[inline]
+    pub fn circumradius<T: Scalar>(a: T, b: T, c: T) -> T {
+        (a * b * c) / (T::i(4) * triangle_area_stable(a, b, c))
+    }
+
+    /// Radius of the excircle touching side `a`, from side lengths
+    #[inline]
+    pub fn exradius<T: Scalar>(a: T, b: T, c: T) -> T {
+        T::i(2) * triangle_area_stable(a, b, c) / (b + c - a)
+    }
+
+    /// Length of the median onto side `a`, from side lengths
+    #[inline]
+    pub fn median<T: Scalar>(a: T, b: T, c: T) -> T {
+        T::f(0.5) * (T::i(2) * (b.powi(2) + c.powi(2)) - a.powi(2)).max(T::ZERO).sqrt()
+    }
+
+    /// Length of the bisector of the angle opposite side `a`, from side lengths
+    #[inline]
+    pub fn angle_bisector<T: Scalar>(a: T, b: T, c: T) -> T {
+        (b * c * (b + c + a) * (b + c - a)).max(T::ZERO).sqrt() / (b + c)
+    }
 }
 pub mod law_of_sines {
+    use crate::angle::Angle;
     use crate::scalar::Scalar;
     use crate::utility::InvalidInput;
 
     // TODO: Optimized versions for retrieving multiple values
 
-    /// Calculates length `a` from length `b` + angles `alpha` and `beta` (in radians)
+    /// Calculates length `a` from length `b` + angles `alpha` and `beta`
     ///
     /// Always returns Ok() where  `b`, `alpha`, `beta` > 0 and `alpha`, `beta` < PI and `alpha` + `beta` < PI
     #[inline]
-    pub fn a_from_bαβ<T: Scalar>(b: T, alpha: T, beta: T) -> Result<T, InvalidInput> {
+    pub fn a_from_bαβ<T: Scalar>(b: T, alpha: Angle<T>, beta: Angle<T>) -> Result<T, InvalidInput> {
         Some(b*(alpha.sin()/beta.sin()))
             .filter(|v| *v >= T::ZERO && v.is_finite()) // If `a` length is negative/infinity/NaN, there is no solution.
             .ok_or(InvalidInput)    // `a` is positive for all valid input
     }
 
-    /// Calculates length `a` from length `c` + angles `alpha` and `gamma` (in radians)
+    /// Calculates length `a` from length `c` + angles `alpha` and `gamma`
     ///
     /// Always returns Ok() where  `c`, `alpha`, `gamma` > 0 and `alpha`, `gamma` < PI and `alpha` + `gamma` < PI
     #[inline]
-    pub fn a_from_cαγ<T: Scalar>(c: T, alpha: T, gamma: T) -> Result<T, InvalidInput> {
+    pub fn a_from_cαγ<T: Scalar>(c: T, alpha: Angle<T>, gamma: Angle<T>) -> Result<T, InvalidInput> {
         Some(c*(alpha.sin()/gamma.sin()))
             .filter(|v| *v >= T::ZERO && v.is_finite()) // If `a` length is negative/infinity/NaN, there is no solution.
             .ok_or(InvalidInput)    // `a` is positive for all valid input
     }
 
-    /// Calculates length `b` from length `a` + angles `alpha` and `beta` (in radians)
+    /// Calculates length `b` from length `a` + angles `alpha` and `beta`
     ///
     /// Always returns Ok() where  `a`, `alpha`, `beta` > 0 and `alpha`, `beta` < PI and `alpha` + `beta` < PI
     #[inline]
-    pub fn b_from_aαβ<T: Scalar>(a: T, alpha: T, beta: T) -> Result<T, InvalidInput> {
+    pub fn b_from_aαβ<T: Scalar>(a: T, alpha: Angle<T>, beta: Angle<T>) -> Result<T, InvalidInput> {
         Some(a*(beta.sin()/alpha.sin()))
             .filter(|v| *v >= T::ZERO && v.is_finite()) // If `b` length is negative/infinity/NaN, there is no solution.
             .ok_or(InvalidInput)    // `b` is positive for all valid input
     }
 
-    /// Calculates length `b` from length `c` + angles `beta` and `gamma` (in radians)
+    /// Calculates length `b` from length `c` + angles `beta` and `gamma`
     ///
     /// Always returns Ok() where  `c`, `beta`, `gamma` > 0 and `beta`, `gamma` < PI and `beta` + `gamma` < PI
     #[inline]
-    pub fn b_from_cβγ<T: Scalar>(c: T, beta: T, gamma: T) -> Result<T, InvalidInput> {
+    pub fn b_from_cβγ<T: Scalar>(c: T, beta: Angle<T>, gamma: Angle<T>) -> Result<T, InvalidInput> {
         Some(c*(beta.sin()/gamma.sin()))
             .filter(|v| *v >= T::ZERO && v.is_finite()) // If `b` length is negative/infinity/NaN, there is no solution.
             .ok_or(InvalidInput)    // `b` is positive for all valid input
     }
 
-    /// Calculates length `c` from length `a` + angles `alpha` and `gamma` (in radians)
+    /// Calculates length `c` from length `a` + angles `alpha` and `gamma`
     ///
     /// Always returns Ok() where  `a`, `alpha`, `gamma` > 0 and `alpha`, `gamma` < PI and `alpha` + `gamma` < PI
     #[inline]
-    pub fn c_from_aαγ<T: Scalar>(a: T, alpha: T, gamma: T) -> Result<T, InvalidInput> {
+    pub fn c_from_aαγ<T: Scalar>(a: T, alpha: Angle<T>, gamma: Angle<T>) -> Result<T, InvalidInput> {
         Some(a*(gamma.sin()/alpha.sin()))
             .filter(|v| *v >= T::ZERO && v.is_finite()) // If `c` length is negative/infinity/NaN, there is no solution.
             .ok_or(InvalidInput)    // `c` is positive for all valid input
     }
 
-    /// Calculates length `c` from length `b` + angles `beta` and `gamma` (in radians)
+    /// Calculates length `c` from length `b` + angles `beta` and `gamma`
     ///
     /// Always returns Ok() where  `b`, `beta`, `gamma` > 0 and `beta`, `gamma` < PI and `beta` + `gamma` < PI
     #[inline]
-    pub fn c_from_bβγ<T: Scalar>(b: T, beta: T, gamma: T) -> Result<T, InvalidInput> {
+    pub fn c_from_bβγ<T: Scalar>(b: T, beta: Angle<T>, gamma: Angle<T>) -> Result<T, InvalidInput> {
         Some(b*(gamma.sin() / beta.sin()))
             .filter(|v| *v >= T::ZERO && v.is_finite()) // If `c` length is negative/infinity/NaN, there is no solution.
             .ok_or(InvalidInput)    // `c` is positive for all valid input
     }
 }
 pub mod law_of_cosines {
+    use crate::angle::Angle;
     use crate::scalar::Scalar;
     use crate::utility::InvalidInput;
 
@@ -1911,11 +2748,11 @@ pub mod law_of_cosines {
         }
     }
 
-    /// Calculates length `a` from lengths `b` and `c` + angle `alpha` (in radians)
+    /// Calculates length `a` from lengths `b` and `c` + angle `alpha`
     ///
     /// Always returns Ok() where  `b`, `c`, `alpha` > 0 and `alpha` < PI
     #[inline]
-    pub fn a_from_bcα<T: Scalar>(b: T, c: T, alpha: T) -> Result<T, InvalidInput> {
+    pub fn a_from_bcα<T: Scalar>(b: T, c: T, alpha: Angle<T>) -> Result<T, InvalidInput> {
         let a_squared = b.powi(2) + c.powi(2) - (T::i(2) * b * c * alpha.cos());
         if a_squared > T::ZERO && a_squared.is_finite() {    // Return None instead of NaN if there are no solutions
             Ok(a_squared.sqrt())
@@ -1924,41 +2761,41 @@ pub mod law_of_cosines {
         }
     }
 
-    /// Calculates length `a` from lengths `b` and `c` + angle `beta` (in radians)
+    /// Calculates length `a` from lengths `b` and `c` + angle `beta`
     ///
     /// Always returns Ok() where  `b`, `c`, `beta` > 0 and `beta' < PI
     #[inline]
-    pub fn a_from_bcβ<T: Scalar>(b: T, c: T, beta: T) -> Result<(T, Option<T>), InvalidInput> {
+    pub fn a_from_bcβ<T: Scalar>(b: T, c: T, beta: Angle<T>) -> Result<(T, Option<T>), InvalidInput> {
         let val = c * beta.cos();
         let squared = b.powi(2) + (c.powi(2) * beta.cos().powi(2)) - c.powi(2);
         return_solutions(val, squared)
     }
 
-    /// Calculates length `a` from lengths `b` and `c` + angle `gamma` (in radians)
+    /// Calculates length `a` from lengths `b` and `c` + angle `gamma`
     ///
     /// Always returns Ok() where  `b`, `c`, `gamma` > 0 and `beta' < PI
     #[inline]
-    pub fn a_from_bcγ<T: Scalar>(b: T, c: T, gamma: T) -> Result<(T, Option<T>), InvalidInput> {
+    pub fn a_from_bcγ<T: Scalar>(b: T, c: T, gamma: Angle<T>) -> Result<(T, Option<T>), InvalidInput> {
         let val = b * gamma.cos();
         let squared = b.powi(2) * gamma.cos().powi(2) - b.powi(2) + c.powi(2);
         return_solutions(val, squared)
     }
 
-    /// Calculates length `b` from lengths `a` and `c` + angle `alpha` (in radians)
+    /// Calculates length `b` from lengths `a` and `c` + angle `alpha`
     ///
     /// Always returns Ok() where  `a`, `c`, `alpha` > 0 and `beta' < PI
     #[inline]
-    pub fn b_from_acα<T: Scalar>(a: T, c: T, alpha: T) -> Result<(T, Option<T>), InvalidInput> {
+    pub fn b_from_acα<T: Scalar>(a: T, c: T, alpha: Angle<T>) -> Result<(T, Option<T>), InvalidInput> {
         let val = c * alpha.cos();
         let squared = a.powi(2) + (c.powi(2) * alpha.cos().powi(2)) - c.powi(2);
         return_solutions(val, squared)
     }
 
-    /// Calculates length `b` from lengths `a` and `c` + angle `beta` (in radians)
+    /// Calculates length `b` from lengths `a` and `c` + angle `beta`
     ///
     /// Always returns Ok() where  `a`, `c`, `beta` > 0 and `beta` < PI
     #[inline]
-    pub fn b_from_acβ<T: Scalar>(a: T, c: T, beta: T) -> Result<T, InvalidInput> {
+    pub fn b_from_acβ<T: Scalar>(a: T, c: T, beta: Angle<T>) -> Result<T, InvalidInput> {
         let b_squared = a.powi(2) + c.powi(2) - (T::i(2) * a * c * beta.cos());
         if b_squared > T::ZERO && b_squared.is_finite() {    // Return None instead of NaN if there are no solutions
             Ok(b_squared.sqrt())
@@ -1967,41 +2804,41 @@ pub mod law_of_cosines {
         }
     }
 
-    /// Calculates length `b` from lengths `a` and `c` + angle `gamma` (in radians)
+    /// Calculates length `b` from lengths `a` and `c` + angle `gamma`
     ///
     /// Always returns Ok() where  `a`, `c`, `gamma` > 0 and `beta' < PI
     #[inline]
-    pub fn b_from_acγ<T: Scalar>(a: T, c: T, gamma: T) -> Result<(T, Option<T>), InvalidInput> {
+    pub fn b_from_acγ<T: Scalar>(a: T, c: T, gamma: Angle<T>) -> Result<(T, Option<T>), InvalidInput> {
         let val = a * gamma.cos();
         let squared = c.powi(2) + (a.powi(2) * gamma.cos().powi(2)) - a.powi(2);
         return_solutions(val, squared)
     }
 
-    /// Calculates length `c` from lengths `a` and `b` + angle `alpha` (in radians)
+    /// Calculates length `c` from lengths `a` and `b` + angle `alpha`
     ///
     /// Always returns Ok() where  `a`, `b`, `alpha` > 0 and `beta' < PI
     #[inline]
-    pub fn c_from_abα<T: Scalar>(a: T, b: T, alpha: T) -> Result<(T, Option<T>), InvalidInput> {
+    pub fn c_from_abα<T: Scalar>(a: T, b: T, alpha: Angle<T>) -> Result<(T, Option<T>), InvalidInput> {
         let val = b * alpha.cos();
         let squared = a.powi(2) + (b.powi(2) * alpha.cos().powi(2)) - b.powi(2);
         return_solutions(val, squared)
     }
 
-    /// Calculates length `c` from lengths `a` and `b` + angle `beta` (in radians)
+    /// Calculates length `c` from lengths `a` and `b` + angle `beta`
     ///
     /// Always returns Ok() where  `a`, `b`, `beta` > 0 and `beta' < PI
     #[inline]
-    pub fn c_from_abβ<T: Scalar>(a: T, b: T, beta: T) -> Result<(T, Option<T>), InvalidInput> {
+    pub fn c_from_abβ<T: Scalar>(a: T, b: T, beta: Angle<T>) -> Result<(T, Option<T>), InvalidInput> {
         let val = a * beta.cos();
         let squared = b.powi(2) + (a.powi(2) * beta.cos().powi(2)) - a.powi(2);
         return_solutions(val, squared)
     }
 
-    /// Calculates length `c` from lengths `a` and `b` + angle `gamma` (in radians)
+    /// Calculates length `c` from lengths `a` and `b` + angle `gamma`
     ///
     /// Always returns Ok() where  `a`, `b`, `gamma` > 0 and `gamma` < PI
     #[inline]
-    pub fn c_from_abγ<T: Scalar>(a: T, b: T, gamma: T) -> Result<T, InvalidInput> {
+    pub fn c_from_abγ<T: Scalar>(a: T, b: T, gamma: Angle<T>) -> Result<T, InvalidInput> {
         let c_squared = a.powi(2) + b.powi(2) - (T::i(2) * a * b * gamma.cos());
         if c_squared > T::ZERO && c_squared.is_finite() {    // Return None instead of NaN if there are no solutions
             Ok(c_squared.sqrt())
@@ -2010,31 +2847,31 @@ pub mod law_of_cosines {
         }
     }
 
-    /// Calculates angle `alpha` (in radians) from lengths `a`, `b` and `c`
+    /// Calculates angle `alpha` from lengths `a`, `b` and `c`
     ///
     /// Always returns Ok() where  `a`, `b`, `c` > 0 and the sum of two lengths is greater than the third length
     #[inline]
-    pub fn alpha_from_abc<T: Scalar>(a: T, b: T, c: T) -> Result<T, InvalidInput> {
-        T::acos((-a.powi(2) + b.powi(2) + c.powi(2)) / (T::i(2) * b * c))
+    pub fn alpha_from_abc<T: Scalar>(a: T, b: T, c: T) -> Result<Angle<T>, InvalidInput> {
+        Angle::acos((-a.powi(2) + b.powi(2) + c.powi(2)) / (T::i(2) * b * c))
             .ok_or(InvalidInput)
     }
 
 
-    /// Calculates angle `beta` (in radians) from lengths `a`, `b` and `c`
+    /// Calculates angle `beta` from lengths `a`, `b` and `c`
     ///
     /// Always returns Ok() where  `a`, `b`, `c` > 0 and the sum of two lengths is greater than the third length
     #[inline]
-    pub fn beta_from_abc<T: Scalar>(a: T, b: T, c: T) -> Result<T, InvalidInput> {
-        T::acos((a.powi(2) - b.powi(2) + c.powi(2)) / (T::i(2) * a * c))
+    pub fn beta_from_abc<T: Scalar>(a: T, b: T, c: T) -> Result<Angle<T>, InvalidInput> {
+        Angle::acos((a.powi(2) - b.powi(2) + c.powi(2)) / (T::i(2) * a * c))
             .ok_or(InvalidInput)
     }
 
-    /// Calculates angle `gamma` (in radians) from lengths `a`, `b` and `c`
+    /// Calculates angle `gamma` from lengths `a`, `b` and `c`
     ///
     /// Always returns Ok() where  `a`, `b`, `c` > 0 and the sum of two lengths is greater than the third length
     #[inline]
-    pub fn gamma_from_abc<T: Scalar>(a: T, b: T, c: T) -> Result<T, InvalidInput> {
-        T::acos((a.powi(2) + b.powi(2) - c.powi(2)) / (T::i(2) * a * b))
+    pub fn gamma_from_abc<T: Scalar>(a: T, b: T, c: T) -> Result<Angle<T>, InvalidInput> {
+        Angle::acos((a.powi(2) + b.powi(2) - c.powi(2)) / (T::i(2) * a * b))
             .ok_or(InvalidInput)
     }
 }
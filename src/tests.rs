@@ -1,7 +1,15 @@
 mod shapes {
     mod triangle {
-        use crate::shapes::triangle::{AbstractTriangle, InvalidTriangleError};
+        #![allow(non_snake_case)]
+
+        use crate::angle::Angle;
+        use crate::basis::{ConvertBasis, YUpLeftHanded, ZUpRightHanded};
+        use crate::geometry2d::Point2D;
+        use crate::geometry3d::{Point3D, Vector3D};
+        use crate::geometry3d::shapes::{Plane3D, Ray3D, Segment3D};
+        use crate::shapes::triangle::{AbstractTriangle, Culling, EdgeInclusion, InvalidTriangleError, Triangle, Winding};
         use crate::utility::MaybeTwo;
+        use crate::vector::PointN;
 
         #[derive(Copy, Clone, Debug)]
         pub struct SolvedTriangle<T> {
@@ -76,70 +84,69 @@ mod shapes {
             }
         }
 
-        fn flt_eq(l: f64, r: f64) -> bool {
-            ((l - r).abs() / (l + r).abs()) < (f64::EPSILON * 5.0)  // x5 to make the tests work, errors accumulate but using fused-arithmetic makes the code less flexible w.r.t. generics
-        }
+        /// Relative tolerance; Errors accumulate, but using fused-arithmetic makes the code less flexible w.r.t. generics
+        const MAX_RELATIVE: f64 = f64::EPSILON * 10.0;
 
         fn assert_abstract_impl<R: AbstractTriangle<f64>>(solution: &SolvedTriangle<f64>, found: R) {
             let len_a_solution = found.length_a();
-            assert!(len_a_solution.any_is(|s| flt_eq(s, solution.a)), "Length-a solution {:?} does not contain {}", len_a_solution, solution.a);
+            assert!(len_a_solution.any_is(|s| approx_eq!(s, solution.a, max_relative = MAX_RELATIVE)), "Length-a solution {:?} does not contain {}", len_a_solution, solution.a);
 
             let len_b_solution = found.length_b();
-            assert!(len_b_solution.any_is(|s| flt_eq(s, solution.b)), "Length-b solution {:?} does not contain {}", len_b_solution, solution.b);
+            assert!(len_b_solution.any_is(|s| approx_eq!(s, solution.b, max_relative = MAX_RELATIVE)), "Length-b solution {:?} does not contain {}", len_b_solution, solution.b);
 
             let len_c_solution = found.length_c();
-            assert!(len_c_solution.any_is(|s| flt_eq(s, solution.c)), "Length-c solution {:?} does not contain {}", len_c_solution, solution.c);
+            assert!(len_c_solution.any_is(|s| approx_eq!(s, solution.c, max_relative = MAX_RELATIVE)), "Length-c solution {:?} does not contain {}", len_c_solution, solution.c);
 
             let angle_alpha_solutions = found.angle_alpha();
             let alpha_degree_expected = solution.alpha.to_degrees();
-            let degree_values = angle_alpha_solutions.map(f64::to_degrees);
-            assert!(angle_alpha_solutions.any_is(|s| flt_eq(s, solution.alpha)), "Angle-α solution {:?} ({:?}) does not contain {} ({}) ", angle_alpha_solutions, degree_values, solution.alpha, alpha_degree_expected);
+            let degree_values = angle_alpha_solutions.map(Angle::degrees);
+            assert!(angle_alpha_solutions.any_is(|s| approx_eq!(s.radians(), solution.alpha, max_relative = MAX_RELATIVE)), "Angle-α solution {:?} ({:?}) does not contain {} ({}) ", angle_alpha_solutions, degree_values, solution.alpha, alpha_degree_expected);
 
             let angle_beta_solutions = found.angle_beta();
             let beta_degree_expected = solution.beta.to_degrees();
-            let degree_values = angle_beta_solutions.map(f64::to_degrees);
-            assert!(angle_beta_solutions.any_is(|s| flt_eq(s, solution.beta)), "Angle-β solution {:?} ({:?}) does not contain {} ({}) ", angle_beta_solutions, degree_values, solution.beta, beta_degree_expected);
+            let degree_values = angle_beta_solutions.map(Angle::degrees);
+            assert!(angle_beta_solutions.any_is(|s| approx_eq!(s.radians(), solution.beta, max_relative = MAX_RELATIVE)), "Angle-β solution {:?} ({:?}) does not contain {} ({}) ", angle_beta_solutions, degree_values, solution.beta, beta_degree_expected);
 
             let angle_gamma_solutions = found.angle_gamma();
             let gamma_degree_expected = solution.gamma.to_degrees();
-            let degree_values = angle_gamma_solutions.map(f64::to_degrees);
-            assert!(angle_gamma_solutions.any_is(|s| flt_eq(s, solution.gamma)), "Angle-γ solution {:?} ({:?}) does not contain {} ({}) ", angle_gamma_solutions, degree_values, solution.gamma, gamma_degree_expected);
+            let degree_values = angle_gamma_solutions.map(Angle::degrees);
+            assert!(angle_gamma_solutions.any_is(|s| approx_eq!(s.radians(), solution.gamma, max_relative = MAX_RELATIVE)), "Angle-γ solution {:?} ({:?}) does not contain {} ({}) ", angle_gamma_solutions, degree_values, solution.gamma, gamma_degree_expected);
 
             let area_solutions = found.area();
-            assert!(area_solutions.any_is(|s| flt_eq(s, solution.area)), "Area solution {:?} does not contain {}", area_solutions, solution.area);
+            assert!(area_solutions.any_is(|s| approx_eq!(s, solution.area, max_relative = MAX_RELATIVE)), "Area solution {:?} does not contain {}", area_solutions, solution.area);
 
             let altitude_a_solutions = found.altitude_a();
-            assert!(altitude_a_solutions.any_is(|s| flt_eq(s, solution.altitude_a)), "Altitude A solution {:?} does not contain {}", altitude_a_solutions, solution.altitude_a);
+            assert!(altitude_a_solutions.any_is(|s| approx_eq!(s, solution.altitude_a, max_relative = MAX_RELATIVE)), "Altitude A solution {:?} does not contain {}", altitude_a_solutions, solution.altitude_a);
 
             let altitude_b_solutions = found.altitude_b();
-            assert!(altitude_b_solutions.any_is(|s| flt_eq(s, solution.altitude_b)), "Altitude B solution {:?} does not contain {}", altitude_b_solutions, solution.altitude_b);
+            assert!(altitude_b_solutions.any_is(|s| approx_eq!(s, solution.altitude_b, max_relative = MAX_RELATIVE)), "Altitude B solution {:?} does not contain {}", altitude_b_solutions, solution.altitude_b);
 
             let altitude_c_solutions = found.altitude_c();
-            assert!(altitude_c_solutions.any_is(|s| flt_eq(s, solution.altitude_c)), "Altitude C solution {:?} does not contain {}", altitude_c_solutions, solution.altitude_c);
+            assert!(altitude_c_solutions.any_is(|s| approx_eq!(s, solution.altitude_c, max_relative = MAX_RELATIVE)), "Altitude C solution {:?} does not contain {}", altitude_c_solutions, solution.altitude_c);
         }
 
         fn test_solution(solution: SolvedTriangle<f64>) -> Result<(), InvalidTriangleError> {
             assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, b: solution.b, c: solution.c }?);
 
-            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, b: solution.b, alpha: solution.alpha }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, c: solution.c, alpha: solution.alpha }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, c: solution.c, alpha: solution.alpha }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, b: solution.b, beta: solution.beta }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, c: solution.c, beta: solution.beta }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, c: solution.c, beta: solution.beta }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, b: solution.b, gamma: solution.gamma }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, c: solution.c, gamma: solution.gamma }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, c: solution.c, gamma: solution.gamma }?);
-
-            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, alpha: solution.alpha, beta: solution.beta }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, alpha: solution.alpha, beta: solution.beta }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ c: solution.c, alpha: solution.alpha, beta: solution.beta }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, alpha: solution.alpha, gamma: solution.gamma }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, alpha: solution.alpha, gamma: solution.gamma }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ c: solution.c, alpha: solution.alpha, gamma: solution.gamma }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, beta: solution.beta, gamma: solution.gamma }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, beta: solution.beta, gamma: solution.gamma }?);
-            assert_abstract_impl(&solution, abstract_triangle!{ c: solution.c, beta: solution.beta, gamma: solution.gamma }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, b: solution.b, alpha: Angle::from_radians(solution.alpha) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, c: solution.c, alpha: Angle::from_radians(solution.alpha) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, c: solution.c, alpha: Angle::from_radians(solution.alpha) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, b: solution.b, beta: Angle::from_radians(solution.beta) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, c: solution.c, beta: Angle::from_radians(solution.beta) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, c: solution.c, beta: Angle::from_radians(solution.beta) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, b: solution.b, gamma: Angle::from_radians(solution.gamma) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, c: solution.c, gamma: Angle::from_radians(solution.gamma) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, c: solution.c, gamma: Angle::from_radians(solution.gamma) }?);
+
+            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, alpha: Angle::from_radians(solution.alpha), beta: Angle::from_radians(solution.beta) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, alpha: Angle::from_radians(solution.alpha), beta: Angle::from_radians(solution.beta) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ c: solution.c, alpha: Angle::from_radians(solution.alpha), beta: Angle::from_radians(solution.beta) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, alpha: Angle::from_radians(solution.alpha), gamma: Angle::from_radians(solution.gamma) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, alpha: Angle::from_radians(solution.alpha), gamma: Angle::from_radians(solution.gamma) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ c: solution.c, alpha: Angle::from_radians(solution.alpha), gamma: Angle::from_radians(solution.gamma) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ a: solution.a, beta: Angle::from_radians(solution.beta), gamma: Angle::from_radians(solution.gamma) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ b: solution.b, beta: Angle::from_radians(solution.beta), gamma: Angle::from_radians(solution.gamma) }?);
+            assert_abstract_impl(&solution, abstract_triangle!{ c: solution.c, beta: Angle::from_radians(solution.beta), gamma: Angle::from_radians(solution.gamma) }?);
 
             Ok(())
         }
@@ -218,8 +225,8 @@ mod shapes {
         pub fn scalene_ambiguity() -> Result<(), InvalidTriangleError> {
             fn assert_eq(left: (f64, Option<f64>), right: (f64, Option<f64>)) {
                 let pass = match (left, right) {
-                    ((l1, Some(l2)), (r1, Some(r2))) => (flt_eq(l1, r1) && flt_eq(l2, r2)) || (flt_eq(l1, r2) && flt_eq(l2, r1)),
-                    ((l1, None), (r1, None)) => flt_eq(l1, r1),
+                    ((l1, Some(l2)), (r1, Some(r2))) => (approx_eq!(l1, r1, max_relative = MAX_RELATIVE) && approx_eq!(l2, r2, max_relative = MAX_RELATIVE)) || (approx_eq!(l1, r2, max_relative = MAX_RELATIVE) && approx_eq!(l2, r1, max_relative = MAX_RELATIVE)),
+                    ((l1, None), (r1, None)) => approx_eq!(l1, r1, max_relative = MAX_RELATIVE),
                     _ => false
                 };
 
@@ -240,60 +247,60 @@ mod shapes {
             };
 
             // Ambiguous values manually confirmed
-            let tri = abstract_triangle!{ a: solution.a, b: solution.b, alpha: solution.alpha }?;
+            let tri = abstract_triangle!{ a: solution.a, b: solution.b, alpha: Angle::from_radians(solution.alpha) }?;
             assert_eq(tri.length_c(), (solution.c, Some(1.1176470588235317)));
-            assert_eq(tri.angle_beta(), (solution.beta, Some(2.65163532733606)));
-            assert_eq(tri.angle_gamma(), (solution.gamma, Some(0.05261943450584479)));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, Some(2.65163532733606)));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, Some(0.05261943450584479)));
 
             assert_eq(tri.area(), (solution.area, Some(2.366782006920449)));
             assert_eq(tri.altitude_a(), (solution.altitude_a, Some(0.5259515570934331)));
             assert_eq(tri.altitude_b(), (solution.altitude_b, Some(0.47335640138408974)));
             assert_eq(tri.altitude_c().both(), (solution.altitude_c, None));    // Ambiguity does not change altitude C
 
-            let tri = abstract_triangle!{ a: solution.a, c: solution.c, alpha: solution.alpha }?;
+            let tri = abstract_triangle!{ a: solution.a, c: solution.c, alpha: Angle::from_radians(solution.alpha) }?;
             assert_eq(tri.length_b(), (solution.b, Some(20.8)));
-            assert_eq(tri.angle_beta(), (solution.beta, Some(1.7769595438402968)));
-            assert_eq(tri.angle_gamma(), (solution.gamma, Some(0.9272952180016127)));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, Some(1.7769595438402968)));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, Some(0.9272952180016127)));
 
             assert_eq(tri.area(), (solution.area, Some(74.88)));
             assert_eq(tri.altitude_a(), (solution.altitude_a, Some(16.64)));
             assert_eq(tri.altitude_b().both(), (solution.altitude_b, None));   // Ambiguity does not change altitude B
             assert_eq(tri.altitude_c(), (solution.altitude_c, Some(8.809411764705882)));
 
-            let tri = abstract_triangle!{ a: solution.a, b: solution.b, beta: solution.beta }?;
+            let tri = abstract_triangle!{ a: solution.a, b: solution.b, beta: Angle::from_radians(solution.beta) }?;
             assert_eq(tri.length_c(), (solution.c, None));
-            assert_eq(tri.angle_alpha(), (solution.alpha, None));
-            assert_eq(tri.angle_gamma(), (solution.gamma, None));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, None));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, None));
 
             assert_eq(tri.area(), (solution.area, None));
             assert_eq(tri.altitude_a(), (solution.altitude_a, None));
             assert_eq(tri.altitude_b(), (solution.altitude_b, None));
             assert_eq(tri.altitude_c().both(), (solution.altitude_c, None));
 
-            let tri = abstract_triangle!{ b: solution.b, c: solution.c, beta: solution.beta }?;
+            let tri = abstract_triangle!{ b: solution.b, c: solution.c, beta: Angle::from_radians(solution.beta) }?;
             assert_eq(tri.length_a(), (solution.a, Some(21.0)));
-            assert_eq(tri.angle_alpha(), (solution.alpha, Some(1.7243401093344528)));
-            assert_eq(tri.angle_gamma(), (solution.gamma, Some(0.9272952180016123)));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, Some(1.7243401093344528)));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, Some(0.9272952180016123)));
 
             assert_eq(tri.area(), (solution.area, Some(84.0)));
             assert_eq(tri.altitude_a().both(), (solution.altitude_a, None));   // Ambiguity does not change altitude A
             assert_eq(tri.altitude_b(), (solution.altitude_b, Some(16.8)));
             assert_eq(tri.altitude_c(), (solution.altitude_c, Some(9.882352941176471)));
 
-            let tri = abstract_triangle!{ a: solution.a, c: solution.c, gamma: solution.gamma }?;
+            let tri = abstract_triangle!{ a: solution.a, c: solution.c, gamma: Angle::from_radians(solution.gamma) }?;
             assert_eq(tri.length_b(), (solution.b, None));
-            assert_eq(tri.angle_alpha(), (solution.alpha, None));
-            assert_eq(tri.angle_beta(), (solution.beta, None));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, None));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, None));
 
             assert_eq(tri.area(), (solution.area, None));
             assert_eq(tri.altitude_a(), (solution.altitude_a, None));
             assert_eq(tri.altitude_b().both(), (solution.altitude_b, None));
             assert_eq(tri.altitude_c(), (solution.altitude_c, None));
 
-            let tri = abstract_triangle!{ b: solution.b, c: solution.c, gamma: solution.gamma }?;
+            let tri = abstract_triangle!{ b: solution.b, c: solution.c, gamma: Angle::from_radians(solution.gamma) }?;
             assert_eq(tri.length_a(), (solution.a, None));
-            assert_eq(tri.angle_alpha(), (solution.alpha, None));
-            assert_eq(tri.angle_beta(), (solution.beta, None));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, None));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, None));
 
             assert_eq(tri.area(), (solution.area, None));
             assert_eq(tri.altitude_a().both(), (solution.altitude_a, None));
@@ -302,60 +309,60 @@ mod shapes {
 
             // Rotated once
             let solution = solution.rotate_left();
-            let tri = abstract_triangle!{ a: solution.a, b: solution.b, alpha: solution.alpha }?;
+            let tri = abstract_triangle!{ a: solution.a, b: solution.b, alpha: Angle::from_radians(solution.alpha) }?;
             assert_eq(tri.length_c(), (solution.c, None));
-            assert_eq(tri.angle_beta(), (solution.beta, None));
-            assert_eq(tri.angle_gamma(), (solution.gamma, None));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, None));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, None));
 
             assert_eq(tri.area(), (solution.area, None));
             assert_eq(tri.altitude_a(), (solution.altitude_a, None));
             assert_eq(tri.altitude_b(), (solution.altitude_b, None));
             assert_eq(tri.altitude_c().both(), (solution.altitude_c, None));
 
-            let tri = abstract_triangle!{ a: solution.a, c: solution.c, alpha: solution.alpha }?;
+            let tri = abstract_triangle!{ a: solution.a, c: solution.c, alpha: Angle::from_radians(solution.alpha) }?;
             assert_eq(tri.length_b(), (solution.b, None));
-            assert_eq(tri.angle_beta(), (solution.beta, None));
-            assert_eq(tri.angle_gamma(), (solution.gamma, None));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, None));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, None));
 
             assert_eq(tri.area(), (solution.area, None));
             assert_eq(tri.altitude_a(), (solution.altitude_a, None));
             assert_eq(tri.altitude_b().both(), (solution.altitude_b, None));
             assert_eq(tri.altitude_c(), (solution.altitude_c, None));
 
-            let tri = abstract_triangle!{ a: solution.a, b: solution.b, beta: solution.beta }?;
+            let tri = abstract_triangle!{ a: solution.a, b: solution.b, beta: Angle::from_radians(solution.beta) }?;
             assert_eq(tri.length_c(), (solution.c, Some(20.8)));
-            assert_eq(tri.angle_alpha(), (solution.alpha, Some(0.9272952180016127)));
-            assert_eq(tri.angle_gamma(), (solution.gamma, Some(1.7769595438402968)));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, Some(0.9272952180016127)));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, Some(1.7769595438402968)));
 
             assert_eq(tri.area(), (solution.area, Some(74.88)));
             assert_eq(tri.altitude_a(), (solution.altitude_a, Some(8.809411764705882)));
             assert_eq(tri.altitude_b(), (solution.altitude_b, Some(16.64)));
             assert_eq(tri.altitude_c().both(), (solution.altitude_c, None));   // Ambiguity does not change altitude C
 
-            let tri = abstract_triangle!{ b: solution.b, c: solution.c, beta: solution.beta }?;
+            let tri = abstract_triangle!{ b: solution.b, c: solution.c, beta: Angle::from_radians(solution.beta) }?;
             assert_eq(tri.length_a(), (solution.a, Some(1.1176470588235317)));
-            assert_eq(tri.angle_alpha(), (solution.alpha, Some(0.05261943450584479)));
-            assert_eq(tri.angle_gamma(), (solution.gamma, Some(2.65163532733606)));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, Some(0.05261943450584479)));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, Some(2.65163532733606)));
 
             assert_eq(tri.area(), (solution.area, Some(2.366782006920449)));
             assert_eq(tri.altitude_a().both(), (solution.altitude_a, None));    // Ambiguity does not change altitude A
             assert_eq(tri.altitude_b(), (solution.altitude_b, Some(0.5259515570934331)));
             assert_eq(tri.altitude_c(), (solution.altitude_c, Some(0.47335640138408974)));
 
-            let tri = abstract_triangle!{ a: solution.a, c: solution.c, gamma: solution.gamma }?;
+            let tri = abstract_triangle!{ a: solution.a, c: solution.c, gamma: Angle::from_radians(solution.gamma) }?;
             assert_eq(tri.length_b(), (solution.b, Some(21.0)));
-            assert_eq(tri.angle_alpha(), (solution.alpha, Some(0.9272952180016123)));
-            assert_eq(tri.angle_beta(), (solution.beta, Some(1.7243401093344528)));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, Some(0.9272952180016123)));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, Some(1.7243401093344528)));
 
             assert_eq(tri.area(), (solution.area, Some(84.0)));
             assert_eq(tri.altitude_a(), (solution.altitude_a, Some(9.882352941176471)));
             assert_eq(tri.altitude_b().both(), (solution.altitude_b, None));   // Ambiguity does not change altitude B
             assert_eq(tri.altitude_c(), (solution.altitude_c, Some(16.8)));
 
-            let tri = abstract_triangle!{ b: solution.b, c: solution.c, gamma: solution.gamma }?;
+            let tri = abstract_triangle!{ b: solution.b, c: solution.c, gamma: Angle::from_radians(solution.gamma) }?;
             assert_eq(tri.length_a(), (solution.a, None));
-            assert_eq(tri.angle_alpha(), (solution.alpha, None));
-            assert_eq(tri.angle_beta(), (solution.beta, None));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, None));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, None));
 
             assert_eq(tri.area(), (solution.area, None));
             assert_eq(tri.altitude_a().both(), (solution.altitude_a, None));
@@ -364,60 +371,60 @@ mod shapes {
 
             // Rotated twice (`solution` already being the rotated version from the previous block)
             let solution = solution.rotate_left();
-            let tri = abstract_triangle!{ a: solution.a, b: solution.b, alpha: solution.alpha }?;
+            let tri = abstract_triangle!{ a: solution.a, b: solution.b, alpha: Angle::from_radians(solution.alpha) }?;
             assert_eq(tri.length_c(), (solution.c, Some(21.0)));
-            assert_eq(tri.angle_beta(), (solution.beta, Some(0.9272952180016123)));
-            assert_eq(tri.angle_gamma(), (solution.gamma, Some(1.7243401093344528)));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, Some(0.9272952180016123)));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, Some(1.7243401093344528)));
 
             assert_eq(tri.area(), (solution.area, Some(84.0)));
             assert_eq(tri.altitude_a(), (solution.altitude_a, Some(16.8)));
             assert_eq(tri.altitude_b(), (solution.altitude_b, Some(9.882352941176471)));
             assert_eq(tri.altitude_c().both(), (solution.altitude_c, None));   // Ambiguity does not change altitude C
 
-            let tri = abstract_triangle!{ a: solution.a, c: solution.c, alpha: solution.alpha }?;
+            let tri = abstract_triangle!{ a: solution.a, c: solution.c, alpha: Angle::from_radians(solution.alpha) }?;
             assert_eq(tri.length_b(), (solution.b, None));
-            assert_eq(tri.angle_beta(), (solution.beta, None));
-            assert_eq(tri.angle_gamma(), (solution.gamma, None));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, None));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, None));
 
             assert_eq(tri.area(), (solution.area, None));
             assert_eq(tri.altitude_a(), (solution.altitude_a, None));
             assert_eq(tri.altitude_b().both(), (solution.altitude_b, None));
             assert_eq(tri.altitude_c(), (solution.altitude_c, None));
 
-            let tri = abstract_triangle!{ a: solution.a, b: solution.b, beta: solution.beta }?;
+            let tri = abstract_triangle!{ a: solution.a, b: solution.b, beta: Angle::from_radians(solution.beta) }?;
             assert_eq(tri.length_c(), (solution.c, None));
-            assert_eq(tri.angle_alpha(), (solution.alpha, None));
-            assert_eq(tri.angle_gamma(), (solution.gamma, None));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, None));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, None));
 
             assert_eq(tri.area(), (solution.area, None));
             assert_eq(tri.altitude_a(), (solution.altitude_a, None));
             assert_eq(tri.altitude_b(), (solution.altitude_b, None));
             assert_eq(tri.altitude_c().both(), (solution.altitude_c, None));
 
-            let tri = abstract_triangle!{ b: solution.b, c: solution.c, beta: solution.beta }?;
+            let tri = abstract_triangle!{ b: solution.b, c: solution.c, beta: Angle::from_radians(solution.beta) }?;
             assert_eq(tri.length_a(), (solution.a, None));
-            assert_eq(tri.angle_alpha(), (solution.alpha, None));
-            assert_eq(tri.angle_gamma(), (solution.gamma, None));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, None));
+            assert_eq(tri.angle_gamma().map(Angle::radians), (solution.gamma, None));
 
             assert_eq(tri.area(), (solution.area, None));
             assert_eq(tri.altitude_a().both(), (solution.altitude_a, None));
             assert_eq(tri.altitude_b(), (solution.altitude_b, None));
             assert_eq(tri.altitude_c(), (solution.altitude_c, None));
 
-            let tri = abstract_triangle!{ a: solution.a, c: solution.c, gamma: solution.gamma }?;
+            let tri = abstract_triangle!{ a: solution.a, c: solution.c, gamma: Angle::from_radians(solution.gamma) }?;
             assert_eq(tri.length_b(), (solution.b, Some(1.1176470588235317)));
-            assert_eq(tri.angle_alpha(), (solution.alpha, Some(2.65163532733606)));
-            assert_eq(tri.angle_beta(), (solution.beta, Some(0.05261943450584479)));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, Some(2.65163532733606)));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, Some(0.05261943450584479)));
 
             assert_eq(tri.area(), (solution.area, Some(2.366782006920449)));
             assert_eq(tri.altitude_a(), (solution.altitude_a, Some(0.47335640138408974)));
             assert_eq(tri.altitude_b().both(), (solution.altitude_b, None));    // Ambiguity does not change altitude B
             assert_eq(tri.altitude_c(), (solution.altitude_c, Some(0.5259515570934331)));
 
-            let tri = abstract_triangle!{ b: solution.b, c: solution.c, gamma: solution.gamma }?;
+            let tri = abstract_triangle!{ b: solution.b, c: solution.c, gamma: Angle::from_radians(solution.gamma) }?;
             assert_eq(tri.length_a(), (solution.a, Some(20.8)));
-            assert_eq(tri.angle_alpha(), (solution.alpha, Some(1.7769595438402968)));
-            assert_eq(tri.angle_beta(), (solution.beta, Some(0.9272952180016127)));
+            assert_eq(tri.angle_alpha().map(Angle::radians), (solution.alpha, Some(1.7769595438402968)));
+            assert_eq(tri.angle_beta().map(Angle::radians), (solution.beta, Some(0.9272952180016127)));
 
             assert_eq(tri.area(), (solution.area, Some(74.88)));
             assert_eq(tri.altitude_a().both(), (solution.altitude_a, None));
@@ -426,5 +433,2088 @@ mod shapes {
 
             Ok(())
         }
+
+        #[test]
+        pub fn from_points() -> Result<(), InvalidTriangleError> {
+            let solution = SolvedTriangle {
+                a: 9.0,
+                b: 10.0,
+                c: 17.0,
+                alpha: f64::acos(8.0/17.0) - f64::acos(8.0/10.0),
+                beta: f64::acos(15.0/17.0),
+                gamma: std::f64::consts::PI - f64::acos(6.0/10.0),
+                area: 36.0,
+                altitude_a: 8.0,
+                altitude_b: 7.2,
+                altitude_c: (2.0*36.0)/ 17.0,
+            };
+            let [A, B, C] = [[0.0, 0.0], [17.0, 0.0], [154.0 / 17.0, 72.0 / 17.0]].map(Point2D::<f64, ()>::new);
+            assert_abstract_impl(&solution, Triangle::new(A, B, C)?);
+            assert_abstract_impl(&solution.rotate_left(), Triangle::new(C, A, B)?);
+            Ok(())
+        }
+
+        #[test]
+        pub fn derived_properties() -> Result<(), InvalidTriangleError> {
+            // See docs/scalene.png; a = 9, b = 10, c = 17, with an area of 36
+            let tri = abstract_triangle!{ a: 9.0, b: 10.0, c: 17.0 }?;
+            assert_eq!(tri.sides(), [9.0, 10.0, 17.0]);
+            assert_eq!(tri.perimeter(), 36.0);
+            assert_eq!(tri.semiperimeter(), 18.0);
+            assert_approx_eq!(tri.inradius(), 2.0);
+            assert_approx_eq!(tri.circumradius(), 10.625);
+            assert_approx_eq!([tri.exradius_a(), tri.exradius_b(), tri.exradius_c()], [4.0, 4.5, 36.0]);
+            assert_approx_eq!([tri.median_a(), tri.median_b(), tri.median_c()], [697.0f64.sqrt() / 2.0, 640.0f64.sqrt() / 2.0, 73.0f64.sqrt() / 2.0]);
+            assert_approx_eq!([tri.angle_bisector_a(), tri.angle_bisector_b(), tri.angle_bisector_c()], [110160.0f64.sqrt() / 27.0, 88128.0f64.sqrt() / 26.0, 6480.0f64.sqrt() / 19.0]);
+
+            // The angle bisector splits the triangle into two with the same altitude from A
+            let bisector = tri.angle_bisector_a();
+            let alpha = tri.angle_alpha();
+            assert_approx_eq!(0.5 * bisector * (10.0 + 17.0) * (alpha * 0.5).sin(), tri.area());
+
+            // SSA triangles have two candidates for all properties derived from the sides
+            let tri = abstract_triangle!{ a: 9.0, b: 10.0, alpha: Angle::from_radians(f64::acos(8.0/17.0) - f64::acos(8.0/10.0)) }?;
+            let (c, other_c) = tri.length_c();
+            let (other_c, other_area) = (other_c.unwrap(), tri.area().1.unwrap());
+            assert_eq!(tri.sides(), ([9.0, 10.0, c], Some([9.0, 10.0, other_c])));
+            assert_eq!(tri.perimeter(), (19.0 + c, Some(19.0 + other_c)));
+            let (inradius, other_inradius) = tri.inradius();
+            assert_approx_eq!(inradius, 2.0, max_relative = 1e-14);
+            assert_approx_eq!(other_inradius.unwrap(), 2.0 * other_area / (19.0 + other_c), max_relative = 1e-14);
+            let (circumradius, other_circumradius) = tri.circumradius();
+            assert_approx_eq!(circumradius, 10.625, max_relative = 1e-14);
+            assert_approx_eq!(other_circumradius.unwrap(), 9.0 * 10.0 * other_c / (4.0 * other_area), max_relative = 1e-14);
+            assert!(tri.median_a().1.is_some());
+            Ok(())
+        }
+
+        #[test]
+        pub fn centers() -> Result<(), InvalidTriangleError> {
+            // Obtuse at C, such that the circumcenter and orthocenter lie outside
+            let [A, B, C] = [[0.0, 0.0], [17.0, 0.0], [154.0 / 17.0, 72.0 / 17.0]].map(Point2D::<f64, ()>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            assert_approx_eq!(triangle.centroid(), Point2D::new([(17.0 + 154.0 / 17.0) / 3.0, 24.0 / 17.0]));
+            assert_approx_eq!(triangle.incenter(), Point2D::new([9.0, 2.0]));
+            assert_approx_eq!(triangle.circumcenter(), Point2D::new([8.5, -6.375]));
+            assert_approx_eq!(triangle.orthocenter(), Point2D::new([154.0 / 17.0, 72.0 / 17.0 + 12.75]), max_relative = 1e-14);
+            assert_approx_eq!(triangle.nine_point_center(), Point2D::new([(8.5 + 154.0 / 17.0) / 2.0, (72.0 / 17.0 + 6.375) / 2.0]), max_relative = 1e-14);
+            assert_approx_eq!(triangle.inradius(), 2.0);
+            assert_approx_eq!(triangle.circumradius(), (triangle.circumcenter() - C).magnitude());
+
+            // The centroid lies on the Euler line, a third of the way from the circumcenter to the orthocenter
+            let (origin, direction) = triangle.euler_line().unwrap();
+            assert_approx_eq!(origin, triangle.circumcenter());
+            assert_approx_eq!(origin + direction * (triangle.orthocenter() - origin).magnitude(), triangle.orthocenter(), max_relative = 1e-14);
+            assert_approx_eq!(origin + direction * (triangle.centroid() - origin).magnitude(), triangle.centroid(), max_relative = 1e-14);
+            assert_approx_eq!((triangle.centroid() - origin).magnitude() * 3.0, (triangle.orthocenter() - origin).magnitude(), max_relative = 1e-14);
+
+            let equilateral = Triangle::new(Point2D::<f64, ()>::new([0.0, 0.0]), Point2D::new([2.0, 0.0]), Point2D::new([1.0, 3.0f64.sqrt()]))?;
+            assert!(equilateral.euler_line().is_none());
+
+            // Centers are defined in any dimension; The circumcenter lies in the plane of the triangle, equidistant from the vertices
+            let [A, B, C] = [[1.0, -2.0, 0.5], [3.0, 1.0, -1.0], [-0.5, 2.0, 4.0]].map(Point3D::<f64, ()>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            let circumcenter = triangle.circumcenter();
+            let normal = (B - A).cross_product(C - A);
+            assert_approx_eq!((circumcenter - A).dot(normal), 0.0, epsilon = 1e-12);
+            assert_approx_eq!([(circumcenter - B).magnitude(), (circumcenter - C).magnitude()], [(circumcenter - A).magnitude(); 2]);
+            assert_approx_eq!((circumcenter - A).magnitude(), triangle.circumradius());
+            let orthocenter = triangle.orthocenter();
+            assert_approx_eq!((orthocenter - A).dot(C - B), 0.0, epsilon = 1e-12);
+            assert_approx_eq!((orthocenter - B).dot(C - A), 0.0, epsilon = 1e-12);
+            let incenter = triangle.incenter();
+            let distance_to_side = |P: Point3D<f64, ()>, Q: Point3D<f64, ()>| (incenter - P).cross_product(Q - P).magnitude() / (Q - P).magnitude();
+            assert_approx_eq!([distance_to_side(A, B), distance_to_side(B, C), distance_to_side(C, A)], [triangle.inradius(); 3]);
+            Ok(())
+        }
+
+        #[test]
+        pub fn barycentric() -> Result<(), InvalidTriangleError> {
+            let [A, B, C] = [[1.0, 1.0], [5.0, 2.0], [2.0, 6.0]].map(Point2D::<f64, ()>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            assert_approx_eq!(triangle.barycentric(A), [1.0, 0.0, 0.0], epsilon = 1e-15);
+            assert_approx_eq!(triangle.barycentric(C), [0.0, 0.0, 1.0], epsilon = 1e-15);
+            assert_approx_eq!(triangle.barycentric(triangle.centroid()), [1.0 / 3.0; 3]);
+            for coordinates in [[0.2, 0.3, 0.5], [1.5, -0.25, -0.25], [-1.0, 4.0, -2.0]] {
+                let point = triangle.from_barycentric(coordinates);
+                assert_approx_eq!(triangle.barycentric(point), coordinates, epsilon = 1e-14);
+            }
+
+            assert!(triangle.contains(triangle.centroid(), EdgeInclusion::Exclusive));
+            assert!(triangle.contains(B, EdgeInclusion::Inclusive));
+            assert!(!triangle.contains(B, EdgeInclusion::Exclusive));
+            let midpoint = Point2D::new([3.0, 1.5]);
+            assert!(triangle.contains(midpoint, EdgeInclusion::Inclusive));
+            assert!(!triangle.contains(midpoint, EdgeInclusion::Exclusive));
+            assert!(!triangle.contains(Point2D::new([3.0, 1.4]), EdgeInclusion::Inclusive));
+
+            // In 3D, barycentric coordinates are those of the projection onto the plane of the triangle, but contained points must lie in the plane
+            let [A, B, C] = [[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [0.0, 4.0, 0.0]].map(Point3D::<f64, ()>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            assert_approx_eq!(triangle.barycentric(Point3D::new([1.0, 2.0, 7.0])), [0.25, 0.25, 0.5]);
+            assert!(triangle.contains(Point3D::new([1.0, 1.0, 0.0]), EdgeInclusion::Exclusive));
+            assert!(!triangle.contains(Point3D::new([1.0, 1.0, -3.0]), EdgeInclusion::Inclusive));
+            assert!(!triangle.contains(Point3D::new([1.0, 1.0, 1e-9]), EdgeInclusion::Inclusive));
+            // Tilted and far from the origin, where points in the plane carry rounding error
+            let [A, B, C] = [[1e6, 2e6, -3e6], [1e6 + 3.0, 2e6 + 1.0, -3e6 + 2.0], [1e6 - 1.0, 2e6 + 4.0, -3e6 + 1.0]].map(Point3D::<f64, ()>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            assert!(triangle.contains(triangle.from_barycentric([0.2, 0.3, 0.5]), EdgeInclusion::Exclusive));
+            assert!(!triangle.contains(triangle.from_barycentric([0.2, 0.3, 0.5]) + triangle.normal().with_length(1e-3), EdgeInclusion::Inclusive));
+            Ok(())
+        }
+
+        #[test]
+        pub fn closest_point() -> Result<(), InvalidTriangleError> {
+            let [A, B, C] = [[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [0.0, 4.0, 0.0]].map(Point3D::<f64, ()>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            let cases = [
+                ([-1.0, -1.0, 2.0], [0.0, 0.0, 0.0]),   // Vertex regions
+                ([6.0, -1.0, 0.0], [4.0, 0.0, 0.0]),
+                ([-1.0, 5.0, -1.0], [0.0, 4.0, 0.0]),
+                ([2.0, -3.0, 4.0], [2.0, 0.0, 0.0]),    // Edge regions
+                ([-2.0, 1.0, 0.0], [0.0, 1.0, 0.0]),
+                ([3.0, 3.0, 1.0], [2.0, 2.0, 0.0]),
+                ([1.0, 1.0, 5.0], [1.0, 1.0, 0.0]),     // Face region
+            ];
+            for (point, expected) in cases {
+                let point = Point3D::new(point);
+                let closest = triangle.closest_point(point);
+                assert_approx_eq!(closest, Point3D::new(expected), epsilon = 1e-15);
+                assert_approx_eq!(triangle.distance_to(point), (point - closest).magnitude());
+                // The closest point is on the triangle, and no sampled point on the triangle is closer
+                assert!(triangle.contains(closest, EdgeInclusion::Inclusive));
+                for u in 0..=10 {
+                    for v in 0..=(10 - u) {
+                        let sample = triangle.from_barycentric([0.0, u as f64 / 10.0, v as f64 / 10.0]);
+                        assert!((point - sample).magnitude() >= triangle.distance_to(point) - 1e-12);
+                    }
+                }
+            }
+            assert_eq!(triangle.distance_to(Point3D::new([1.0, 1.0, 0.0])), 0.0);
+
+            // Any dimension
+            let [A, B, C] = [[0.0, 0.0, 0.0, 0.0], [2.0, 0.0, 0.0, 0.0], [0.0, 2.0, 0.0, 0.0]].map(PointN::<f64, 4, ()>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            assert_approx_eq!(triangle.closest_point(PointN::new([3.0, 3.0, 1.0, -1.0])), PointN::new([1.0, 1.0, 0.0, 0.0]));
+            assert_approx_eq!(triangle.distance_to(PointN::new([0.5, 0.5, 3.0, 4.0])), 5.0);
+            Ok(())
+        }
+
+        #[test]
+        pub fn ray_intersection() -> Result<(), InvalidTriangleError> {
+            // Counter-clockwise when viewed from +Z, so the front face points towards +Z
+            let [A, B, C] = [[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [0.0, 4.0, 0.0]].map(Point3D::<f64, ()>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            let down = Ray3D { origin: Point3D::new([1.0, 2.0, 5.0]), direction: Vector3D::new([0.0, 0.0, -2.0]) };
+            let hit = triangle.ray_intersection(down, Culling::BackFace).unwrap();
+            assert_approx_eq!(hit.t, 2.5);
+            assert_approx_eq!(hit.barycentric, [0.25, 0.25, 0.5]);
+            assert_approx_eq!(down.at(hit.t), triangle.from_barycentric(hit.barycentric));
+
+            let up = Ray3D { origin: Point3D::new([1.0, 2.0, -5.0]), direction: Vector3D::new([0.0, 0.0, 1.0]) };
+            assert!(triangle.ray_intersection(up, Culling::BackFace).is_none());
+            assert_approx_eq!(triangle.ray_intersection(up, Culling::DoubleSided).unwrap().t, 5.0);
+            // Behind the origin, outside the triangle, on an edge, and parallel
+            assert!(triangle.ray_intersection(Ray3D { direction: -down.direction, ..down }, Culling::DoubleSided).is_none());
+            assert!(triangle.ray_intersection(Ray3D { origin: Point3D::new([3.0, 3.0, 5.0]), ..down }, Culling::DoubleSided).is_none());
+            assert!(triangle.ray_intersection(Ray3D { origin: Point3D::new([2.0, 0.0, 5.0]), ..down }, Culling::DoubleSided).is_some());
+            assert!(triangle.ray_intersection(Ray3D { origin: Point3D::new([-1.0, 1.0, 0.0]), direction: Vector3D::new([1.0, 0.0, 0.0]) }, Culling::DoubleSided).is_none());
+
+            // Front faces follow the right-hand rule of the basis
+            let [A, B, C] = [[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [0.0, 4.0, 0.0]].map(Point3D::<f64, YUpLeftHanded>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            let up = Ray3D { origin: Point3D::new([1.0, 2.0, -5.0]), direction: Vector3D::new([0.0, 0.0, 1.0]) };
+            let hit = triangle.ray_intersection(up, Culling::BackFace).unwrap();
+            assert_approx_eq!(hit.barycentric, [0.25, 0.25, 0.5]);
+
+            let segment = Segment3D { start: Point3D::new([1.0, 1.0, -1.0]), end: Point3D::new([1.0, 1.0, 3.0]) };
+            assert_approx_eq!(triangle.segment_intersection(segment).unwrap().t, 0.25);
+            assert!(triangle.segment_intersection(Segment3D { end: Point3D::new([1.0, 1.0, -0.5]), ..segment }).is_none());
+            Ok(())
+        }
+
+        #[test]
+        pub fn triangle_overlap() -> Result<(), InvalidTriangleError> {
+            let triangle = |points: [[f64; 3]; 3]| Triangle::new(Point3D::<f64, ()>::new(points[0]), Point3D::new(points[1]), Point3D::new(points[2]));
+            let base = triangle([[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [0.0, 4.0, 0.0]])?;
+
+            let piercing = triangle([[1.0, 1.0, -1.0], [1.0, 1.0, 1.0], [5.0, 5.0, 0.0]])?;
+            assert!(base.overlaps(piercing) && piercing.overlaps(base));
+            let above = triangle([[1.0, 1.0, 0.5], [1.0, 2.0, 1.0], [2.0, 1.0, 1.0]])?;
+            assert!(!base.overlaps(above) && !above.overlaps(base));
+            // Crosses the plane of `base`, but outside of it
+            let beside = triangle([[3.0, 3.0, -1.0], [3.0, 3.0, 1.0], [6.0, 6.0, 0.0]])?;
+            assert!(!base.overlaps(beside) && !beside.overlaps(base));
+            let touching = triangle([[4.0, 0.0, 0.0], [5.0, 0.0, 1.0], [5.0, 1.0, -1.0]])?;
+            assert!(base.overlaps(touching));
+
+            // Coplanar
+            assert!(base.overlaps(triangle([[1.0, 1.0, 0.0], [5.0, 1.0, 0.0], [1.0, 5.0, 0.0]])?));
+            assert!(base.overlaps(triangle([[0.5, 0.5, 0.0], [1.0, 0.5, 0.0], [0.5, 1.0, 0.0]])?));
+            assert!(!base.overlaps(triangle([[3.0, 3.0, 0.0], [5.0, 3.0, 0.0], [3.0, 5.0, 0.0]])?));
+            assert!(base.overlaps(triangle([[4.0, 0.0, 0.0], [6.0, 0.0, 0.0], [5.0, 1.0, 0.0]])?));
+            assert!(!base.overlaps(triangle([[5.0, 0.0, 0.0], [7.0, 0.0, 0.0], [6.0, 1.0, 0.0]])?));
+            Ok(())
+        }
+
+        #[test]
+        pub fn plane_clipping() -> Result<(), InvalidTriangleError> {
+            let [A, B, C] = [[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [0.0, 4.0, 0.0]].map(Point3D::<f64, ()>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            // Keeps x ≥ 1
+            let plane = Plane3D::new(Vector3D::new([2.0, 0.0, 0.0]), 2.0).unwrap();
+            let clipped = triangle.clip(plane);
+            assert_eq!(clipped.vertices(), [Point3D::new([1.0, 0.0, 0.0]), B, Point3D::new([1.0, 3.0, 0.0])]);
+            let segment = triangle.plane_intersection(plane).unwrap();
+            assert_approx_eq!(segment, Segment3D { start: Point3D::new([1.0, 0.0, 0.0]), end: Point3D::new([1.0, 3.0, 0.0]) });
+
+            // Keeps x ≤ 1, a quadrilateral
+            let flipped = Plane3D::new(Vector3D::new([-1.0, 0.0, 0.0]), -1.0).unwrap();
+            assert_eq!(triangle.clip(flipped).vertices(), [A, Point3D::new([1.0, 0.0, 0.0]), Point3D::new([1.0, 3.0, 0.0]), C]);
+
+            let behind = Plane3D::new(Vector3D::new([1.0, 0.0, 0.0]), 5.0).unwrap();
+            assert!(triangle.clip(behind).vertices().is_empty());
+            assert!(triangle.plane_intersection(behind).is_none());
+            let touching = Plane3D::new(Vector3D::new([1.0, 0.0, 0.0]), 4.0).unwrap();
+            assert!(triangle.clip(touching).vertices().is_empty());
+            assert_eq!(triangle.plane_intersection(touching), Some(Segment3D { start: B, end: B }));
+            let containing = Plane3D::from_points(A, B, C).unwrap();
+            assert_eq!(triangle.clip(containing).vertices(), [A, B, C]);
+            assert!(triangle.plane_intersection(containing).is_none());
+            Ok(())
+        }
+
+        #[test]
+        pub fn normal_and_winding() -> Result<(), InvalidTriangleError> {
+            let [A, B, C] = [[1.0, 0.0, 0.0], [3.0, 0.0, 0.0], [1.0, 2.0, 0.0]].map(Point3D::<f64, ZUpRightHanded>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            assert_eq!(triangle.normal(), [0.0, 0.0, 4.0]);
+            assert_approx_eq!(triangle.normal().magnitude(), 2.0 * triangle.area());
+            assert_eq!(triangle.unit_normal(), [0.0, 0.0, 1.0]);
+            let plane = triangle.plane().unwrap();
+            assert_eq!(plane.normal(), [0.0, 0.0, 1.0]);
+            assert!([A, B, C, triangle.centroid()].into_iter().all(|point| plane.signed_distance(point).abs() < 1e-15));
+
+            assert_eq!(triangle.winding(Point3D::new([0.0, 0.0, 1.0])), Winding::CounterClockwise);
+            assert_eq!(triangle.winding(Point3D::new([5.0, 5.0, -1.0])), Winding::Clockwise);
+            assert_eq!(triangle.winding(Point3D::new([5.0, 5.0, 0.0])), Winding::EdgeOn);
+            let flipped = triangle.flip_winding();
+            assert_eq!(flipped.normal(), [0.0, 0.0, -4.0]);
+            assert_eq!(flipped.winding(Point3D::new([0.0, 0.0, 1.0])), Winding::Clockwise);
+            assert_eq!(flipped.flip_winding(), triangle);
+
+            // The normal is the same physical direction in bases of either handedness
+            let convert = ConvertBasis::<ZUpRightHanded, YUpLeftHanded, 3>::new().unwrap();
+            let converted = Triangle::new(convert.apply_point(A), convert.apply_point(B), convert.apply_point(C))?;
+            assert_eq!(converted.normal(), convert.apply(triangle.normal()));
+            assert_eq!(converted.winding(convert.apply_point(Point3D::new([0.0, 0.0, 1.0]))), Winding::CounterClockwise);
+            // The same coordinates in a left-handed basis have the opposite normal
+            let [A, B, C] = [A, B, C].map(|point| Point3D::<f64, YUpLeftHanded>::new(point.to_array()));
+            assert_eq!(Triangle::new(A, B, C)?.normal(), [0.0, 0.0, -4.0]);
+
+            let [A, B, C] = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]].map(Point2D::<f64, ()>::new);
+            let triangle = Triangle::new(A, B, C)?;
+            assert_eq!(triangle.winding(), Winding::CounterClockwise);
+            assert_eq!(triangle.flip_winding().winding(), Winding::Clockwise);
+            Ok(())
+        }
+
+        #[test]
+        pub fn degenerate_points() {
+            let point = Point3D::<f64, ()>::new;
+            assert_eq!(Triangle::new(point([0.0, 0.0, 0.0]), point([1.0, 1.0, 1.0]), point([0.0, 0.0, 0.0])), Err(InvalidTriangleError::CoincidentPoints));
+            assert_eq!(Triangle::new(point([0.0, f64::NAN, 0.0]), point([1.0, 1.0, 1.0]), point([2.0, 0.0, 0.0])), Err(InvalidTriangleError::NonFinitePoint));
+            assert_eq!(Triangle::new(point([0.0, 0.0, 0.0]), point([1.0, f64::INFINITY, 1.0]), point([0.0, 0.0, 0.0])), Err(InvalidTriangleError::NonFinitePoint));
+            assert_eq!(Triangle::new(point([0.0, 0.0, 0.0]), point([1.0, 2.0, 3.0]), point([3.0, 6.0, 9.0])), Err(InvalidTriangleError::CollinearPoints));
+            assert_eq!(Triangle::new(point([0.1, 0.2, 0.3]), point([0.2, 0.4, 0.6]), point([0.7, 1.4, 2.1])), Err(InvalidTriangleError::CollinearPoints));
+
+            // Collinearity is relative to the size of the triangle
+            let thin = [point([0.0, 0.0, 0.0]), point([1.0, 0.0, 0.0]), point([2.0, 1e-6, 0.0])];
+            assert!(Triangle::new(thin[0], thin[1], thin[2]).is_ok());
+            let [A, B, C] = thin.map(|point| Point3D::from_vector(point.to_vector() * 1e-9));
+            assert!(Triangle::new(A, B, C).is_ok());
+            assert_eq!(Triangle::with_tolerance(thin[0], thin[1], thin[2], 1e-6), Err(InvalidTriangleError::CollinearPoints));
+            assert!(Triangle::with_tolerance(thin[0], thin[1], thin[2], 1e-7).is_ok());
+            assert_eq!(Triangle::with_tolerance(point([0.0, 0.0, 0.0]), point([1.0, 0.0, 0.0]), point([2.0, 0.0, 0.0]), 0.0), Err(InvalidTriangleError::CollinearPoints));
+
+            // Squared side lengths would overflow or underflow at these scales
+            let point2 = Point2D::<f64, ()>::new;
+            assert_eq!(Triangle::new(point2([0.0, 0.0]), point2([1e160, 0.0]), point2([2e160, 0.0])), Err(InvalidTriangleError::CollinearPoints));
+            assert_eq!(Triangle::new(point2([0.0, 0.0]), point2([1e-170, 0.0]), point2([2e-170, 0.0])), Err(InvalidTriangleError::CollinearPoints));
+            assert!(Triangle::new(point2([0.0, 0.0]), point2([1e160, 0.0]), point2([0.0, 1e160])).is_ok());
+            assert!(Triangle::new(point2([0.0, 0.0]), point2([1e-170, 0.0]), point2([0.0, 1e-170])).is_ok());
+
+            // Properties of thin triangles remain well defined
+            let triangle = Triangle::new(thin[0], thin[1], thin[2]).unwrap();
+            let angles = [triangle.angle_alpha(), triangle.angle_beta(), triangle.angle_gamma()].map(Angle::radians);
+            assert!(angles.iter().all(|angle| angle.is_finite() && *angle > 0.0));
+            assert!((angles.iter().sum::<f64>() - std::f64::consts::PI).abs() < 1e-12);
+            assert!((triangle.area() - 0.5e-6).abs() < 1e-18);
+            assert!((triangle.altitude_c() - 1e-6).abs() < 1e-18);
+        }
+    }
+
+    mod enclosing {
+        use crate::shapes::enclosing::{approximate_enclosing_ball, minimum_enclosing_ball, DEFAULT_SEED};
+        use crate::utility::SplitMix64;
+        use crate::vector::PointN;
+
+        #[test]
+        pub fn four_dimensions() {
+            // Corners of the unit tesseract, and a regular simplex whose points all lie on the boundary
+            let corners: Vec<_> = (0..16).map(|corner| PointN::<f64, 4, ()>::new(std::array::from_fn(|axis| (corner >> axis & 1) as f64))).collect();
+            let (center, radius) = minimum_enclosing_ball(&corners, DEFAULT_SEED).unwrap();
+            assert_approx_eq!(center, PointN::new([0.5; 4]));
+            assert_approx_eq!(radius, 1.0);
+            let simplex: Vec<_> = (0..4).map(|axis| PointN::<f64, 4, ()>::new(std::array::from_fn(|index| if index == axis { 1.0 } else { 0.0 })))
+                .chain(std::iter::once(PointN::new([(1.0 - 5.0f64.sqrt()) / 4.0; 4])))
+                .collect();
+            let (center, radius) = minimum_enclosing_ball(&simplex, DEFAULT_SEED).unwrap();
+            assert!(simplex.iter().all(|&point| ((point - center).magnitude() - radius).abs() < 1e-12));
+
+            let mut random = SplitMix64::new(3);
+            let points: Vec<_> = (0..500).map(|_| PointN::<f64, 4, ()>::new(std::array::from_fn(|_| random.uniform(-1.0, 1.0)))).collect();
+            let (center, radius) = minimum_enclosing_ball(&points, DEFAULT_SEED).unwrap();
+            assert!(points.iter().all(|&point| (point - center).magnitude() <= radius + 1e-12));
+            assert!(points.iter().filter(|&&point| ((point - center).magnitude() - radius).abs() <= 1e-12).count() >= 2);
+            let (_, approximate) = approximate_enclosing_ball(&points).unwrap();
+            assert!(approximate >= radius);
+        }
+    }
+
+    mod aabb {
+        use crate::geometry2d::{Aabb2D, Point2D, Vector2D};
+        use crate::geometry2d::shapes::Circle;
+        use crate::geometry3d::{Aabb3D, Point3D, RotationMatrix, Vector3D};
+        use crate::geometry3d::shapes::Sphere;
+        use crate::geometry3d::transform::Affine3D;
+        use crate::matrix::MatrixMN;
+        use crate::shapes::aabb::Bounded;
+        use crate::shapes::triangle::Triangle;
+
+        #[test]
+        pub fn construction_and_measurements() {
+            let unordered = Aabb3D::<f64, ()>::new(Point3D::new([2.0, -1.0, 4.0]), Point3D::new([0.0, 1.0, 1.0]));
+            assert_eq!(unordered, Aabb3D::new(Point3D::new([0.0, -1.0, 1.0]), Point3D::new([2.0, 1.0, 4.0])));
+            assert_eq!(unordered.center(), Point3D::new([1.0, 0.0, 2.5]));
+            assert_eq!(unordered.extents(), Vector3D::new([2.0, 2.0, 3.0]));
+            assert_eq!(unordered.volume(), 12.0);
+            assert_eq!(unordered.surface_area(), 32.0);
+
+            let points = [[1.0, 5.0], [-2.0, 3.0], [0.0, 7.0]].map(Point2D::new);
+            let flat = Aabb2D::<f64, ()>::from_points(&points).unwrap();
+            assert_eq!((flat.min(), flat.max()), (Point2D::new([-2.0, 3.0]), Point2D::new([1.0, 7.0])));
+            assert_eq!(flat.area(), 12.0);
+            assert!(points.iter().all(|&point| flat.contains(point)));
+            assert!(Aabb2D::<f64, ()>::from_points(&[]).is_none());
+            assert_eq!(Aabb2D::<f64, ()>::from_points(&points[..1]).unwrap().area(), 0.0);
+        }
+
+        #[test]
+        pub fn set_operations() {
+            let first = Aabb3D::<f64, ()>::new(Point3D::new([0.0, 0.0, 0.0]), Point3D::new([2.0, 2.0, 2.0]));
+            let second = Aabb3D::new(Point3D::new([1.0, -1.0, 1.0]), Point3D::new([3.0, 1.0, 4.0]));
+            assert_eq!(first.union(second), Aabb3D::new(Point3D::new([0.0, -1.0, 0.0]), Point3D::new([3.0, 2.0, 4.0])));
+            assert_eq!(first.intersection(second), Some(Aabb3D::new(Point3D::new([1.0, 0.0, 1.0]), Point3D::new([2.0, 1.0, 2.0]))));
+            assert!(first.overlaps(second));
+            assert!(first.union(second).contains_box(first) && !first.contains_box(second));
+
+            // Touching boxes overlap, with an intersection of zero volume
+            let touching = Aabb3D::new(Point3D::new([2.0, 0.0, 0.0]), Point3D::new([3.0, 1.0, 1.0]));
+            assert_eq!(first.intersection(touching).unwrap().volume(), 0.0);
+            assert!(!first.overlaps(Aabb3D::new(Point3D::new([2.5, 0.0, 0.0]), Point3D::new([3.0, 1.0, 1.0]))));
+            assert!(first.intersection(Aabb3D::new(Point3D::new([0.0, 0.0, 2.5]), Point3D::new([1.0, 1.0, 3.0]))).is_none());
+
+            assert!(first.contains(Point3D::new([2.0, 0.0, 1.0])));
+            assert!(!first.contains(Point3D::new([2.0, -0.1, 1.0])));
+            assert_eq!(first.closest_point(Point3D::new([1.0, 1.0, 1.5])), Point3D::new([1.0, 1.0, 1.5]));
+            assert_eq!(first.closest_point(Point3D::new([5.0, 1.0, -4.0])), Point3D::new([2.0, 1.0, 0.0]));
+            assert_eq!(first.distance_to(Point3D::new([5.0, 1.0, -4.0])), 5.0);
+            assert_eq!(first.distance_to(Point3D::new([1.0, 1.0, 1.0])), 0.0);
+        }
+
+        #[test]
+        pub fn ray_intersection() {
+            let target = Aabb3D::<f64, ()>::new(Point3D::new([-1.0, -1.0, -1.0]), Point3D::new([1.0, 1.0, 1.0]));
+            let origin = Point3D::new([-3.0, 0.5, 0.0]);
+            assert_eq!(target.ray_intersection(origin, Vector3D::new([1.0, 0.0, 0.0])), Some((2.0, 4.0)));
+            assert_eq!(target.ray_intersection(origin, Vector3D::new([2.0, 0.0, 0.0])), Some((1.0, 2.0)));
+            assert!(target.ray_intersection(origin, Vector3D::new([-1.0, 0.0, 0.0])).is_none());
+            assert!(target.ray_intersection(origin, Vector3D::new([1.0, 1.0, 0.0])).is_none());
+            assert_approx_eq!(target.ray_intersection(origin, Vector3D::new([1.0, -0.25, 0.0])).unwrap().0, 2.0);
+            assert_eq!(target.ray_intersection(Point3D::new([0.0, 0.0, 0.0]), Vector3D::new([0.0, 0.0, -1.0])), Some((-1.0, 1.0)));
+
+            // Parallel rays, in the plane of a face and just outside it
+            assert_eq!(target.ray_intersection(Point3D::new([-3.0, 1.0, 1.0]), Vector3D::new([1.0, 0.0, 0.0])), Some((2.0, 4.0)));
+            assert!(target.ray_intersection(Point3D::new([-3.0, 1.0, 1.5]), Vector3D::new([1.0, 0.0, 0.0])).is_none());
+            assert!(target.ray_intersection(Point3D::new([0.0, 0.0, 0.0]), Vector3D::new([0.0, 0.0, 0.0])).is_none());
+        }
+
+        #[test]
+        pub fn transform() {
+            let square = Aabb2D::<f64, ()>::new(Point2D::new([-1.0, -1.0]), Point2D::new([1.0, 1.0]));
+            let half = std::f64::consts::FRAC_1_SQRT_2;
+            let rotated = square.transform(MatrixMN::from_row_major([[half, -half], [half, half]]), Vector2D::new([1.0, 0.0]));
+            assert_approx_eq!(rotated, Aabb2D::new(Point2D::new([1.0 - 2.0f64.sqrt(), -(2.0f64.sqrt())]), Point2D::new([1.0 + 2.0f64.sqrt(), 2.0f64.sqrt()])));
+
+            // Matches the box of the transformed corners
+            let target = Aabb3D::<f64, ()>::new(Point3D::new([0.0, -1.0, 2.0]), Point3D::new([3.0, 1.0, 5.0]));
+            let affine = Affine3D::new(MatrixMN::from_row_major([[1.0, -2.0, 0.5], [0.0, 3.0, -1.0], [-1.5, 0.0, 2.0]]), Vector3D::new([1.0, 2.0, 3.0]));
+            let corners: Vec<_> = (0..8).map(|corner| {
+                let [min, max] = [target.min().to_array(), target.max().to_array()];
+                affine.transform_point(Point3D::new(std::array::from_fn(|axis| if corner >> axis & 1 == 0 { min[axis] } else { max[axis] })))
+            }).collect();
+            assert_approx_eq!(affine.transform_aabb(target), Aabb3D::from_points(&corners).unwrap());
+
+            let rotation = RotationMatrix::from_axis_angle(Vector3D::new([1.0, 2.0, 3.0]), 0.7);
+            let rotated = target.transform(rotation.into(), Vector3D::new([0.0; 3]));
+            assert_approx_eq!(rotated.center(), Point3D::from_vector(rotation.apply(target.center().to_vector())));
+            assert!(rotated.volume() > target.volume());
+        }
+
+        #[test]
+        pub fn bounded() {
+            let triangle = Triangle::<f64, 3, ()>::new(Point3D::new([0.0, 1.0, 2.0]), Point3D::new([3.0, -1.0, 2.0]), Point3D::new([1.0, 4.0, -2.0])).unwrap();
+            assert_eq!(triangle.bounding_box(), Aabb3D::new(Point3D::new([0.0, -1.0, -2.0]), Point3D::new([3.0, 4.0, 2.0])));
+            let sphere = Sphere::<f64, ()>::new(Point3D::new([1.0, 2.0, 3.0]), 2.0).unwrap();
+            assert_eq!(sphere.bounding_box(), Aabb3D::new(Point3D::new([-1.0, 0.0, 1.0]), Point3D::new([3.0, 4.0, 5.0])));
+            let circle = Circle::<f64, ()>::new(Point2D::new([1.0, -1.0]), 0.5).unwrap();
+            assert_eq!(circle.bounding_box(), Aabb2D::new(Point2D::new([0.5, -1.5]), Point2D::new([1.5, -0.5])));
+            assert_eq!(sphere.bounding_box().bounding_box(), sphere.bounding_box());
+        }
+    }
+}
+
+mod geometry3d {
+    mod quaternion {
+        use crate::geometry3d::{Quaternion, RotationMatrix, Vector3D};
+
+        fn test_rotations() -> [Quaternion<f64, ()>; 5] {
+            [
+                Quaternion::identity(),
+                Quaternion::from_axis_angle(Vector3D::new([1.0, 0.0, 0.0]), 0.5),
+                Quaternion::from_axis_angle(Vector3D::new([0.0, 0.0, 1.0]), 3.0),   // Large rotations cover the non-trace branches of Shepperd's method
+                Quaternion::from_axis_angle(Vector3D::new([1.0, -2.0, 0.5]), -2.5),
+                Quaternion::from_axis_angle(Vector3D::new([0.2, 1.0, 3.0]), 3.1),
+            ]
+        }
+
+        #[test]
+        pub fn axis_angle() {
+            let quarter_turn = Quaternion::<f64, ()>::from_axis_angle(Vector3D::new([0.0, 0.0, 2.0]), std::f64::consts::FRAC_PI_2);
+            assert_approx_eq!(quarter_turn.apply(Vector3D::new([1.0, 0.0, 0.0])), Vector3D::new([0.0, 1.0, 0.0]), epsilon = 1e-12);
+            assert_approx_eq!(quarter_turn.apply(Vector3D::new([0.0, 0.0, 1.0])), Vector3D::new([0.0, 0.0, 1.0]), epsilon = 1e-12);
+        }
+
+        #[test]
+        pub fn hamilton_product() {
+            let i = Quaternion::<f64, ()>::new(0.0, 1.0, 0.0, 0.0);
+            let j = Quaternion::<f64, ()>::new(0.0, 0.0, 1.0, 0.0);
+            let k = Quaternion::<f64, ()>::new(0.0, 0.0, 0.0, 1.0);
+            let minus_one = Quaternion::<f64, ()>::new(-1.0, 0.0, 0.0, 0.0);
+
+            assert_eq!(i * i, minus_one);
+            assert_eq!(j * j, minus_one);
+            assert_eq!(k * k, minus_one);
+            assert_eq!(i * j * k, minus_one);
+            assert_eq!(i * j, k);
+            assert_eq!(j * i, -k);
+        }
+
+        #[test]
+        pub fn composition() {
+            let vector = Vector3D::new([0.3, -1.2, 2.0]);
+            for lhs in test_rotations() {
+                for rhs in test_rotations() {
+                    assert_approx_eq!((lhs * rhs).apply(vector), lhs.apply(rhs.apply(vector)), epsilon = 1e-12);
+                }
+            }
+        }
+
+        #[test]
+        pub fn inverse() {
+            let quaternion = Quaternion::<f64, ()>::new(1.0, 2.0, -3.0, 4.0);
+            assert_approx_eq!(quaternion * quaternion.inverse().unwrap(), Quaternion::identity(), epsilon = 1e-12);
+            assert_approx_eq!(quaternion.inverse().unwrap() * quaternion, Quaternion::identity(), epsilon = 1e-12);
+            assert_approx_eq!(quaternion.with_unit_length().norm(), 1.0, epsilon = 1e-12);
+
+            for rotation in test_rotations() {
+                assert_approx_eq!(rotation.conjugate(), rotation.inverse().unwrap(), epsilon = 1e-12);
+            }
+
+            assert_eq!(Quaternion::<f64, ()>::new(0.0, 0.0, 0.0, 0.0).inverse(), None);
+        }
+
+        #[test]
+        pub fn rotation_matrix_conversion() {
+            let vector = Vector3D::new([0.3, -1.2, 2.0]);
+            for rotation in test_rotations() {
+                let matrix = RotationMatrix::from(rotation);
+                assert_approx_eq!(matrix.apply(vector), rotation.apply(vector), epsilon = 1e-12);
+
+                // q and -q are the same rotation, conversion normalizes to positive w
+                let expected = if rotation.w() < 0.0 { -rotation } else { rotation };
+                assert_approx_eq!(Quaternion::from(matrix), expected, epsilon = 1e-12);
+            }
+        }
+    }
+
+    mod rotation_matrix {
+        use crate::geometry3d::{EulerOrder, RotationMatrix, Vector3D};
+
+        fn test_rotations() -> [RotationMatrix<f64, ()>; 4] {
+            [
+                RotationMatrix::identity(),
+                RotationMatrix::from_axis_angle(Vector3D::new([1.0, 0.0, 0.0]), 0.5),
+                RotationMatrix::from_axis_angle(Vector3D::new([1.0, -2.0, 0.5]), -2.5),
+                RotationMatrix::from_euler(EulerOrder::ZYX, 0.1, 0.2, 0.3),
+            ]
+        }
+
+        #[test]
+        pub fn multiplication() {
+            let vector = Vector3D::new([0.3, -1.2, 2.0]);
+            for a in test_rotations() {
+                for b in test_rotations() {
+                    assert_approx_eq!((a * b).apply(vector), a.apply(b.apply(vector)), epsilon = 1e-12);
+                    for c in test_rotations() {
+                        assert_approx_eq!((a * b) * c, a * (b * c), epsilon = 1e-12);
+                    }
+                }
+            }
+
+            // Non-rotation matrices catch errors in individual elements
+            let a = RotationMatrix::<f64, ()>::from_row_major([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+            let b = RotationMatrix::<f64, ()>::from_row_major([[-1.0, 0.5, 2.0], [3.0, -2.0, 1.0], [0.0, 4.0, -3.0]]);
+            assert_eq!((a * b).to_row_major(), [[5.0, 8.5, -5.0], [11.0, 16.0, -5.0], [17.0, 23.5, -5.0]]);
+        }
+
+        #[test]
+        pub fn inverse() {
+            for rotation in test_rotations() {
+                assert_approx_eq!(rotation * rotation.inverse(), RotationMatrix::identity(), epsilon = 1e-12);
+                assert_approx_eq!(rotation.inverse() * rotation, RotationMatrix::identity(), epsilon = 1e-12);
+                assert_approx_eq!(rotation.determinant(), 1.0, epsilon = 1e-12);
+                assert!(rotation.is_orthonormal(1e-12));
+            }
+
+            let matrix = RotationMatrix::<f64, ()>::from_row_major([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+            assert_eq!(matrix.transpose().to_row_major(), [[1.0, 4.0, 7.0], [2.0, 5.0, 8.0], [3.0, 6.0, 10.0]]);
+            assert_approx_eq!(matrix.determinant(), -3.0, epsilon = 1e-12);
+
+            let reflection = RotationMatrix::<f64, ()>::from_row_major([[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+            assert!(!reflection.is_orthonormal(1e-12));
+        }
+
+        #[test]
+        pub fn orthonormalize() {
+            let vector = Vector3D::new([0.3, -1.2, 2.0]);
+            let step = RotationMatrix::<f64, ()>::from_axis_angle(Vector3D::new([1.0, 2.0, 3.0]), 0.1);
+            let drifted = RotationMatrix::<f64, ()>::from_row_major(step.to_row_major().map(|row| row.map(|value| value * 1.001)));
+            assert!(!drifted.is_orthonormal(1e-6));
+
+            let polar = drifted.orthonormalize_polar();
+            assert!(polar.is_orthonormal(1e-12));
+            assert_approx_eq!(polar.apply(vector), step.apply(vector), epsilon = 1e-12);  // Uniform scaling drift is removed exactly by polar decomposition
+
+            let gram_schmidt = drifted.orthonormalize_gram_schmidt();
+            assert!(gram_schmidt.is_orthonormal(1e-12));
+            assert_approx_eq!(gram_schmidt.apply(vector), step.apply(vector), epsilon = 1e-12);
+
+            // Accumulated error over repeated composition
+            let mut accumulated = RotationMatrix::<f32, ()>::identity();
+            let step = RotationMatrix::<f32, ()>::from_axis_angle(Vector3D::new([1.0, 2.0, 3.0]), 0.1);
+            for _ in 0..10_000 {
+                accumulated = accumulated * step;
+            }
+            assert!(accumulated.orthonormalize_polar().is_orthonormal(1e-6));
+            assert!(accumulated.orthonormalize_gram_schmidt().is_orthonormal(1e-6));
+        }
+
+        #[test]
+        pub fn axis_angle() {
+            let x_axis = Vector3D::new([1.0, 0.0, 0.0]);
+            let y_axis = Vector3D::new([0.0, 1.0, 0.0]);
+            let z_axis = Vector3D::new([0.0, 0.0, 1.0]);
+            let quarter = std::f64::consts::FRAC_PI_2;
+
+            // Right-hand rule: X -> Y -> Z -> X
+            assert_approx_eq!(RotationMatrix::from_axis_angle(z_axis, quarter).apply(x_axis), y_axis, epsilon = 1e-12);
+            assert_approx_eq!(RotationMatrix::from_axis_angle(x_axis, quarter).apply(y_axis), z_axis, epsilon = 1e-12);
+            assert_approx_eq!(RotationMatrix::from_axis_angle(y_axis, quarter).apply(z_axis), x_axis, epsilon = 1e-12);
+
+            for (axis, angle) in [(Vector3D::new([1.0, 2.0, 3.0]), 0.7), (Vector3D::new([-1.0, 0.5, 0.0]), 3.0), (z_axis, std::f64::consts::PI), (x_axis, 1e-9)] {
+                let (found_axis, found_angle) = RotationMatrix::<f64, ()>::from_axis_angle(axis, angle).to_axis_angle();
+                assert_approx_eq!(found_axis, axis.with_unit_length(), epsilon = 1e-12);
+                assert_approx_eq!(found_angle, angle, epsilon = 1e-12);
+            }
+
+            let (_, identity_angle) = RotationMatrix::<f64, ()>::identity().to_axis_angle();
+            assert_eq!(identity_angle, 0.0);
+        }
+
+        #[test]
+        pub fn euler_angles() {
+            let x_axis = Vector3D::new([1.0, 0.0, 0.0]);
+            let y_axis = Vector3D::new([0.0, 1.0, 0.0]);
+            let z_axis = Vector3D::new([0.0, 0.0, 1.0]);
+            let axes = [x_axis, y_axis, z_axis];
+
+            for order in EulerOrder::ALL {
+                let (a, b, c) = if order.is_proper() { (0.3, 1.1, -2.4) } else { (0.3, -1.1, 2.4) };
+                let matrix = RotationMatrix::<f64, ()>::from_euler(order, a, b, c);
+
+                // Intrinsic rotations: first axis, then second, then third
+                let [first, second, third] = order.axes();
+                let vector = Vector3D::new([0.3, -1.2, 2.0]);
+                let composed = RotationMatrix::from_axis_angle(axes[first], a).apply(
+                    RotationMatrix::from_axis_angle(axes[second], b).apply(
+                        RotationMatrix::from_axis_angle(axes[third], c).apply(vector)
+                    )
+                );
+                assert_approx_eq!(matrix.apply(vector), composed, epsilon = 1e-12);
+
+                let (found_a, found_b, found_c) = matrix.to_euler(order);
+                assert_approx_eq!([found_a, found_b, found_c], [a, b, c], epsilon = 1e-12);
+            }
+        }
+
+        #[test]
+        pub fn euler_angles_gimbal_lock() {
+            for order in EulerOrder::ALL {
+                let locked_angles = if order.is_proper() { [0.0, std::f64::consts::PI] } else { [std::f64::consts::FRAC_PI_2, -std::f64::consts::FRAC_PI_2] };
+                for b in locked_angles {
+                    let matrix = RotationMatrix::<f64, ()>::from_euler(order, 0.4, b, 1.3);
+                    let (found_a, found_b, found_c) = matrix.to_euler(order);
+                    assert_eq!(found_a, 0.0, "{:?}: First angle not zeroed in gimbal lock", order);
+                    assert_approx_eq!(RotationMatrix::from_euler(order, found_a, found_b, found_c), matrix, epsilon = 1e-12);
+                }
+            }
+        }
+
+        #[test]
+        pub fn rotation_between() {
+            let pairs = [
+                (Vector3D::new([1.0, 0.0, 0.0]), Vector3D::new([0.0, 2.0, 0.0])),
+                (Vector3D::new([1.0, 2.0, 3.0]), Vector3D::new([-3.0, 0.5, 1.0])),
+                (Vector3D::new([1.0, 2.0, 3.0]), Vector3D::new([1.0, 2.0, 3.0])),
+                (Vector3D::new([1.0, 2.0, 3.0]), Vector3D::new([-1.0, -2.0, -3.0])),
+                (Vector3D::new([0.0, 0.0, 1.0]), Vector3D::new([0.0, 0.0, -1.0])),
+            ];
+            for (from, to) in pairs {
+                let rotation = RotationMatrix::<f64, ()>::rotation_between(from, to).unwrap();
+                assert_approx_eq!(rotation.apply(from.with_unit_length()), to.with_unit_length(), epsilon = 1e-12);
+            }
+
+            // Nearly opposite directions, which must not be treated as exactly opposite
+            let from = Vector3D::new([1.0, 0.0, 0.0]);
+            for epsilon in [1.5e-6f64, 9e-7, 1e-7] {
+                let to = Vector3D::new([-(1.0 - epsilon * epsilon).sqrt(), epsilon, 0.0]);
+                let rotation = RotationMatrix::<f64, ()>::rotation_between(from, to).unwrap();
+                let error = (rotation.apply(from) - to).magnitude();
+                assert!(error < 1e-8, "Nearly opposite rotation off by {}", error);
+            }
+
+            assert_eq!(RotationMatrix::<f64, ()>::rotation_between(Vector3D::new([0.0, 0.0, 0.0]), Vector3D::new([1.0, 0.0, 0.0])), None);
+        }
+
+        #[test]
+        pub fn look_at() {
+            let forward = Vector3D::new([1.0, 0.0, 1.0]);
+            let up = Vector3D::new([0.0, 1.0, 0.0]);
+            let rotation = RotationMatrix::<f64, ()>::look_at(forward, up).unwrap();
+
+            assert_approx_eq!(rotation.apply(Vector3D::new([0.0, 0.0, 1.0])), forward.with_unit_length(), epsilon = 1e-12);
+            assert_approx_eq!(rotation.apply(Vector3D::new([0.0, 1.0, 0.0])), up, epsilon = 1e-12);
+            assert_approx_eq!(rotation.apply(Vector3D::new([1.0, 0.0, 0.0])), Vector3D::new([1.0, 0.0, -1.0]).with_unit_length(), epsilon = 1e-12);
+
+            assert_eq!(RotationMatrix::<f64, ()>::look_at(up, up * 2.0), None);
+        }
+    }
+
+    mod transform {
+        use crate::geometry3d::{Point3D, RotationMatrix, Vector3D};
+        use crate::geometry3d::transform::{Affine3D, Isometry3D, Similarity3D};
+        use crate::matrix::MatrixMN;
+        use crate::vector::VectorN;
+
+        fn assert_homogeneous_eq(matrix: MatrixMN<f64, 4, 4, ()>, point: Point3D<f64, ()>, expected_point: Point3D<f64, ()>, expected_vector: Vector3D<f64, ()>) {
+            let [x, y, z] = point.to_array();
+            let [px, py, pz, pw] = (matrix * VectorN::new([x, y, z, 1.0])).to_array();
+            assert_approx_eq!(Point3D::new([px, py, pz]), expected_point, epsilon = 1e-12);
+            assert_eq!(pw, 1.0);
+            let [vx, vy, vz, vw] = (matrix * VectorN::new([x, y, z, 0.0])).to_array();
+            assert_approx_eq!(Vector3D::new([vx, vy, vz]), expected_vector, epsilon = 1e-12);
+            assert_eq!(vw, 0.0);
+        }
+
+        fn isometries() -> [Isometry3D<f64, ()>; 2] {
+            [
+                Isometry3D::new(RotationMatrix::from_axis_angle(Vector3D::new([1.0, 2.0, 3.0]), 0.7), Vector3D::new([1.0, -2.0, 0.5])),
+                Isometry3D::new(RotationMatrix::from_axis_angle(Vector3D::new([0.0, 1.0, 0.0]), -2.0), Vector3D::new([0.0, 3.0, 1.0])),
+            ]
+        }
+
+        #[test]
+        pub fn points_and_vectors() {
+            let translation = Isometry3D::<f64, ()>::from_translation(Vector3D::new([1.0, 2.0, 3.0]));
+            assert_eq!(translation.transform_point(Point3D::new([1.0, 1.0, 1.0])), [2.0, 3.0, 4.0]);
+            assert_eq!(translation.transform_vector(Vector3D::new([1.0, 1.0, 1.0])), [1.0, 1.0, 1.0]);
+
+            let scale = Similarity3D::<f64, ()>::from_scale(2.0);
+            assert_eq!(scale.transform_vector(Vector3D::new([1.0, 1.0, 1.0])), [2.0, 2.0, 2.0]);
+
+            let affine = Affine3D::<f64, ()>::from_scale(Vector3D::new([1.0, 2.0, 3.0])) * Affine3D::from(translation);
+            assert_eq!(affine.transform_point(Point3D::new([0.0, 0.0, 0.0])), [1.0, 4.0, 9.0]);
+            assert_eq!(affine.transform_vector(Vector3D::new([1.0, 1.0, 1.0])), [1.0, 2.0, 3.0]);
+        }
+
+        #[test]
+        pub fn composition_and_inverse() {
+            let point = Point3D::new([0.3, -1.2, 2.0]);
+            let [a, b] = isometries();
+            assert_approx_eq!((a * b).transform_point(point), a.transform_point(b.transform_point(point)), epsilon = 1e-12);
+            assert_approx_eq!((a * b).transform_vector(point.to_vector()), a.transform_vector(b.transform_vector(point.to_vector())), epsilon = 1e-12);
+            assert_approx_eq!(a.inverse().transform_point(a.transform_point(point)), point, epsilon = 1e-12);
+            assert_approx_eq!((a * a.inverse()).transform_point(point), point, epsilon = 1e-12);
+
+            let (c, d) = (Similarity3D::new(2.0, a.rotation, a.translation), Similarity3D::new(0.25, b.rotation, b.translation));
+            assert_approx_eq!((c * d).transform_point(point), c.transform_point(d.transform_point(point)), epsilon = 1e-12);
+            assert_approx_eq!(c.inverse().unwrap().transform_point(c.transform_point(point)), point, epsilon = 1e-12);
+            assert_eq!(Similarity3D::<f64, ()>::from_scale(0.0).inverse(), None);
+
+            let (e, f) = (Affine3D::from(c), Affine3D::new(MatrixMN::from_row_major([[1.0, 0.5, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, -1.0]]), Vector3D::new([1.0, 0.0, 0.0])));
+            assert_approx_eq!(e.transform_point(point), c.transform_point(point), epsilon = 1e-12);
+            assert_approx_eq!((e * f).transform_point(point), e.transform_point(f.transform_point(point)), epsilon = 1e-12);
+            assert_approx_eq!(f.inverse().unwrap().transform_point(f.transform_point(point)), point, epsilon = 1e-12);
+            assert_eq!(Affine3D::<f64, ()>::from_scale(Vector3D::new([1.0, 0.0, 1.0])).inverse(), None);
+        }
+
+        #[test]
+        pub fn homogeneous() {
+            let point = Point3D::new([0.3, -1.2, 2.0]);
+            let [isometry, _] = isometries();
+            let similarity = Similarity3D::new(3.0, isometry.rotation, isometry.translation);
+            let affine = Affine3D::new(MatrixMN::from_row_major([[1.0, 0.5, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, -1.0]]), Vector3D::new([1.0, 0.0, 0.0]));
+
+            assert_homogeneous_eq(isometry.to_homogeneous(), point, isometry.transform_point(point), isometry.transform_vector(point.to_vector()));
+            assert_homogeneous_eq(similarity.to_homogeneous(), point, similarity.transform_point(point), similarity.transform_vector(point.to_vector()));
+            assert_homogeneous_eq(affine.to_homogeneous(), point, affine.transform_point(point), affine.transform_vector(point.to_vector()));
+        }
+    }
+
+    mod sphere {
+        use std::f64::consts::PI;
+        use crate::geometry3d::{Point3D, Vector3D};
+        use crate::geometry3d::shapes::{Circle3D, InvalidSphereError, Line3D, Plane3D, Ray3D, Sphere};
+        use crate::utility::SplitMix64;
+
+        #[test]
+        pub fn validation() {
+            assert!(Sphere::<f64, ()>::new(Point3D::new([0.0, 0.0, 0.0]), 0.0).is_ok());
+            assert_eq!(Sphere::<f64, ()>::new(Point3D::new([0.0, 0.0, 0.0]), -1.0), Err(InvalidSphereError::NegativeRadius));
+            assert_eq!(Sphere::<f64, ()>::new(Point3D::new([0.0, 0.0, 0.0]), f64::NAN), Err(InvalidSphereError::NonFinite));
+            assert_eq!(Sphere::<f64, ()>::new(Point3D::new([0.0, f64::INFINITY, 0.0]), 1.0), Err(InvalidSphereError::NonFinite));
+        }
+
+        #[test]
+        pub fn measurements() {
+            let unit = Sphere::<f64, ()>::new(Point3D::new([1.0, 2.0, 3.0]), 2.0).unwrap();
+            assert_approx_eq!(unit.volume(), 32.0 / 3.0 * PI);
+            assert_approx_eq!(unit.surface_area(), 16.0 * PI);
+
+            assert!(unit.contains(Point3D::new([1.0, 2.0, 5.0])));
+            assert!(unit.contains(Point3D::new([2.0, 3.0, 4.0])));
+            assert!(!unit.contains(Point3D::new([1.0, 2.0, 5.1])));
+            assert_approx_eq!(unit.distance_to(Point3D::new([1.0, 6.0, 3.0])), 2.0);
+            assert_approx_eq!(unit.distance_to(Point3D::new([1.5, 2.0, 3.0])), -1.5);
+            assert_approx_eq!(unit.closest_point(Point3D::new([1.0, 6.0, 3.0])), Point3D::new([1.0, 4.0, 3.0]));
+            assert_approx_eq!(unit.closest_point(Point3D::new([1.0, 2.0, 2.5])), Point3D::new([1.0, 2.0, 1.0]));
+            assert_approx_eq!(unit.distance_to(unit.closest_point(unit.center)), 0.0);
+        }
+
+        #[test]
+        pub fn sphere_intersection() {
+            let first = Sphere::<f64, ()>::new(Point3D::new([0.0, 0.0, 0.0]), 5.0).unwrap();
+            let second = Sphere::new(Point3D::new([8.0, 0.0, 0.0]), 5.0).unwrap();
+            assert!(first.overlaps(second));
+            assert_approx_eq!(first.sphere_intersection(second).unwrap(), Circle3D { center: Point3D::new([4.0, 0.0, 0.0]), normal: Vector3D::new([1.0, 0.0, 0.0]), radius: 3.0 });
+            let circle = second.sphere_intersection(first).unwrap();
+            assert_approx_eq!(circle.normal, Vector3D::new([-1.0, 0.0, 0.0]));
+
+            // Asymmetric; 3-4-5 and 3-√(r²-9) triangles from each center
+            let third = Sphere::new(Point3D::new([0.0, 0.0, 9.0]), 34.0f64.sqrt()).unwrap();
+            assert_approx_eq!(first.sphere_intersection(third).unwrap(), Circle3D { center: Point3D::new([0.0, 0.0, 4.0]), normal: Vector3D::new([0.0, 0.0, 1.0]), radius: 3.0 });
+
+            let touching = Sphere::new(Point3D::new([0.0, 10.0, 0.0]), 5.0).unwrap();
+            assert_approx_eq!(first.sphere_intersection(touching).unwrap().radius, 0.0);
+            assert!(first.sphere_intersection(Sphere::new(Point3D::new([0.0, 10.5, 0.0]), 5.0).unwrap()).is_none());
+            assert!(!first.overlaps(Sphere::new(Point3D::new([0.0, 10.5, 0.0]), 5.0).unwrap()));
+            // Contained spheres overlap as volumes, but their surfaces do not intersect
+            assert!(first.overlaps(Sphere::new(Point3D::new([1.0, 0.0, 0.0]), 1.0).unwrap()));
+            assert!(first.sphere_intersection(Sphere::new(Point3D::new([1.0, 0.0, 0.0]), 1.0).unwrap()).is_none());
+            assert!(first.sphere_intersection(first).is_none());
+        }
+
+        #[test]
+        pub fn line_intersection() {
+            let target = Sphere::<f64, ()>::new(Point3D::new([0.0, 0.0, 0.0]), 5.0).unwrap();
+            let line = Line3D { point: Point3D::new([-10.0, 3.0, 0.0]), direction: Vector3D::new([2.0, 0.0, 0.0]) };
+            let (entry, exit) = target.line_intersection(line).unwrap();
+            assert_approx_eq!([entry, exit], [3.0, 7.0]);
+            assert_approx_eq!(line.at(entry), Point3D::new([-4.0, 3.0, 0.0]));
+            assert!(target.line_intersection(Line3D { point: Point3D::new([-10.0, 5.5, 0.0]), ..line }).is_none());
+            let (entry, exit) = target.line_intersection(Line3D { point: Point3D::new([-10.0, 5.0, 0.0]), ..line }).unwrap();
+            assert_approx_eq!(entry, exit);
+
+            // Distant origin, where the naive quadratic formula loses most of its precision
+            let far = Line3D { point: Point3D::new([-1e9, 3.0, 0.0]), direction: Vector3D::new([1.0, 0.0, 0.0]) };
+            let (entry, exit) = target.line_intersection(far).unwrap();
+            assert_approx_eq!(exit - entry, 8.0, epsilon = 1e-6);
+
+            let ray = Ray3D { origin: Point3D::new([-10.0, 3.0, 0.0]), direction: Vector3D::new([1.0, 0.0, 0.0]) };
+            assert_approx_eq!(target.ray_intersection(ray).unwrap().0, 6.0);
+            assert!(target.ray_intersection(Ray3D { direction: -ray.direction, ..ray }).is_none());
+            let inside = target.ray_intersection(Ray3D { origin: Point3D::new([0.0, 3.0, 0.0]), ..ray }).unwrap();
+            assert_approx_eq!([inside.0, inside.1], [-4.0, 4.0]);
+        }
+
+        #[test]
+        pub fn plane_intersection() {
+            let target = Sphere::<f64, ()>::new(Point3D::new([1.0, 1.0, 1.0]), 5.0).unwrap();
+            let plane = Plane3D::new(Vector3D::new([0.0, 0.0, 2.0]), 10.0).unwrap();
+            assert_approx_eq!(target.plane_intersection(plane).unwrap(), Circle3D { center: Point3D::new([1.0, 1.0, 5.0]), normal: Vector3D::new([0.0, 0.0, 1.0]), radius: 3.0 });
+            let tangent = Plane3D::new(Vector3D::new([0.0, 0.0, -1.0]), 4.0).unwrap();
+            assert_approx_eq!(target.plane_intersection(tangent).unwrap().center, Point3D::new([1.0, 1.0, -4.0]));
+            assert!(target.plane_intersection(Plane3D::new(Vector3D::new([0.0, 0.0, 1.0]), 6.5).unwrap()).is_none());
+        }
+
+        fn random_points(seed: u64, count: usize) -> Vec<Point3D<f64, ()>> {
+            let mut random = SplitMix64::new(seed);
+            (0..count).map(|_| Point3D::new(std::array::from_fn(|_| random.uniform(-10.0, 10.0)))).collect()
+        }
+
+        #[test]
+        pub fn minimum_enclosing() {
+            let cube: Vec<_> = (0..8).map(|corner| Point3D::new([0, 1, 2].map(|axis| if corner >> axis & 1 == 0 { -1.0 } else { 1.0 }))).collect();
+            assert_approx_eq!(Sphere::minimum_enclosing(&cube).unwrap(), Sphere::<f64, ()>::new(Point3D::new([0.0, 0.0, 0.0]), 3.0f64.sqrt()).unwrap());
+            let tetrahedron = [[1.0, 1.0, 1.0], [1.0, -1.0, -1.0], [-1.0, 1.0, -1.0], [-1.0, -1.0, 1.0]].map(|point| Point3D::new(point) + Vector3D::new([2.0, 0.0, -1.0]));
+            assert_approx_eq!(Sphere::minimum_enclosing(&tetrahedron).unwrap(), Sphere::<f64, ()>::new(Point3D::new([2.0, 0.0, -1.0]), 3.0f64.sqrt()).unwrap());
+            // Diameter of the two farthest points, with the rest inside
+            let pair = [[-3.0, 0.0, 0.0], [0.0, 1.0, 0.5], [3.0, 0.0, 0.0], [0.0, 0.0, -2.0]].map(Point3D::new);
+            assert_approx_eq!(Sphere::minimum_enclosing(&pair).unwrap(), Sphere::<f64, ()>::new(Point3D::new([0.0, 0.0, 0.0]), 3.0).unwrap());
+            assert_approx_eq!(Sphere::minimum_enclosing(&[Point3D::new([1.0, 2.0, 3.0])]).unwrap(), Sphere::<f64, ()>::new(Point3D::new([1.0, 2.0, 3.0]), 0.0).unwrap());
+
+            // Degenerate support sets; Collinear, and coplanar points on a circle
+            let collinear = [0.0, 4.0, 1.0, 3.0, 2.0].map(|t| Point3D::new([t, 2.0 * t, 0.0]));
+            assert_approx_eq!(Sphere::minimum_enclosing(&collinear).unwrap(), Sphere::<f64, ()>::new(Point3D::new([2.0, 4.0, 0.0]), 20.0f64.sqrt()).unwrap());
+            let coplanar = [[5.0, 0.0, 1.0], [0.0, 5.0, 1.0], [-5.0, 0.0, 1.0], [0.0, -5.0, 1.0], [3.0, 4.0, 1.0], [-4.0, -3.0, 1.0]].map(Point3D::new);
+            assert_approx_eq!(Sphere::minimum_enclosing(&coplanar).unwrap(), Sphere::<f64, ()>::new(Point3D::new([0.0, 0.0, 1.0]), 5.0).unwrap());
+
+            assert!(Sphere::<f64, ()>::minimum_enclosing(&[]).is_none());
+            assert!(Sphere::minimum_enclosing(&[Point3D::<f64, ()>::new([0.0, f64::NAN, 0.0])]).is_none());
+        }
+
+        #[test]
+        pub fn minimum_enclosing_random() {
+            let points = random_points(7, 500);
+            let minimum = Sphere::minimum_enclosing(&points).unwrap();
+            assert!(points.iter().all(|&point| minimum.distance_to(point) <= 1e-12));
+            // Minimal spheres are supported by at least 2 points on the surface
+            assert!(points.iter().filter(|&&point| minimum.distance_to(point).abs() <= 1e-12).count() >= 2);
+            assert_eq!(Sphere::minimum_enclosing(&points), Sphere::minimum_enclosing(&points));
+            for seed in [1, 2, 3] {
+                assert_approx_eq!(Sphere::minimum_enclosing_seeded(&points, seed).unwrap(), minimum, epsilon = 1e-9);
+            }
+
+            let approximate = Sphere::approximate_enclosing(&points).unwrap();
+            assert!(points.iter().all(|&point| approximate.distance_to(point) <= 1e-12));
+            assert!(approximate.radius >= minimum.radius && approximate.radius <= 1.2 * minimum.radius);
+            assert!(Sphere::<f64, ()>::approximate_enclosing(&[]).is_none());
+        }
+    }
+
+    mod obb {
+        use std::f64::consts::{FRAC_PI_4, SQRT_2};
+        use crate::geometry3d::{Aabb3D, Point3D, RotationMatrix, Vector3D};
+        use crate::geometry3d::shapes::{InvalidObbError, Obb3D};
+        use crate::matrix::MatrixN;
+        use crate::shapes::aabb::Bounded;
+        use crate::shapes::triangle::Triangle;
+        use crate::utility::SplitMix64;
+
+        #[test]
+        pub fn queries() {
+            let identity = RotationMatrix::<f64, ()>::identity();
+            assert_eq!(Obb3D::new(Point3D::new([0.0; 3]), Vector3D::new([1.0, -1.0, 1.0]), identity), Err(InvalidObbError::NegativeExtent));
+            assert_eq!(Obb3D::new(Point3D::new([0.0, f64::NAN, 0.0]), Vector3D::new([1.0; 3]), identity), Err(InvalidObbError::NonFinite));
+
+            // Rotated 45° around Z, with its long axis along the XY diagonal
+            let quarter_turn = RotationMatrix::<f64, ()>::from_axis_angle(Vector3D::new([0.0, 0.0, 1.0]), FRAC_PI_4);
+            let diagonal = Obb3D::new(Point3D::new([1.0, 0.0, 0.0]), Vector3D::new([2.0, 1.0, 1.0]), quarter_turn).unwrap();
+            assert_eq!(diagonal.volume(), 16.0);
+            assert!(diagonal.contains(Point3D::new([2.0, 1.0, 0.5])));
+            assert!(!diagonal.contains(Point3D::new([2.0, -1.0, 0.0])));
+            assert_approx_eq!(diagonal.closest_point(Point3D::new([3.0, 2.0, 0.0])), Point3D::new([1.0 + SQRT_2, SQRT_2, 0.0]));
+            assert_approx_eq!(diagonal.distance_to(Point3D::new([3.0, 2.0, 0.0])), 2.0 * SQRT_2 - 2.0);
+            assert_approx_eq!(diagonal.closest_point(Point3D::new([1.5, 0.2, -0.3])), Point3D::new([1.5, 0.2, -0.3]));
+
+            let corners = diagonal.corners();
+            assert!(corners.iter().all(|&corner| diagonal.distance_to(corner) < 1e-12));
+            assert_approx_eq!(corners[7], Point3D::new([1.0 + SQRT_2 - SQRT_2 / 2.0, SQRT_2 + SQRT_2 / 2.0, 1.0]));
+            assert_approx_eq!(diagonal.bounding_box(), Aabb3D::from_points(&corners).unwrap());
+        }
+
+        #[test]
+        pub fn box_overlap() {
+            let (identity, quarter_turn) = (RotationMatrix::<f64, ()>::identity(), RotationMatrix::from_axis_angle(Vector3D::new([0.0, 0.0, 1.0]), FRAC_PI_4));
+            let unit = Obb3D::new(Point3D::new([0.0; 3]), Vector3D::new([1.0; 3]), identity).unwrap();
+            let turned = |x: f64| Obb3D::new(Point3D::new([x, 0.0, 0.0]), Vector3D::new([1.0; 3]), quarter_turn).unwrap();
+            assert!(unit.overlaps(turned(1.0 + SQRT_2 - 0.01)));
+            assert!(!unit.overlaps(turned(1.0 + SQRT_2 + 0.01)));
+            assert!(turned(0.0).overlaps(unit) && unit.overlaps(unit));
+
+            // Edges crossing at right angles, separated only by their cross product (the X axis)
+            let vertical_edge = Obb3D::new(Point3D::new([0.0; 3]), Vector3D::new([1.0; 3]), quarter_turn).unwrap();
+            let horizontal_edge = |x: f64| Obb3D::new(Point3D::new([x, 0.0, 0.0]), Vector3D::new([1.0; 3]), RotationMatrix::from_axis_angle(Vector3D::new([0.0, 1.0, 0.0]), FRAC_PI_4)).unwrap();
+            assert!(vertical_edge.overlaps(horizontal_edge(2.0 * SQRT_2 - 0.01)));
+            assert!(!vertical_edge.overlaps(horizontal_edge(2.0 * SQRT_2 + 0.01)));
+            assert!(!horizontal_edge(2.0 * SQRT_2 + 0.01).overlaps(vertical_edge));
+
+            // Parallel boxes, where the cross products of the axes are zero
+            assert!(unit.overlaps(Obb3D::new(Point3D::new([2.0, 2.0, 0.0]), Vector3D::new([1.0; 3]), identity).unwrap()));
+            assert!(!unit.overlaps(Obb3D::new(Point3D::new([2.0, 2.1, 0.0]), Vector3D::new([1.0; 3]), identity).unwrap()));
+        }
+
+        #[test]
+        pub fn triangle_overlap() {
+            let (identity, quarter_turn) = (RotationMatrix::<f64, ()>::identity(), RotationMatrix::<f64, ()>::from_axis_angle(Vector3D::new([0.0, 0.0, 1.0]), FRAC_PI_4));
+            let unit = Obb3D::new(Point3D::new([0.0; 3]), Vector3D::new([1.0; 3]), identity).unwrap();
+            assert!(unit.overlaps_triangle(Triangle::new(Point3D::new([-3.0, -3.0, 0.5]), Point3D::new([3.0, -3.0, 0.5]), Point3D::new([0.0, 3.0, 0.5])).unwrap()));
+            assert!(unit.overlaps_triangle(Triangle::new(Point3D::new([0.1, 0.1, 0.1]), Point3D::new([0.2, 0.1, 0.1]), Point3D::new([0.1, 0.2, 0.1])).unwrap()));
+            assert!(!unit.overlaps_triangle(Triangle::new(Point3D::new([-3.0, -3.0, 1.5]), Point3D::new([3.0, -3.0, 1.5]), Point3D::new([0.0, 3.0, 1.5])).unwrap()));
+            // Separated by the triangle normal, past the corner
+            assert!(!unit.overlaps_triangle(Triangle::new(Point3D::new([3.5, 0.0, 0.0]), Point3D::new([0.0, 3.5, 0.0]), Point3D::new([0.0, 0.0, 3.5])).unwrap()));
+            assert!(unit.overlaps_triangle(Triangle::new(Point3D::new([2.5, 0.0, 0.0]), Point3D::new([0.0, 2.5, 0.0]), Point3D::new([0.0, 0.0, 2.5])).unwrap()));
+            // Separated by the cross product of the Z axis and an edge, past the vertical edge of the box
+            assert!(!unit.overlaps_triangle(Triangle::new(Point3D::new([2.2, 0.0, 0.0]), Point3D::new([0.0, 2.2, 0.0]), Point3D::new([3.0, 3.0, 0.0])).unwrap()));
+            assert!(unit.overlaps_triangle(Triangle::new(Point3D::new([1.8, 0.0, 0.0]), Point3D::new([0.0, 1.8, 0.0]), Point3D::new([3.0, 3.0, 0.0])).unwrap()));
+
+            let turned = Obb3D::new(Point3D::new([0.0; 3]), Vector3D::new([1.0; 3]), quarter_turn).unwrap();
+            assert!(turned.overlaps_triangle(Triangle::new(Point3D::new([1.4, 0.0, 0.0]), Point3D::new([1.4, 1.0, 0.0]), Point3D::new([1.4, 0.0, 1.0])).unwrap()));
+            assert!(!turned.overlaps_triangle(Triangle::new(Point3D::new([1.5, 0.0, 0.0]), Point3D::new([1.5, 1.0, 0.0]), Point3D::new([1.5, 0.0, 1.0])).unwrap()));
+        }
+
+        #[test]
+        pub fn from_points() {
+            let orientation = RotationMatrix::<f64, ()>::from_axis_angle(Vector3D::new([1.0, 2.0, -1.0]), 0.8);
+            let fitted = Obb3D::new(Point3D::new([3.0, -2.0, 1.0]), Vector3D::new([5.0, 2.0, 0.5]), orientation).unwrap();
+            let mut random = SplitMix64::new(5);
+            let mut points: Vec<_> = (0..2000).map(|_| fitted.center + orientation.apply(Vector3D::new([random.uniform(-5.0, 5.0), random.uniform(-2.0, 2.0), random.uniform(-0.5, 0.5)]))).collect();
+            points.extend(fitted.corners());
+
+            let found = Obb3D::from_points(&points).unwrap();
+            assert!(points.iter().all(|&point| found.distance_to(point) < 1e-9));
+            assert_approx_eq!(found.half_extents, fitted.half_extents, epsilon = 0.05);
+            assert_approx_eq!(found.center, fitted.center, epsilon = 0.05);
+            assert_approx_eq!(MatrixN::from(found.orientation).determinant(), 1.0, epsilon = 1e-12);
+            assert!(found.volume() < 1.2 * fitted.volume());
+
+            let single = Obb3D::from_points(&[Point3D::<f64, ()>::new([1.0, 2.0, 3.0])]).unwrap();
+            assert_eq!((single.center, single.volume()), (Point3D::new([1.0, 2.0, 3.0]), 0.0));
+            assert!(Obb3D::<f64, ()>::from_points(&[]).is_none());
+        }
+    }
+
+    mod linear {
+        use crate::basis::YUpLeftHanded;
+        use crate::geometry3d::{Point3D, Vector3D};
+        use crate::geometry3d::shapes::{Line3D, Plane3D, Ray3D, Segment3D};
+        use crate::shapes::triangle::Triangle;
+
+        #[test]
+        pub fn plane_construction() {
+            let plane = Plane3D::<f64, ()>::from_points(Point3D::new([1.0, 0.0, 2.0]), Point3D::new([0.0, 1.0, 2.0]), Point3D::new([0.0, 0.0, 2.0])).unwrap();
+            assert_approx_eq!(plane, Plane3D::new(Vector3D::new([0.0, 0.0, 1.0]), 2.0).unwrap());
+            let triangle = Triangle::new(Point3D::new([0.0, 0.0, 2.0]), Point3D::new([1.0, 0.0, 2.0]), Point3D::new([0.0, 1.0, 2.0])).unwrap();
+            assert_approx_eq!(Plane3D::from_triangle(triangle).unwrap(), plane);
+            assert_approx_eq!(plane.flip(), Plane3D::new(Vector3D::new([0.0, 0.0, -1.0]), -2.0).unwrap());
+            assert!(Plane3D::from_points(Point3D::<f64, ()>::new([0.0; 3]), Point3D::new([1.0, 1.0, 1.0]), Point3D::new([2.0, 2.0, 2.0])).is_none());
+
+            // The normal follows the handedness of the basis, such that the same points face the other way in coordinates
+            let left = Plane3D::<f64, YUpLeftHanded>::from_points(Point3D::new([1.0, 0.0, 2.0]), Point3D::new([0.0, 1.0, 2.0]), Point3D::new([0.0, 0.0, 2.0])).unwrap();
+            assert_approx_eq!(left.normal(), Vector3D::new([0.0, 0.0, -1.0]));
+        }
+
+        #[test]
+        pub fn distance_and_projection() {
+            let plane = Plane3D::<f64, ()>::new(Vector3D::new([0.0, 3.0, 4.0]), 10.0).unwrap();
+            assert_approx_eq!(plane.offset(), 2.0);
+            assert_approx_eq!(plane.signed_distance(Point3D::new([7.0, 3.0, 4.0])), 3.0);
+            assert_approx_eq!(plane.flip().signed_distance(Point3D::new([7.0, 3.0, 4.0])), -3.0);
+            assert_approx_eq!(plane.closest_point(Point3D::new([7.0, 3.0, 4.0])), Point3D::new([7.0, 1.2, 1.6]), epsilon = 1e-12);
+            assert_approx_eq!(plane.project_vector(Vector3D::new([1.0, 3.0, 4.0])), Vector3D::new([1.0, 0.0, 0.0]));
+
+            let line = Line3D::<f64, ()>::from_points(Point3D::new([1.0, 1.0, 0.0]), Point3D::new([3.0, 1.0, 0.0]));
+            assert_approx_eq!(line.closest_parameter(Point3D::new([4.0, 5.0, 0.0])), 1.5);
+            assert_approx_eq!(line.closest_point(Point3D::new([-4.0, 5.0, 3.0])), Point3D::new([-4.0, 1.0, 0.0]));
+            assert_approx_eq!(line.distance_to(Point3D::new([-4.0, 5.0, 3.0])), 5.0);
+
+            let ray = Ray3D { origin: line.point, direction: line.direction };
+            assert_approx_eq!(ray.closest_point(Point3D::new([-4.0, 5.0, 3.0])), Point3D::new([1.0, 1.0, 0.0]));
+            assert_approx_eq!(ray.distance_to(Point3D::new([5.0, 4.0, 1.0])), 10.0f64.sqrt());
+
+            let bounded = Segment3D { start: Point3D::<f64, ()>::new([1.0, 1.0, 0.0]), end: Point3D::new([3.0, 1.0, 0.0]) };
+            assert_approx_eq!(bounded.length(), 2.0);
+            assert_approx_eq!(bounded.closest_parameter(Point3D::new([2.5, -1.0, 0.0])), 0.75);
+            assert_approx_eq!(bounded.closest_point(Point3D::new([7.0, 4.0, 0.0])), Point3D::new([3.0, 1.0, 0.0]));
+            assert_approx_eq!(bounded.distance_to(Point3D::new([7.0, 4.0, 0.0])), 5.0);
+            assert_approx_eq!(Segment3D { start: Point3D::<f64, ()>::new([1.0; 3]), end: Point3D::new([1.0; 3]) }.distance_to(Point3D::new([1.0, 1.0, 3.0])), 2.0);
+        }
+
+        #[test]
+        pub fn closest_points() {
+            // Skew lines along X at z = 0, and along Y at z = 2
+            let first = Line3D { point: Point3D::<f64, ()>::new([-5.0, 1.0, 0.0]), direction: Vector3D::new([2.0, 0.0, 0.0]) };
+            let second = Line3D { point: Point3D::new([3.0, 4.0, 2.0]), direction: Vector3D::new([0.0, -1.0, 0.0]) };
+            let (t, u) = first.closest_parameters(second).unwrap();
+            assert_approx_eq!([t, u], [4.0, 3.0]);
+            assert_approx_eq!(first.at(t), Point3D::new([3.0, 1.0, 0.0]));
+            assert_approx_eq!(first.distance_to_line(second), 2.0);
+            let parallel = Line3D { point: Point3D::new([0.0, 4.0, 4.0]), direction: Vector3D::new([-1.0, 0.0, 0.0]) };
+            assert!(first.closest_parameters(parallel).is_none());
+            assert_approx_eq!(first.distance_to_line(parallel), 5.0);
+            let degenerate = Line3D { point: Point3D::<f64, ()>::new([0.0, 0.0, 1.0]), direction: Vector3D::new([0.0; 3]) };
+            let axis = Line3D { point: Point3D::new([5.0, 0.0, 0.0]), direction: Vector3D::new([1.0, 0.0, 0.0]) };
+            assert_approx_eq!(degenerate.distance_to_line(axis), 1.0);
+            assert_approx_eq!(axis.distance_to_line(degenerate), 1.0);
+
+            // Segments whose lines cross, but which are closest at an end point
+            let a = Segment3D { start: Point3D::<f64, ()>::new([0.0, 0.0, 0.0]), end: Point3D::new([2.0, 0.0, 0.0]) };
+            assert_approx_eq!(a.distance_to_segment(Segment3D { start: Point3D::new([1.0, -1.0, 1.0]), end: Point3D::new([1.0, 1.0, 1.0]) }), 1.0);
+            let (t, u) = a.closest_parameters(Segment3D { start: Point3D::new([4.0, -1.0, 1.0]), end: Point3D::new([4.0, 1.0, 1.0]) });
+            assert_approx_eq!([t, u], [1.0, 0.5]);
+            let (t, u) = a.closest_parameters(Segment3D { start: Point3D::new([1.0, 2.0, 0.0]), end: Point3D::new([1.0, 5.0, 0.0]) });
+            assert_approx_eq!([t, u], [0.5, 0.0]);
+            assert_approx_eq!(a.distance_to_segment(Segment3D { start: Point3D::new([-3.0, -1.0, 0.0]), end: Point3D::new([-1.0, 3.0, 0.0]) }), 5.0f64.sqrt());
+            // Parallel and overlapping, and parallel and disjoint
+            assert_approx_eq!(a.distance_to_segment(Segment3D { start: Point3D::new([1.0, 3.0, 0.0]), end: Point3D::new([5.0, 3.0, 0.0]) }), 3.0);
+            assert_approx_eq!(a.distance_to_segment(Segment3D { start: Point3D::new([6.0, 3.0, 0.0]), end: Point3D::new([5.0, 3.0, 0.0]) }), 18.0f64.sqrt());
+            // Degenerate segments
+            assert_approx_eq!(a.distance_to_segment(Segment3D { start: Point3D::new([1.0, 2.0, 0.0]), end: Point3D::new([1.0, 2.0, 0.0]) }), 2.0);
+            assert_approx_eq!(Segment3D { start: Point3D::new([1.0, 2.0, 0.0]), end: Point3D::new([1.0, 2.0, 0.0]) }.distance_to_segment(a), 2.0);
+        }
+
+        #[test]
+        pub fn plane_intersections() {
+            let plane = Plane3D::<f64, ()>::new(Vector3D::new([0.0, 0.0, 1.0]), 2.0).unwrap();
+            let line = Line3D { point: Point3D::new([1.0, 1.0, -2.0]), direction: Vector3D::new([1.0, 0.0, 2.0]) };
+            assert_approx_eq!(plane.line_intersection(line).unwrap(), 2.0);
+            assert!(plane.line_intersection(Line3D { direction: Vector3D::new([1.0, 1.0, 0.0]), ..line }).is_none());
+            assert_approx_eq!(plane.ray_intersection(Ray3D { origin: line.point, direction: line.direction }).unwrap(), 2.0);
+            assert!(plane.ray_intersection(Ray3D { origin: line.point, direction: -line.direction }).is_none());
+
+            assert_approx_eq!(plane.segment_intersection(Segment3D { start: Point3D::new([0.0, 0.0, 0.0]), end: Point3D::new([0.0, 0.0, 8.0]) }).unwrap(), 0.25);
+            assert_approx_eq!(plane.segment_intersection(Segment3D { start: Point3D::new([0.0, 0.0, 8.0]), end: Point3D::new([0.0, 0.0, 0.0]) }).unwrap(), 0.75);
+            assert_approx_eq!(plane.segment_intersection(Segment3D { start: Point3D::new([0.0, 0.0, 2.0]), end: Point3D::new([0.0, 0.0, 3.0]) }).unwrap(), 0.0);
+            assert!(plane.segment_intersection(Segment3D { start: Point3D::new([0.0, 0.0, 2.5]), end: Point3D::new([0.0, 0.0, 3.0]) }).is_none());
+            assert!(plane.segment_intersection(Segment3D { start: Point3D::new([0.0, 0.0, 2.0]), end: Point3D::new([1.0, 0.0, 2.0]) }).is_none());
+
+            let wall = Plane3D::<f64, ()>::new(Vector3D::new([1.0, 1.0, 0.0]), 2.0).unwrap();
+            let crossing = plane.plane_intersection(wall).unwrap();
+            assert!(crossing.at(0.0) != crossing.at(1.0));
+            for t in [-3.0, 0.0, 5.0] {
+                assert_approx_eq!(plane.signed_distance(crossing.at(t)), 0.0, epsilon = 1e-12);
+                assert_approx_eq!(wall.signed_distance(crossing.at(t)), 0.0, epsilon = 1e-12);
+            }
+            assert!(plane.plane_intersection(plane.flip()).is_none());
+
+            let side = Plane3D::<f64, ()>::new(Vector3D::new([0.0, 1.0, 0.0]), -1.0).unwrap();
+            assert_approx_eq!(Plane3D::three_plane_intersection(plane, wall, side).unwrap(), Point3D::new([3.0, -1.0, 2.0]));
+            assert_approx_eq!(Plane3D::three_plane_intersection(side, wall, plane).unwrap(), Point3D::new([3.0, -1.0, 2.0]));
+            // Three planes sharing a line, and two parallel planes
+            let diagonal = Plane3D::new(Vector3D::new([1.0, 1.0, 1.0]), 4.0).unwrap();
+            assert!(Plane3D::three_plane_intersection(plane, wall, diagonal).is_none());
+            assert!(Plane3D::three_plane_intersection(plane, wall, plane.flip()).is_none());
+        }
+    }
+}
+
+mod geometry2d {
+    mod transform {
+        use crate::geometry2d::{Point2D, Rotation2D, Vector2D};
+        use crate::geometry2d::transform::{Affine2D, Isometry2D, Similarity2D};
+        use crate::matrix::MatrixMN;
+        use crate::vector::VectorN;
+
+        #[test]
+        pub fn rotation() {
+            let quarter = Rotation2D::<f64, ()>::from_angle(std::f64::consts::FRAC_PI_2);
+            assert_approx_eq!(quarter.apply(Vector2D::new([1.0, 0.0])), Vector2D::new([0.0, 1.0]), epsilon = 1e-12);
+            assert_approx_eq!((quarter * quarter).apply(Vector2D::new([1.0, 0.0])), Vector2D::new([-1.0, 0.0]), epsilon = 1e-12);
+            assert_approx_eq!((quarter * quarter.inverse()).apply(Vector2D::new([1.0, 2.0])), Vector2D::new([1.0, 2.0]), epsilon = 1e-12);
+            assert!((Rotation2D::<f64, ()>::from_angle(-2.5).angle() + 2.5).abs() < 1e-12);
+        }
+
+        #[test]
+        pub fn composition_and_inverse() {
+            let point = Point2D::new([0.3, -1.2]);
+            let a = Isometry2D::<f64, ()>::new(Rotation2D::from_angle(0.7), Vector2D::new([1.0, -2.0]));
+            let b = Isometry2D::<f64, ()>::new(Rotation2D::from_angle(-2.0), Vector2D::new([0.0, 3.0]));
+            assert_eq!(Isometry2D::<f64, ()>::from_translation(Vector2D::new([1.0, 2.0])).transform_vector(point.to_vector()), point.to_vector());
+            assert_approx_eq!((a * b).transform_point(point), a.transform_point(b.transform_point(point)), epsilon = 1e-12);
+            assert_approx_eq!(a.inverse().transform_point(a.transform_point(point)), point, epsilon = 1e-12);
+
+            let c = Similarity2D::new(2.0, a.rotation, a.translation);
+            assert_approx_eq!((c * Similarity2D::from(b)).transform_point(point), c.transform_point(b.transform_point(point)), epsilon = 1e-12);
+            assert_approx_eq!(c.inverse().unwrap().transform_point(c.transform_point(point)), point, epsilon = 1e-12);
+
+            let d = Affine2D::new(MatrixMN::from_row_major([[1.0, 0.5], [0.0, 2.0]]), Vector2D::new([1.0, 0.0]));
+            assert_approx_eq!(Affine2D::from(c).transform_point(point), c.transform_point(point), epsilon = 1e-12);
+            assert_approx_eq!((d * Affine2D::from(a)).transform_point(point), d.transform_point(a.transform_point(point)), epsilon = 1e-12);
+            assert_approx_eq!(d.inverse().unwrap().transform_point(d.transform_point(point)), point, epsilon = 1e-12);
+
+            let [x, y] = point.to_array();
+            let [hx, hy, hw] = (c.to_homogeneous() * VectorN::new([x, y, 1.0])).to_array();
+            assert_approx_eq!(Point2D::new([hx, hy]), c.transform_point(point), epsilon = 1e-12);
+            assert_eq!(hw, 1.0);
+        }
+    }
+
+    mod circle {
+        use std::f64::consts::PI;
+        use crate::geometry2d::Point2D;
+        use crate::geometry2d::shapes::{Circle, InvalidCircleError};
+        use crate::utility::SplitMix64;
+
+        #[test]
+        pub fn measurements() {
+            let target = Circle::<f64, ()>::new(Point2D::new([1.0, -1.0]), 2.0).unwrap();
+            assert_approx_eq!(target.area(), 4.0 * PI);
+            assert_approx_eq!(target.circumference(), 4.0 * PI);
+            assert!(target.contains(Point2D::new([3.0, -1.0])));
+            assert!(!target.contains(Point2D::new([2.5, 0.5])));
+            assert_eq!(Circle::<f64, ()>::new(Point2D::new([0.0, 0.0]), -1.0), Err(InvalidCircleError::NegativeRadius));
+            assert_eq!(Circle::<f64, ()>::new(Point2D::new([f64::NAN, 0.0]), 1.0), Err(InvalidCircleError::NonFinite));
+        }
+
+        #[test]
+        pub fn minimum_enclosing() {
+            // Obtuse triangles are enclosed by the circle on their longest side, acute ones by their circumcircle
+            let obtuse = [[0.0, 0.0], [4.0, 0.0], [1.0, 1.0]].map(Point2D::new);
+            assert_approx_eq!(Circle::minimum_enclosing(&obtuse).unwrap(), Circle::<f64, ()>::new(Point2D::new([2.0, 0.0]), 2.0).unwrap());
+            let acute = [[-3.0, -4.0], [3.0, -4.0], [0.0, 5.0]].map(Point2D::new);
+            assert_approx_eq!(Circle::minimum_enclosing(&acute).unwrap(), Circle::<f64, ()>::new(Point2D::new([0.0, 0.0]), 5.0).unwrap());
+            let square = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0], [1.0, 1.5]].map(Point2D::new);
+            assert_approx_eq!(Circle::minimum_enclosing(&square).unwrap(), Circle::<f64, ()>::new(Point2D::new([1.0, 1.0]), 2.0f64.sqrt()).unwrap());
+            let collinear = [1.0, -2.0, 0.5, 3.0].map(|t| Point2D::new([t, -t]));
+            assert_approx_eq!(Circle::minimum_enclosing(&collinear).unwrap(), Circle::<f64, ()>::new(Point2D::new([0.5, -0.5]), 12.5f64.sqrt()).unwrap());
+            assert!(Circle::<f64, ()>::minimum_enclosing(&[]).is_none());
+
+            let mut random = SplitMix64::new(11);
+            let points: Vec<_> = (0..1000).map(|_| Point2D::<f64, ()>::new(std::array::from_fn(|_| random.uniform(-1.0, 1.0)))).collect();
+            let minimum = Circle::minimum_enclosing(&points).unwrap();
+            assert!(points.iter().all(|&point| (point - minimum.center).magnitude() <= minimum.radius + 1e-12));
+            assert_approx_eq!(Circle::minimum_enclosing_seeded(&points, 99).unwrap(), minimum, epsilon = 1e-9);
+            let approximate = Circle::approximate_enclosing(&points).unwrap();
+            assert!(points.iter().all(|&point| (point - approximate.center).magnitude() <= approximate.radius + 1e-12));
+            assert!(approximate.radius >= minimum.radius && approximate.radius <= 1.2 * minimum.radius);
+        }
+    }
+}
+
+mod approx {
+    use crate::angle::Angle;
+    use crate::approx::ApproxEq;
+    use crate::geometry3d::{Point3D, RotationMatrix, Vector3D};
+    use crate::geometry3d::shapes::Sphere;
+    use crate::shapes::triangle::Triangle;
+
+    #[test]
+    pub fn scalars() {
+        assert!(approx_eq!(0.1 + 0.2, 0.3));
+        assert!(!approx_eq!(1.0, 1.0 + 1e-9));
+        assert!(approx_eq!(1.0, 1.0 + 1e-9, epsilon = 1e-8));
+        assert!(approx_eq!(1e20, 1e20 + 1e6, max_relative = 1e-12));
+        assert!(!approx_eq!(1e20, 1e20 + 1e6, epsilon = 1e-8));
+        assert!(approx_eq!(1.0f32, 1.0f32 + 2.0 * f32::EPSILON, max_ulps = 2));
+        assert!(!approx_eq!(1.0f64, 1.0 + 3.0 * f64::EPSILON, epsilon = 0.0, max_ulps = 2));
+
+        assert!(approx_eq!(f64::INFINITY, f64::INFINITY));
+        assert!(!approx_eq!(f64::NAN, f64::NAN));
+        assert!(!approx_eq!(f64::INFINITY, f64::MAX));
+        assert!(!approx_eq!(-1e-300, 1e-300, epsilon = 0.0, max_ulps = 4));
+        assert!(0.0f64.ulps_eq(-0.0, 0.0, 0));
+    }
+
+    #[test]
+    pub fn geometry() {
+        let rotation = RotationMatrix::<f64, ()>::from_axis_angle(Vector3D::new([1.0, 2.0, 3.0]), 0.4);
+        assert_approx_eq!(rotation * rotation.inverse(), RotationMatrix::identity(), epsilon = 1e-15);
+        assert_approx_ne!(rotation, RotationMatrix::identity());
+
+        let sphere = Sphere { center: Point3D::<f64, ()>::new([0.1 + 0.2, 0.0, 0.0]), radius: 1.0 };
+        assert_approx_eq!(sphere, Sphere { center: Point3D::new([0.3, 0.0, 0.0]), radius: 1.0 });
+        assert_approx_ne!(sphere, Sphere { center: Point3D::new([0.3, 0.0, 0.0]), radius: 1.1 });
+
+        let triangle = Triangle::new(Point3D::<f64, ()>::new([0.0, 0.0, 0.0]), Point3D::new([1.0, 0.0, 0.0]), Point3D::new([0.0, 1.0, 0.0])).unwrap();
+        let shifted = Triangle::new(Point3D::new([0.0, 1e-10, 0.0]), Point3D::new([1.0, 0.0, 0.0]), Point3D::new([0.0, 1.0, 0.0])).unwrap();
+        assert_approx_eq!(triangle, shifted, epsilon = 1e-9);
+        assert_approx_ne!(triangle, shifted);
+
+        let abstract_triangle = abstract_triangle!{ a: 3.0, b: 4.0, gamma: Angle::from_degrees(90.0) }.unwrap();
+        assert_approx_eq!(abstract_triangle, abstract_triangle!{ a: 3.0, b: 4.0, gamma: Angle::from_radians(std::f64::consts::FRAC_PI_2) }.unwrap());
+        assert_approx_ne!(abstract_triangle, abstract_triangle!{ a: 3.0, b: 4.0, gamma: Angle::from_degrees(90.1) }.unwrap());
+    }
+}
+
+mod angle {
+    use std::f64::consts::{FRAC_PI_2, PI};
+    use crate::angle::Angle;
+    use crate::shapes::triangle::InvalidTriangleError;
+
+    #[test]
+    pub fn units() {
+        let angle = Angle::<f64>::from_degrees(90.0);
+        assert_eq!(angle.radians(), FRAC_PI_2);
+        assert_approx_eq!(Angle::from_turns(0.25), angle, epsilon = 1e-12);
+        assert_approx_eq!(Angle::from_gradians(100.0), angle, epsilon = 1e-12);
+        assert!((angle.degrees() - 90.0).abs() < 1e-12);
+        assert!((angle.turns() - 0.25).abs() < 1e-12);
+        assert!((angle.gradians() - 100.0).abs() < 1e-12);
+        assert_eq!(Angle::<f64>::STRAIGHT.radians(), PI);
+    }
+
+    #[test]
+    pub fn normalization() {
+        assert_eq!(Angle::<f64>::from_radians(1.0).normalized(), Angle::from_radians(1.0));
+        assert_approx_eq!(Angle::from_degrees(-90.0).normalized(), Angle::from_degrees(270.0), epsilon = 1e-12);
+        assert_approx_eq!(Angle::from_degrees(720.0 + 45.0).normalized(), Angle::from_degrees(45.0), epsilon = 1e-12);
+        assert_eq!(Angle::<f64>::from_turns(1.0).normalized(), Angle::ZERO);
+        assert_eq!(Angle::<f64>::from_radians(-1e-20).normalized(), Angle::ZERO);
+
+        assert_approx_eq!(Angle::from_degrees(270.0).normalized_signed(), Angle::from_degrees(-90.0), epsilon = 1e-12);
+        assert_eq!(Angle::<f64>::STRAIGHT.normalized_signed(), Angle::STRAIGHT);
+        assert_eq!((-Angle::<f64>::STRAIGHT).normalized_signed(), Angle::STRAIGHT);
+        assert_approx_eq!(Angle::from_degrees(-540.0 - 30.0).normalized_signed(), Angle::from_degrees(150.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    pub fn trigonometry_and_arithmetic() {
+        let angle = Angle::<f64>::from_degrees(30.0);
+        assert!((angle.sin() - 0.5).abs() < 1e-12);
+        assert!((angle.cos() - 0.75f64.sqrt()).abs() < 1e-12);
+        assert!((angle.tan() - 1.0 / 3.0f64.sqrt()).abs() < 1e-12);
+        assert_approx_eq!(Angle::asin(0.5).unwrap(), angle, epsilon = 1e-12);
+        assert_approx_eq!(Angle::acos(0.5).unwrap(), Angle::from_degrees(60.0), epsilon = 1e-12);
+        assert_eq!(Angle::<f64>::acos(1.5), None);
+        assert_approx_eq!(Angle::atan2(-1.0, -1.0), Angle::from_degrees(-135.0), epsilon = 1e-12);
+
+        assert_approx_eq!(angle + angle, Angle::from_degrees(60.0), epsilon = 1e-12);
+        assert_approx_eq!(Angle::STRAIGHT - angle, Angle::from_degrees(150.0), epsilon = 1e-12);
+        assert_approx_eq!(angle * 3.0, Angle::from_degrees(90.0), epsilon = 1e-12);
+        assert_approx_eq!(angle / 3.0, Angle::from_degrees(10.0), epsilon = 1e-12);
+        assert!((Angle::STRAIGHT / angle - 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    pub fn triangle_solver() {
+        assert_eq!(abstract_triangle!{ a: 1.0, b: 2.0, alpha: Angle::from_degrees(200.0) }.unwrap_err(), InvalidTriangleError::InvalidAngle);
+        assert_eq!(abstract_triangle!{ a: 1.0, alpha: Angle::from_degrees(100.0), beta: Angle::from_degrees(80.0) }.unwrap_err(), InvalidTriangleError::InvalidAngle);
+        assert!(abstract_triangle!{ a: 1.0, alpha: Angle::from_degrees(100.0), beta: Angle::from_degrees(79.0) }.is_ok());
+    }
+}
+
+mod basis {
+    use crate::basis::{Basis, ConvertBasis, Handedness, SignedAxis, YUpLeftHanded, YUpRightHanded, ZUpRightHanded};
+    use crate::geometry3d::{Point3D, RotationMatrix, Vector3D};
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    struct Invalid;
+
+    impl Basis<3> for Invalid {
+        const AXES: Option<[SignedAxis; 3]> = Some([SignedAxis::positive(0), SignedAxis::positive(0), SignedAxis::positive(1)]);
+    }
+
+    #[test]
+    pub fn handedness() {
+        assert_eq!(<YUpRightHanded as Basis<3>>::HANDEDNESS, Some(Handedness::Right));
+        assert_eq!(<ZUpRightHanded as Basis<3>>::HANDEDNESS, Some(Handedness::Right));
+        assert_eq!(<YUpLeftHanded as Basis<3>>::HANDEDNESS, Some(Handedness::Left));
+        assert_eq!(<() as Basis<3>>::HANDEDNESS, None);
+        assert_eq!(<Invalid as Basis<3>>::HANDEDNESS, None);
+
+        assert_eq!(ConvertBasis::<(), YUpRightHanded, 3>::new(), None);
+        assert_eq!(ConvertBasis::<Invalid, YUpRightHanded, 3>::new(), None);
+        assert!(!ConvertBasis::<ZUpRightHanded, YUpRightHanded, 3>::new().unwrap().flips_handedness());
+        assert!(ConvertBasis::<ZUpRightHanded, YUpLeftHanded, 3>::new().unwrap().flips_handedness());
+    }
+
+    #[test]
+    pub fn conversion() {
+        let convert = ConvertBasis::<ZUpRightHanded, YUpLeftHanded, 3>::new().unwrap();
+        // Right, forward, up
+        assert_eq!(convert.apply(Vector3D::new([1.0, 2.0, 3.0])), [1.0, 3.0, 2.0]);
+        assert_eq!(convert.apply_point(Point3D::new([1.0, -2.0, 3.0])), [1.0, 3.0, -2.0]);
+        assert_eq!(convert.inverse().apply(convert.apply(Vector3D::new([4.0, 5.0, 6.0]))), [4.0, 5.0, 6.0]);
+        assert_eq!(convert.to_row_major::<f64>(), [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]]);
+
+        let convert = ConvertBasis::<ZUpRightHanded, YUpRightHanded, 3>::new().unwrap();
+        assert_eq!(convert.apply(Vector3D::new([1.0, 2.0, 3.0])), [1.0, 3.0, -2.0]);
+        assert_eq!(convert.to_row_major::<f64>(), [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, -1.0, 0.0]]);
+    }
+
+    #[test]
+    pub fn cross_product() {
+        let convert = ConvertBasis::<ZUpRightHanded, YUpLeftHanded, 3>::new().unwrap();
+        let (a, b) = (Vector3D::<f64, ZUpRightHanded>::new([1.0, 2.0, 3.0]), Vector3D::new([-2.0, 0.5, 4.0]));
+        assert_eq!(convert.apply(a.cross_product(b)), convert.apply(a).cross_product(convert.apply(b)));
+
+        // Right × up is backward, by the right-hand rule
+        let (right, up) = (Vector3D::<f64, YUpLeftHanded>::new([1.0, 0.0, 0.0]), Vector3D::new([0.0, 1.0, 0.0]));
+        assert_eq!(right.cross_product(up), [0.0, 0.0, -1.0]);
+        assert_eq!(Vector3D::<f64, ()>::new([1.0, 0.0, 0.0]).cross_product(Vector3D::new([0.0, 1.0, 0.0])), [0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    pub fn rotation() {
+        let convert = ConvertBasis::<ZUpRightHanded, YUpLeftHanded, 3>::new().unwrap();
+        let rotation = RotationMatrix::<f64, ZUpRightHanded>::from_axis_angle(Vector3D::new([1.0, -2.0, 0.5]), 0.8);
+        let converted = convert.apply_rotation(rotation);
+        let vector = Vector3D::new([0.3, 1.5, -2.0]);
+        let (expected, actual) = (convert.apply(rotation.apply(vector)), converted.apply(convert.apply(vector)));
+        assert!(expected.into_iter().zip(actual).all(|(e, a)| (e - a).abs() < 1e-12), "{} != {}", expected, actual);
+        assert!(converted.is_orthonormal(1e-12));
+    }
+}
+
+mod scalar {
+    use crate::scalar::{Ring, Scalar};
+
+    fn generic_signed_angle<T: Scalar>(y: T, x: T) -> T {
+        y.atan2(x)
+    }
+
+    #[test]
+    pub fn functions() {
+        assert_eq!(generic_signed_angle(1.0f64, -1.0), 0.75 * std::f64::consts::PI);
+        assert_eq!(generic_signed_angle(-1.0f32, -1.0), -0.75 * std::f32::consts::PI);
+        assert_eq!(Scalar::hypot(3e300f64, 4e300), 5e300);
+        assert_eq!(Scalar::log(8.0f64, 2.0), 3.0);
+        assert_eq!(Scalar::ln(f64::exp(1.0)), 1.0);
+        assert_eq!((Scalar::floor(-1.5f64), Scalar::ceil(-1.5f64)), (-2.0, -1.0));
+        assert_eq!((Scalar::signum(-3.0f64), Scalar::abs(-3.0f64)), (-1.0, 3.0));
+        assert_eq!((Scalar::min(1.0f64, 2.0), Scalar::max(1.0f64, 2.0)), (1.0, 2.0));
+        assert_eq!(<f64 as Ring>::ONE + <f64 as Scalar>::EPSILON, 1.0 + f64::EPSILON);
+    }
+
+    mod dual {
+        use crate::angle::Angle;
+        use crate::geometry2d::Point2D;
+        use crate::scalar::dual::{Dual, DualN};
+        use crate::scalar::{Ring, Scalar};
+        use crate::shapes::triangle::{law_of_cosines, AbstractTriangle, Triangle};
+
+        /// Compares the dual number derivative of `f` at `x` against a central finite difference
+        fn assert_derivative(f: impl Fn(Dual<f64>) -> Dual<f64>, x: f64) {
+            let h = 1e-6;
+            let expected = (f(Dual::constant(x + h)).value() - f(Dual::constant(x - h)).value()) / (2.0 * h);
+            let [variable] = Dual::variables([x]);
+            let found = f(variable).derivative();
+            assert!((found - expected).abs() < 1e-6 * expected.abs().max(1.0), "Derivative at {} is {}, expected {}", x, found, expected);
+        }
+
+        #[test]
+        pub fn derivative_rules() {
+            for x in [0.3, 0.7, 1.9] {
+                assert_derivative(|x| x * x / (x + Dual::i(1)) - x, x);
+                assert_derivative(Dual::sqrt, x);
+                assert_derivative(|x| x.pow(x), x);
+                assert_derivative(|x| Dual::f(2.5).pow(x), x);
+                assert_derivative(|x| x.powi(3), x);
+                assert_derivative(|x| x.powf(-1.5), x);
+                assert_derivative(Dual::sin, x);
+                assert_derivative(Dual::cos, x);
+                assert_derivative(Dual::tan, x);
+                assert_derivative(Dual::atan, x);
+                assert_derivative(|x| (x / Dual::i(2)).acos().unwrap(), x);
+                assert_derivative(|x| (x / Dual::i(2)).asin().unwrap(), x);
+                assert_derivative(|x| x.atan2(Dual::f(-0.5) * x + Dual::i(1)), x);
+                assert_derivative(Dual::exp, x);
+                assert_derivative(Dual::ln, x);
+                assert_derivative(|x| x.log(x + Dual::i(2)), x);
+                assert_derivative(|x| (-x).abs(), x);
+                assert_derivative(|x| x.min(x * x), x);
+                assert_derivative(|x| x.max(x * x), x);
+                assert_derivative(|x| x.hypot(Dual::i(2) * x + Dual::i(1)), x);
+            }
+
+            let [x] = Dual::variables([1.5f64]);
+            assert_eq!((x.floor().value(), x.floor().derivative()), (1.0, 0.0));
+            assert_eq!((x.signum().value(), x.signum().derivative()), (1.0, 0.0));
+            assert_eq!(x.powi(0).derivative(), 0.0);
+            assert!(Dual::<f64>::f(2.0).acos().is_none());
+            assert!(x > Dual::ONE && x == Dual::new(1.5, [-7.0]));
+            // Unlike equality, approximate equality considers the derivatives
+            assert_approx_ne!(x, Dual::new(1.5, [-7.0]));
+            assert_approx_eq!(x * x, Dual::new(2.25, [3.0]), max_ulps = 1);
+        }
+
+        #[test]
+        pub fn triangle_area_gradient() {
+            // Area of a triangle with base along the X axis is half the base length times the height of the apex
+            let [cx, cy] = DualN::<f64, 2>::variables([0.6, 2.0]);
+            let triangle: Triangle<_, 2, ()> = Triangle::new(
+                Point2D::new([DualN::constant(0.0), DualN::constant(0.0)]),
+                Point2D::new([DualN::constant(3.0), DualN::constant(0.0)]),
+                Point2D::new([cx, cy])
+            ).unwrap();
+            assert_approx_eq!(triangle.area(), DualN::new(3.0, [0.0, 1.5]), epsilon = DualN::constant(1e-12));
+        }
+
+        #[test]
+        pub fn law_of_cosines_sensitivity() {
+            let [a, b, gamma] = DualN::<f64, 3>::variables([3.0, 4.0, std::f64::consts::FRAC_PI_2]);
+            let c = law_of_cosines::c_from_abγ(a, b, Angle::from_radians(gamma)).unwrap();
+            // ∂c/∂a = (a - b cos γ) / c, ∂c/∂b = (b - a cos γ) / c, ∂c/∂γ = a b sin γ / c
+            assert_approx_eq!(c, DualN::new(5.0, [0.6, 0.8, 2.4]), epsilon = DualN::constant(1e-12));
+        }
+    }
+
+    mod rational {
+        use crate::geometry2d::{Point2D, Vector2D};
+        use crate::geometry3d::{Point3D, Vector3D};
+        use crate::scalar::{Field, Rational, Ring};
+
+        #[test]
+        pub fn arithmetic() {
+            let (half, third) = (Rational::<i128>::new(1, 2).unwrap(), Rational::<i128>::new(-2, -6).unwrap());
+            assert_eq!((third.numer(), third.denom()), (1, 3));
+            assert_eq!(Rational::<i128>::new(3, -6).unwrap(), -half);
+            assert_eq!(half + third, Rational::<i128>::new(5, 6).unwrap());
+            assert_eq!(half - third, Rational::<i128>::new(1, 6).unwrap());
+            assert_eq!(half * third, Rational::<i128>::new(1, 6).unwrap());
+            assert_eq!(half / third, Rational::<i128>::new(3, 2).unwrap());
+            assert_eq!(third.recip(), Some(Rational::from(3)));
+            assert_eq!(Rational::<i128>::ZERO.recip(), None);
+            assert_eq!(Rational::<i128>::new(1, 0), None);
+            assert_eq!(Rational::<i128>::new(i128::MIN, i128::MIN), Some(Rational::ONE));
+            assert_eq!(Rational::<i128>::new(i128::MIN, 2), Some(Rational::from(i128::MIN / 2)));
+            assert_eq!(Rational::<i128>::new(i128::MIN, 1), Some(Rational::from(i128::MIN)));
+            assert_eq!(Rational::<i128>::new(-2, i128::MIN).map(|ratio| (ratio.numer(), ratio.denom())), Some((1, 1 << 126)));
+            assert_eq!(Rational::<i128>::new(i128::MIN, -1), None);
+            assert_eq!(Rational::<i128>::new(1, i128::MIN), None);
+            assert_eq!((Rational::<i128>::new(-7, 2).unwrap().floor(), Rational::<i128>::new(-7, 2).unwrap().abs()), (-4, Rational::<i128>::new(7, 2).unwrap()));
+            assert_eq!(format!("{} {}", Rational::<i128>::new(-7, 2).unwrap(), Rational::<i64>::i(4)), "-7/2 4");
+
+            // Values which cannot be cross-multiplied without overflow
+            let large = Rational::<i128>::new(i128::MAX, i128::MAX - 1).unwrap();
+            let larger = Rational::<i128>::new(i128::MAX - 1, i128::MAX - 2).unwrap();
+            assert!(large < larger && -larger < -large && large > Rational::ONE);
+            assert_eq!(large - large, Rational::ZERO);
+            assert_eq!(large * large.recip().unwrap(), Rational::ONE);
+
+            // Approximate equality is exact unless given a tolerance
+            assert_approx_eq!(half + third, Rational::<i128>::new(5, 6).unwrap(), max_ulps = 4);
+            assert_approx_ne!(large, Rational::ONE);
+            assert_approx_eq!(large, Rational::ONE, epsilon = Rational::<i128>::new(1, 1 << 100).unwrap());
+            assert_approx_eq!(Rational::from(1000), Rational::<i128>::from(1001), max_relative = Rational::<i128>::new(1, 1000).unwrap());
+            assert_approx_ne!(Rational::from(1000), Rational::<i128>::from(1002), max_relative = Rational::<i128>::new(1, 1000).unwrap());
+        }
+
+        #[test]
+        #[should_panic(expected = "rational arithmetic overflowed")]
+        pub fn overflow() {
+            let _ = Rational::from(i128::MAX) + Rational::ONE;
+        }
+
+        /// Generic over [`Field`]; Only exact for exact types
+        fn solve_2x2<T: Field>([[a, b], [c, d]]: [[T; 2]; 2], [x, y]: [T; 2]) -> [T; 2] {
+            let determinant = a * d - b * c;
+            [(x * d - b * y) / determinant, (a * y - x * c) / determinant]
+        }
+
+        #[test]
+        pub fn exact_predicates() {
+            // Orientation of integer points, through the cross product of their offsets
+            let [a, b, c] = [[0i64, 0], [1_000_000_007, 1], [2_000_000_014, 2]].map(Point2D::<i64, ()>::new);
+            let (ab, ac) = (b - a, c - a);
+            let [ab_x, ab_y] = ab.to_array();
+            let [ac_x, ac_y] = ac.to_array();
+            assert_eq!(ab_x * ac_y - ab_y * ac_x, 0);
+            assert_eq!(ab.dot(ac), 2 * (1_000_000_007i64.pow(2) + 1));
+            assert_eq!(ab * 2 - ac, Vector2D::new([0, 0]));
+
+            let normal = Vector3D::<i128, ()>::new([1, 2, 3]).cross_product(Vector3D::new([4, 5, 6]));
+            assert_eq!(normal, [-3, 6, -3]);
+            assert_eq!(normal.dot(Vector3D::new([1, 2, 3])), 0);
+            let point = Point3D::<i32, ()>::origin() + Vector3D::new([1, 2, 3]);
+            assert_eq!(point - Point3D::new([1, 1, 1]), Vector3D::new([0, 1, 2]));
+
+            let centroid = Point2D::<Rational<i128>, ()>::centroid(&[
+                Point2D::new([Rational::from(0), Rational::from(0)]),
+                Point2D::new([Rational::from(1), Rational::from(0)]),
+                Point2D::new([Rational::from(0), Rational::from(1)]),
+            ]).unwrap();
+            assert_eq!(centroid, [Rational::<i128>::new(1, 3).unwrap(), Rational::<i128>::new(1, 3).unwrap()]);
+            assert_eq!(solve_2x2([[Rational::from(2), Rational::from(1)], [Rational::from(1), Rational::from(3)]], [Rational::from(1), Rational::from(0)]), [Rational::<i128>::new(3, 5).unwrap(), Rational::<i128>::new(-1, 5).unwrap()]);
+        }
+    }
+
+    mod fixed {
+        use crate::geometry3d::{RotationMatrix, Vector3D};
+        use crate::scalar::fixed::{Q16_16, Q32_32};
+        use crate::scalar::{Ring, Scalar};
+        use crate::shapes::triangle::{AbstractTriangle, AbstractTriangle_abc};
+
+        const ULP: f64 = 1.0 / 4294967296.0;
+
+        /// Fixed-point types checked against `f64`, with the size of their unit in the last place
+        trait Reference: Scalar {
+            const ULP: f64;
+            fn to_f64(self) -> f64;
+        }
+
+        impl Reference for Q32_32 {
+            const ULP: f64 = ULP;
+            fn to_f64(self) -> f64 { Q32_32::to_f64(self) }
+        }
+
+        impl Reference for Q16_16 {
+            const ULP: f64 = 1.0 / 65536.0;
+            fn to_f64(self) -> f64 { Q16_16::to_f64(self) }
+        }
+
+        /// Checks `f` against the `f64` reference `g` at `x`, within `ulps` units in the last place plus a relative error of `relative`
+        fn assert_conforms<T: Reference>(f: impl Fn(T) -> T, g: impl Fn(f64) -> f64, x: f64, ulps: f64, relative: f64) {
+            let (found, expected) = (f(T::f(x)).to_f64(), g(x));
+            let tolerance = ulps * T::ULP + (relative + 4.0 * f64::EPSILON) * expected.abs();
+            assert!((found - expected).abs() <= tolerance, "f({}) = {}, expected {} within {}", x, found, expected, tolerance);
+        }
+
+        /// Checks every function documented as accurate against `f64` at `inputs`, with `exp` only checked up to `exp_limit` to stay in range
+        fn assert_conforms_all<T: Reference>(inputs: impl Iterator<Item=f64>, exp_limit: f64) {
+            let relative = 2f64.powi(-50);
+            for x in inputs {
+                assert_conforms(T::sin, f64::sin, x, 1.0, 0.0);
+                assert_conforms(T::cos, f64::cos, x, 1.0, 0.0);
+                assert_conforms(T::atan, f64::atan, x, 1.0, 0.0);
+                assert_conforms(|y: T| y.atan2(T::f(-0.75)), |y| y.atan2(-0.75), x, 1.0, 0.0);
+                assert_conforms(|y| T::f(0.375).atan2(y), |y| 0.375f64.atan2(y), x, 1.0, 0.0);
+                assert_conforms(|y: T| y.hypot(T::f(2.5)), |y| y.hypot(2.5), x, 1.0, 0.0);
+                if x.cos().abs() > 1e-3 {
+                    assert_conforms(T::tan, f64::tan, x, 1.0, relative);
+                }
+                if x.abs() <= exp_limit {
+                    assert_conforms(T::exp, f64::exp, x, 1.0, relative);
+                }
+                if x > 0.0 {
+                    assert_conforms(T::sqrt, f64::sqrt, x, 1.0, 0.0);
+                    assert_conforms(T::ln, f64::ln, x, 1.0, 0.0);
+                    assert_conforms(|base: T| base.powf(1.5), |base| base.powf(1.5), x, 1.0, relative);
+                    assert_conforms(|base: T| base.pow(T::f(-0.25)), |base| base.powf(-0.25), x, 1.0, relative);
+                }
+            }
+            for x in (-256..=256).map(|step| step as f64 / 256.0) {
+                assert_conforms(|x: T| x.asin().unwrap(), f64::asin, x, 1.0, 0.0);
+                assert_conforms(|x: T| x.acos().unwrap(), f64::acos, x, 1.0, 0.0);
+            }
+        }
+
+        #[test]
+        pub fn conformance() {
+            let inputs = || (-512..=512).map(|step| step as f64 / 64.0);
+            assert_conforms_all::<Q32_32>(inputs().chain([1000.25, -12345.5, 1e6 + 0.125]), 20.0);
+            // Q16_16 only reaches 32768, which limits the range of `exp` and `powf`
+            assert_conforms_all::<Q16_16>(inputs().chain([1000.25, -12345.5]), 10.0);
+        }
+
+        #[test]
+        pub fn determinism() {
+            // Exact results, which must not vary between platforms
+            assert_eq!(Q32_32::PI.to_raw(), 13493037705);
+            assert_eq!(Q16_16::PI.to_raw(), 205887);
+            assert_eq!(Q32_32::i(2).sqrt().to_raw(), 6074001000);
+            assert_eq!(Q32_32::f(0.5).sin().to_raw(), 2059117009);
+            assert_eq!(Q32_32::ONE.acos(), Some(Q32_32::ZERO));
+            assert_eq!(Q32_32::ONE.asin(), Some(Q32_32::from_raw(6746518852)));   // π/2, rounded to nearest
+            assert_eq!(Q32_32::f(-1.5).powi(3), Q32_32::f(-3.375));
+            assert_eq!(Q32_32::i(2).pow(Q32_32::i(-2)), Q32_32::f(0.25));
+            assert_eq!(Q32_32::f(2.5).floor(), Q32_32::i(2));
+            assert_eq!(Q32_32::f(-2.5).floor(), Q32_32::i(-3));
+            assert_eq!(Q32_32::f(-2.5).ceil(), Q32_32::i(-2));
+            assert_eq!(Q16_16::f(0.75) * Q16_16::f(-0.5), Q16_16::f(-0.375));
+            assert_eq!(Q16_16::i(1) / Q16_16::i(3), Q16_16::from_raw(21845));
+        }
+
+        #[test]
+        pub fn saturation() {
+            assert_eq!(Q32_32::MAX + Q32_32::ONE, Q32_32::MAX);
+            assert_eq!(Q32_32::MIN - Q32_32::ONE, Q32_32::MIN);
+            assert_eq!(-Q32_32::MIN, Q32_32::MAX);
+            assert_eq!(Q32_32::i(70000) * Q32_32::i(70000), Q32_32::MAX);
+            assert_eq!(Q32_32::ONE / Q32_32::ZERO, Q32_32::MAX);
+            assert_eq!(-Q32_32::ONE / Q32_32::ZERO, Q32_32::MIN);
+            assert_eq!(Q32_32::ZERO / Q32_32::ZERO, Q32_32::ZERO);
+            assert_eq!(Q32_32::i(100).exp(), Q32_32::MAX);
+            assert_eq!(Q32_32::i(-100).exp(), Q32_32::ZERO);
+            assert_eq!(Q32_32::i(-4).sqrt(), Q32_32::ZERO);
+            assert_eq!(Q32_32::ZERO.ln(), Q32_32::MIN);
+            assert_eq!(Q32_32::ZERO.signum(), Q32_32::ZERO);
+            assert_eq!(Q32_32::i(i32::MAX).hypot(Q32_32::i(i32::MAX)), Q32_32::MAX);
+            assert!((Q32_32::ONE + Q32_32::EPSILON).acos().is_none());
+            assert!(Q32_32::MIN.is_finite());
+        }
+
+        #[test]
+        pub fn geometry() {
+            let triangle = AbstractTriangle_abc::new(Q32_32::i(3), Q32_32::i(4), Q32_32::i(5)).unwrap();
+            assert_approx_eq!(triangle.angle_gamma().radians(), Q32_32::PI / Q32_32::i(2), max_ulps = 4);
+            assert_approx_eq!(triangle.area(), Q32_32::i(6), max_ulps = 4);
+
+            let rotation = RotationMatrix::<Q32_32, ()>::from_axis_angle(Vector3D::new([Q32_32::ZERO, Q32_32::ZERO, Q32_32::i(2)]), Q32_32::PI / Q32_32::i(2));
+            let rotated = rotation.apply(Vector3D::new([Q32_32::ONE, Q32_32::ZERO, Q32_32::ZERO]));
+            assert_approx_eq!(rotated, Vector3D::new([Q32_32::ZERO, Q32_32::ONE, Q32_32::ZERO]), epsilon = Q32_32::ZERO, max_ulps = 2);
+            let magnitude = Vector3D::<Q32_32, ()>::new([Q32_32::i(3), Q32_32::i(4), Q32_32::i(12)]).magnitude();
+            assert_approx_eq!(magnitude, Q32_32::i(13), max_ulps = 8);
+            assert_approx_ne!(magnitude, Q32_32::i(13) + Q32_32::EPSILON * Q32_32::i(9), epsilon = Q32_32::ZERO, max_ulps = 8);
+            assert_approx_eq!(Q32_32::i(1000), Q32_32::i(1001), max_relative = Q32_32::f(1e-3));
+            assert_approx_ne!(Q32_32::MIN, Q32_32::MAX, epsilon = Q32_32::MAX);
+            assert_approx_eq!(Q32_32::MIN, Q32_32::MAX, max_relative = Q32_32::i(2));
+        }
+    }
+
+    mod interval {
+        use std::cmp::Ordering;
+        use std::f64::consts::FRAC_PI_2;
+        use crate::geometry3d::Vector3D;
+        use crate::scalar::interval::Interval;
+        use crate::scalar::{Ring, Scalar};
+        use crate::shapes::triangle::{AbstractTriangle, AbstractTriangle_abc};
+
+        fn bounds(interval: Interval<f64>) -> (f64, f64) {
+            (interval.lower(), interval.upper())
+        }
+
+        /// Checks that the enclosure computed by `f` contains the value of `g` at sample points throughout `input`
+        fn assert_encloses(input: Interval<f64>, f: impl Fn(Interval<f64>) -> Interval<f64>, g: impl Fn(f64) -> f64) {
+            let enclosure = f(input);
+            for step in 0..=64 {
+                let x = input.lower() + (input.upper() - input.lower()) * (step as f64 / 64.0);
+                let y = g(x);
+                assert!(enclosure.contains(y), "{:?} does not contain f({}) = {}", enclosure, x, y);
+            }
+        }
+
+        #[test]
+        pub fn arithmetic_rounding() {
+            // Exact operations stay exact
+            assert_eq!(Interval::<f64>::i(2) + Interval::i(3), Interval::i(5));
+            assert_eq!(Interval::<f64>::i(6) / Interval::i(4), Interval::f(1.5));
+            assert_eq!(Interval::<f64>::i(9).sqrt(), Interval::i(3));
+
+            let third = Interval::<f64>::ONE / Interval::i(3);
+            assert_eq!(third.lower().next_up(), third.upper());
+            assert!(third.lower() <= 1.0 / 3.0 && (third * Interval::i(3)).contains(1.0));
+            let root = Interval::<f64>::i(2).sqrt();
+            assert_eq!(root.lower().next_up(), root.upper());
+            assert!((root * root).contains(2.0));
+
+            let tenth = Interval::<f32>::f(0.1);
+            assert!(tenth.lower() < tenth.upper() && (tenth.lower() as f64) < 0.1 && (tenth.upper() as f64) > 0.1);
+            assert!(Interval::<f64>::PI.contains(std::f64::consts::PI) && Interval::<f32>::PI.contains(std::f32::consts::PI));
+            assert!(Interval::<f32>::PI.lower() < Interval::<f32>::PI.upper());
+
+            assert_eq!(bounds(Interval::new(-1.0, 2.0).unwrap() * Interval::new(-3.0, 1.0).unwrap()), (-6.0, 3.0));
+            assert_eq!(bounds(Interval::new(1.0, 2.0).unwrap() - Interval::new(0.5, 1.0).unwrap()), (0.0, 1.5));
+            assert_eq!(bounds(Interval::new(1.0, 2.0).unwrap() / Interval::new(-1.0, 1.0).unwrap()), (f64::NEG_INFINITY, f64::INFINITY));
+            assert_eq!(bounds(Interval::new(-2.0, 1.0).unwrap().powi(2)), (0.0, 4.0));
+            assert_eq!(bounds(Interval::new(-2.0, 1.0).unwrap().powi(3)), (-8.0, 1.0));
+            assert_eq!(bounds(Interval::new(-2.0, 1.0).unwrap().abs()), (0.0, 2.0));
+            let overflow = Interval::point(f64::MAX) + Interval::point(f64::MAX);
+            assert_eq!((overflow.lower(), overflow.upper()), (f64::MAX, f64::INFINITY));
+        }
+
+        #[test]
+        pub fn function_enclosures() {
+            let inputs = [Interval::new(0.1, 0.2).unwrap(), Interval::new(-0.9, 0.3).unwrap(), Interval::new(0.5, 3.5).unwrap(), Interval::new(-4.0, -1.0).unwrap(), Interval::new(2.0, 9.0).unwrap(), Interval::new(1e-3, 1e3).unwrap()];
+            for input in inputs {
+                assert_encloses(input, Interval::sin, f64::sin);
+                assert_encloses(input, Interval::cos, f64::cos);
+                assert_encloses(input, Interval::tan, f64::tan);
+                assert_encloses(input, Interval::atan, f64::atan);
+                assert_encloses(input, Interval::exp, f64::exp);
+                assert_encloses(input, |x| x.powi(3), |x| x.powi(3));
+                assert_encloses(input, |x| x.powi(-2), |x| x.powi(-2));
+                assert_encloses(input, |x| x.atan2(Interval::f(-0.5)), |x| x.atan2(-0.5));
+                assert_encloses(input, |x| Interval::f(0.7).atan2(x), |x| 0.7f64.atan2(x));
+                assert_encloses(input, |x| x.hypot(Interval::f(-0.5)), |x| x.hypot(-0.5));
+                if input.lower() > 0.0 {
+                    assert_encloses(input, Interval::sqrt, f64::sqrt);
+                    assert_encloses(input, Interval::ln, f64::ln);
+                    assert_encloses(input, |x| x.powf(1.5), |x| x.powf(1.5));
+                    assert_encloses(input, |x| x.pow(Interval::new(0.5, 2.5).unwrap()), |x| x.powf(0.5).min(x.powf(2.5)));
+                    assert_encloses(input, |x| x.pow(Interval::new(0.5, 2.5).unwrap()), |x| x.powf(0.5).max(x.powf(2.5)));
+                }
+                if input.lower() >= -1.0 && input.upper() <= 1.0 {
+                    assert_encloses(input, |x| x.acos().unwrap(), |x| x.acos());
+                    assert_encloses(input, |x| x.asin().unwrap(), |x| x.asin());
+                }
+            }
+
+            assert_eq!(Interval::new(0.5, 3.5).unwrap().sin().upper(), 1.0);
+            assert_eq!(Interval::new(3.0, 3.5).unwrap().cos().lower(), -1.0);
+            assert_eq!(bounds(Interval::new(1.0, 2.0).unwrap().tan()), (f64::NEG_INFINITY, f64::INFINITY));
+            assert_eq!(bounds(Interval::new(-1.0, 0.0).unwrap().atan2(Interval::i(-1))), (-Interval::<f64>::PI.upper(), Interval::<f64>::PI.upper()));
+            assert!(!Interval::new(-4.0, -1.0).unwrap().sqrt().is_finite());
+            assert_eq!(bounds(Interval::new(-1.0, 4.0).unwrap().sqrt()), (0.0, 2.0));
+        }
+
+        #[test]
+        pub fn partial_domains() {
+            // Partially out of domain intervals are clipped, as rounding commonly pushes values just outside [-1, 1]
+            let acos = Interval::new(0.5, 1.0 + 1e-12).unwrap().acos().unwrap();
+            assert_eq!(acos.lower(), 0.0);
+            assert!(acos.contains(0.5f64.acos()));
+            let asin = Interval::new(-1.5, -0.5).unwrap().asin().unwrap();
+            assert!(asin.lower() <= -FRAC_PI_2 && asin.contains((-0.5f64).asin()));
+            assert!(Interval::new(1.1, 1.2).unwrap().acos().is_none());
+            assert!(Interval::new(-1.2, -1.1).unwrap().asin().is_none());
+            assert!(Interval::<f64>::point(f64::NAN).acos().is_none());
+        }
+
+        #[test]
+        pub fn comparisons() {
+            let (a, b, c) = (Interval::new(1.0, 2.0).unwrap(), Interval::new(2.0, 3.0).unwrap(), Interval::new(2.5, 4.0).unwrap());
+            assert!(a < c && a <= c && a != c);
+            assert_eq!(a.partial_cmp(&b), None);
+            assert!(a != b && b.partial_cmp(&c).is_none());
+            assert_eq!(a.partial_cmp(&a), None);
+            assert_eq!(Interval::point(2.0).partial_cmp(&Interval::point(2.0)), Some(Ordering::Equal));
+            assert!(Interval::point(2.0) <= Interval::point(2.0));
+            assert!(Interval::new(2.0, 1.0).is_none() && Interval::new(f64::NAN, 1.0).is_none());
+            assert_eq!(a.intersection(b), Some(Interval::point(2.0)));
+            assert_eq!(a.intersection(c), None);
+            assert_eq!(bounds(a.hull(c)), (1.0, 4.0));
+
+            // Approximate equality compares the bounds, so wide intervals compare equal to themselves
+            assert_approx_eq!(a, a);
+            assert_approx_ne!(a, b);
+            assert_approx_eq!(Interval::<f64>::ONE / Interval::i(3), Interval::point(1.0 / 3.0), max_ulps = 1);
+            assert_approx_ne!(Interval::<f64>::ONE / Interval::i(3), Interval::point(1.0 / 3.0), epsilon = Interval::ZERO, max_ulps = 0);
+        }
+
+        #[test]
+        pub fn certified_solutions() {
+            let triangle = AbstractTriangle_abc::new(Interval::<f64>::i(3), Interval::i(4), Interval::i(5)).unwrap();
+            let gamma = triangle.angle_gamma().radians();
+            // The exact right angle lies between the two adjacent f64 values
+            assert!(gamma.lower() <= FRAC_PI_2 && gamma.upper() >= FRAC_PI_2.next_up());
+            assert!(gamma.width() < 1e-14);
+            let alpha = triangle.angle_alpha().radians();
+            assert!(alpha.contains(0.6f64.asin()) && alpha.width() < 1e-14);
+            assert!(triangle.area().contains(6.0));
+
+            let magnitude = Vector3D::<Interval<f64>, ()>::new([Interval::i(3), Interval::i(4), Interval::i(12)]).magnitude();
+            assert!(magnitude.contains(13.0) && magnitude.width() < 1e-13);
+        }
+    }
+}
+
+mod vector {
+    use crate::geometry3d::{Point3D, Vector3D};
+
+    #[test]
+    pub fn magnitude() {
+        assert_eq!(Vector3D::<f64, ()>::new([3.0, 4.0, 12.0]).magnitude(), 13.0);
+        assert_eq!(Vector3D::<f64, ()>::new([0.0, 0.0, 0.0]).magnitude(), 0.0);
+        assert!((Vector3D::<f64, ()>::new([3e200, -4e200, 0.0]).magnitude() / 5e200 - 1.0).abs() < 1e-15);
+        assert!((Vector3D::<f64, ()>::new([3e-200, 4e-200, 0.0]).magnitude() / 5e-200 - 1.0).abs() < 1e-15);
+        assert!((Vector3D::<f32, ()>::new([3e30, 4e30, 0.0]).magnitude() / 5e30 - 1.0).abs() < 1e-6);
+        assert_eq!(Vector3D::<f64, ()>::new([f64::INFINITY, 1.0, f64::NAN]).magnitude(), f64::INFINITY);
+        assert!(Vector3D::<f64, ()>::new([1.0, 1.0, f64::NAN]).magnitude().is_nan());
+        assert!(Vector3D::<f64, ()>::new([f64::NAN; 3]).magnitude().is_nan());
+    }
+
+    #[test]
+    pub fn point_arithmetic() {
+        let a = Point3D::<f64, ()>::new([1.0, 2.0, 3.0]);
+        let b = Point3D::<f64, ()>::new([4.0, 6.0, 3.0]);
+        let offset = Vector3D::new([3.0, 4.0, 0.0]);
+
+        assert_eq!(b - a, offset);
+        assert_eq!(a.vector_to(b), offset);
+        assert_eq!(a + offset, b);
+        assert_eq!(b - offset, a);
+
+        let mut c = a;
+        c += offset;
+        assert_eq!(c, b);
+        c -= offset;
+        assert_eq!(c, a);
+
+        assert_eq!(Point3D::<f64, ()>::origin() + a.to_vector(), a);
+    }
+
+    #[test]
+    pub fn affine_combinations() {
+        let a = Point3D::<f64, ()>::new([1.0, 2.0, 3.0]);
+        let b = Point3D::<f64, ()>::new([5.0, 6.0, -1.0]);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), [3.0, 4.0, 1.0]);
+        assert_eq!(a.lerp(b, 2.0), [9.0, 10.0, -5.0]);
+
+        let points = [a, b, Point3D::new([0.0, -2.0, 1.0])];
+        assert_eq!(Point3D::centroid(&points), Some(Point3D::new([2.0, 2.0, 1.0])));
+        assert_eq!(Point3D::centroid(&points[..1]), Some(a));
+        assert_eq!(Point3D::<f64, ()>::centroid(&[]), None);
+    }
+}
+
+mod matrix {
+    use crate::geometry3d::{RotationMatrix, Vector3D};
+    use crate::matrix::{MatrixMN, MatrixN};
+    use crate::vector::VectorN;
+
+    #[test]
+    pub fn layout() {
+        let matrix = MatrixMN::<f64, 2, 3, ()>::from_row_major([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(matrix.to_column_major(), [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        assert_eq!(matrix[(0, 2)], 3.0);
+        assert_eq!(matrix.column(1), [2.0, 5.0]);
+        assert_eq!(matrix.transpose().to_row_major(), [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        assert_eq!(MatrixMN::from_column_major(matrix.to_column_major()), matrix);
+    }
+
+    #[test]
+    pub fn multiplication() {
+        let a = MatrixMN::<f64, 2, 3, ()>::from_row_major([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = MatrixMN::<f64, 3, 2, ()>::from_row_major([[7.0, 8.0], [9.0, 10.0], [11.0, 12.0]]);
+        assert_eq!((a * b).to_row_major(), [[58.0, 64.0], [139.0, 154.0]]);
+        assert_eq!(a * VectorN::new([1.0, 0.0, -1.0]), [-2.0, -2.0]);
+        assert_eq!(MatrixN::<f64, 3, ()>::identity() * b, b);
+
+        // Consistent with the 3x3 rotation matrix
+        let rotation = RotationMatrix::<f64, ()>::from_axis_angle(Vector3D::new([1.0, 2.0, 3.0]), 0.7);
+        let vector = Vector3D::new([0.3, -1.2, 2.0]);
+        let found = MatrixN::from(rotation) * vector;
+        assert_approx_eq!(found, rotation.apply(vector), epsilon = 1e-12);
+    }
+
+    #[test]
+    pub fn determinant_and_inverse() {
+        let matrix = MatrixN::<f64, 3, ()>::from_row_major([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 4.0]]);  // Zero in the first pivot position requires pivoting
+        assert_approx_eq!(matrix.determinant(), -11.0, epsilon = 1e-12);
+
+        let inverse = matrix.inverse().unwrap();
+        assert_approx_eq!(matrix * inverse, MatrixN::identity(), epsilon = 1e-12);
+        assert_approx_eq!(inverse * matrix, MatrixN::identity(), epsilon = 1e-12);
+
+        let singular = MatrixN::<f64, 3, ()>::from_row_major([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+        assert_eq!(singular.determinant(), 0.0);
+        assert_eq!(singular.inverse(), None);
+        assert_eq!(singular.solve(VectorN::new([1.0, 2.0, 3.0])), None);
+
+        let large = MatrixN::<f64, 5, ()>::from_row_major([
+            [2.0, -1.0, 0.0, 0.0, 1.0],
+            [-1.0, 2.0, -1.0, 0.0, 0.0],
+            [0.0, -1.0, 2.0, -1.0, 0.0],
+            [0.0, 0.0, -1.0, 2.0, -1.0],
+            [1.0, 0.0, 0.0, -1.0, 2.0],
+        ]);
+        assert_approx_eq!(large * large.inverse().unwrap(), MatrixN::identity(), epsilon = 1e-12);
+    }
+
+    #[test]
+    pub fn solve() {
+        let matrix = MatrixN::<f64, 3, ()>::from_row_major([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 4.0]]);
+        let x = VectorN::new([1.0, -2.0, 0.5]);
+        let solution = matrix.solve(matrix * x).unwrap();
+        assert_approx_eq!(solution, x, epsilon = 1e-12);
+
+        let lu = matrix.lu().unwrap();
+        assert_approx_eq!(lu.determinant(), matrix.determinant(), epsilon = 1e-12);
+        assert_eq!(lu.solve(matrix * x), solution);
+    }
+
+    #[test]
+    pub fn least_squares() {
+        // Fit y = a + bx through points on the line y = 1 + 2x, and through noisy points symmetric around it
+        let exact = MatrixMN::<f64, 4, 2, ()>::from_row_major([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let fit = exact.solve_least_squares(VectorN::new([1.0, 3.0, 5.0, 7.0])).unwrap();
+        assert_approx_eq!(fit.to_array(), [1.0, 2.0], epsilon = 1e-12);
+
+        let fit = exact.solve_least_squares(VectorN::new([1.5, 2.5, 4.5, 7.5])).unwrap();
+        assert_approx_eq!(fit.to_array(), [1.0, 2.0], epsilon = 1e-12);
+
+        let dependent = MatrixMN::<f64, 3, 2, ()>::from_row_major([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        assert_eq!(dependent.solve_least_squares(VectorN::new([1.0, 2.0, 3.0])), None);
+    }
+
+    #[test]
+    pub fn symmetric_eigen() {
+        // Known decomposition; A rotated diagonal matrix
+        let rotation = MatrixN::from(RotationMatrix::<f64, ()>::from_axis_angle(Vector3D::new([1.0, -2.0, 0.5]), 1.1));
+        let diagonal = MatrixN::from_row_major([[2.0, 0.0, 0.0], [0.0, 7.0, 0.0], [0.0, 0.0, -1.0]]);
+        let (values, vectors) = (rotation * diagonal * rotation.transpose()).symmetric_eigen();
+        assert_approx_eq!(values, [7.0, 2.0, -1.0], epsilon = 1e-12);
+        assert_approx_eq!(vectors.transpose() * vectors, MatrixN::identity(), epsilon = 1e-12);
+        // Eigenvectors are determined up to sign
+        for (column, expected) in [(0, 1), (1, 0), (2, 2)] {
+            assert_approx_eq!(vectors.column(column).dot(rotation.column(expected)).abs(), 1.0, epsilon = 1e-12);
+        }
+
+        let large = MatrixN::<f64, 5, ()>::from_row_major([
+            [4.0, -1.0, 0.0, 0.5, 1.0],
+            [-1.0, 2.0, -1.0, 0.0, 0.0],
+            [0.0, -1.0, 3.0, -1.0, 0.25],
+            [0.5, 0.0, -1.0, 2.0, -1.0],
+            [1.0, 0.0, 0.25, -1.0, 5.0],
+        ]);
+        let (values, vectors) = large.symmetric_eigen();
+        assert!(values.windows(2).all(|pair| pair[0] >= pair[1]));
+        for (value, vector) in values.into_iter().zip(vectors.to_columns()) {
+            assert!((large * vector - vector * value).magnitude() < 1e-12);
+        }
+        assert_approx_eq!(values.into_iter().product::<f64>(), large.determinant(), epsilon = 1e-12);
+
+        let (values, vectors) = MatrixN::<f64, 2, ()>::identity().symmetric_eigen();
+        assert_eq!((values, vectors), ([1.0, 1.0], MatrixN::identity()));
+    }
+}
+
+mod predicates {
+    use std::cmp::Ordering;
+    use crate::basis::YUpLeftHanded;
+    use crate::geometry2d::Point2D;
+    use crate::geometry3d::Point3D;
+    use crate::predicates::{incircle, insphere, orient2d, orient3d};
+
+    #[test]
+    pub fn orient2d_near_degenerate() {
+        // Points within a few ulps of (0.5, 0.5), against the line through (12, 12) and (24, 24); Compared with exact integer evaluation, scaled by 2^53
+        let ulp = 0.5f64.next_up() - 0.5;
+        let (b, c) = (Point2D::<f64, ()>::new([12.0, 12.0]), Point2D::new([24.0, 24.0]));
+        let scale = 2i128.pow(53);
+        let mut naive_errors = 0;
+        for i in 0..64 {
+            for j in 0..64 {
+                let a = Point2D::new([0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp]);
+                let (ax, ay) = (scale / 2 + i, scale / 2 + j);
+                let expected = ((ax - 24 * scale) * (-12 * scale) - (ay - 24 * scale) * (-12 * scale)).cmp(&0);
+                assert_eq!(orient2d(a, b, c), expected, "i = {}, j = {}", i, j);
+                assert_eq!(orient2d(b, c, a), expected);
+                assert_eq!(orient2d(b, a, c), expected.reverse());
+
+                let [ax, ay] = a.to_array();
+                let naive = (ax - 24.0) * (12.0 - 24.0) - (ay - 24.0) * (12.0 - 24.0);
+                if naive.partial_cmp(&0.0) != Some(expected) {
+                    naive_errors += 1;
+                }
+            }
+        }
+        assert!(naive_errors > 0, "the naive determinant should fail on this input");
+
+        // f32, scaled by 2^24
+        let ulp = 0.5f32.next_up() - 0.5;
+        let (b, c) = (Point2D::<f32, ()>::new([12.0, 12.0]), Point2D::new([24.0, 24.0]));
+        let scale = 2i128.pow(24);
+        for i in 0..32 {
+            for j in 0..32 {
+                let a = Point2D::new([0.5 + i as f32 * ulp, 0.5 + j as f32 * ulp]);
+                let (ax, ay) = (scale / 2 + i, scale / 2 + j);
+                let expected = ((ax - 24 * scale) * (-12 * scale) - (ay - 24 * scale) * (-12 * scale)).cmp(&0);
+                assert_eq!(orient2d(a, b, c), expected);
+            }
+        }
+
+        assert_eq!(orient2d(Point2D::<f64, ()>::new([0.0, 0.0]), Point2D::new([1.0, 0.0]), Point2D::new([0.0, 1.0])), Ordering::Greater);
+        assert_eq!(orient2d(Point2D::<f64, ()>::new([1.0, 1.0]), Point2D::new([1.0, 1.0]), Point2D::new([3.0, 7.0])), Ordering::Equal);
+    }
+
+    #[test]
+    pub fn orient3d_near_degenerate() {
+        // b, c and d lie exactly on the plane x + y + z = 1.5, as does a for k = 0; a moves above the plane for k > 0
+        let (b, c, d) = (Point3D::<f64, ()>::new([12.0, -4.0, -6.5]), Point3D::new([-7.0, 20.0, -11.5]), Point3D::new([3.25, 0.25, -2.0]));
+        let above = orient3d(Point3D::new([0.0, 0.0, 10.0]), b, c, d);
+        assert_ne!(above, Ordering::Equal);
+        let ulp = 0.5f64.next_up() - 0.5;
+        for i in 0..16 {
+            for j in 0..16 {
+                for k in -4..=4 {
+                    let a = Point3D::new([0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp, 0.5 - (i + j - k) as f64 * ulp]);
+                    let expected = match k.cmp(&0) {
+                        Ordering::Less => above.reverse(),
+                        Ordering::Equal => Ordering::Equal,
+                        Ordering::Greater => above,
+                    };
+                    assert_eq!(orient3d(a, b, c, d), expected, "i = {}, j = {}, k = {}", i, j, k);
+                    assert_eq!(orient3d(b, a, c, d), expected.reverse());
+                }
+            }
+        }
+
+        // d below the counter-clockwise triangle
+        let [a, b, c] = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]].map(Point3D::<f64, ()>::new);
+        assert_eq!(orient3d(a, b, c, Point3D::new([0.3, 0.3, -1.0])), Ordering::Greater);
+        assert_eq!(orient3d(a, b, c, Point3D::new([0.3, 0.3, 1.0])), Ordering::Less);
+        // Follows the right-hand rule of the basis
+        let [a, b, c] = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]].map(Point3D::<f64, YUpLeftHanded>::new);
+        assert_eq!(orient3d(a, b, c, Point3D::new([0.3, 0.3, -1.0])), Ordering::Less);
+    }
+
+    #[test]
+    pub fn incircle_near_degenerate() {
+        // Integer points on a circle of radius 5, translated far from the origin such that the naive determinant cancels catastrophically
+        let (x, y) = (1048576.25, -786432.5);
+        let ulp = (x + 5.0f64).next_up() - (x + 5.0);
+        let [a, b, c] = [[3.0, 4.0], [-4.0, 3.0], [0.0, -5.0]].map(|[dx, dy]| Point2D::<f64, ()>::new([x + dx, y + dy]));
+        assert_eq!(orient2d(a, b, c), Ordering::Greater);
+        assert_eq!(incircle(a, b, c, Point2D::new([x - 3.0, y - 4.0])), Ordering::Equal);
+        assert_eq!(incircle(a, b, c, Point2D::new([x, y])), Ordering::Greater);
+        for k in 1..8 {
+            let outside = Point2D::new([x + 5.0 + k as f64 * ulp, y]);
+            let inside = Point2D::new([x + 5.0 - k as f64 * ulp, y]);
+            assert_eq!(incircle(a, b, c, outside), Ordering::Less, "k = {}", k);
+            assert_eq!(incircle(a, b, c, inside), Ordering::Greater, "k = {}", k);
+            assert_eq!(incircle(b, a, c, inside), Ordering::Less);
+        }
+
+        let [a, b, c] = [[3.0, 4.0], [-4.0, 3.0], [0.0, -5.0]].map(|[dx, dy]| Point2D::<f32, ()>::new([1024.5 + dx, 512.25 + dy]));
+        assert_eq!(incircle(a, b, c, Point2D::new([1024.5 + 5.0, 512.25])), Ordering::Equal);
+        assert_eq!(incircle(a, b, c, Point2D::new([(1024.5f32 + 5.0).next_up(), 512.25])), Ordering::Less);
+    }
+
+    #[test]
+    pub fn insphere_near_degenerate() {
+        // Integer points on a sphere of radius 3 (including (2, 2, 1)), translated far from the origin
+        let offset = [65536.125, -32768.75, 4096.5];
+        let point = |[x, y, z]: [f64; 3]| Point3D::<f64, ()>::new([offset[0] + x, offset[1] + y, offset[2] + z]);
+        let [a, b, c, d] = [[3.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 3.0], [-3.0, 0.0, 0.0]].map(point);
+        let orientation = orient3d(a, b, c, d);
+        assert_eq!(orientation, Ordering::Greater);
+        assert_eq!(insphere(a, b, c, d, point([2.0, 2.0, 1.0])), Ordering::Equal);
+        assert_eq!(insphere(a, b, c, d, point([-1.0, -2.0, 2.0])), Ordering::Equal);
+        assert_eq!(insphere(a, b, c, d, point([0.0, 0.0, 0.0])), Ordering::Greater);
+        assert_eq!(insphere(b, a, c, d, point([0.0, 0.0, 0.0])), Ordering::Less);
+
+        let [x, y, z] = offset;
+        let edge = z - 3.0;
+        for k in 1..8 {
+            let mut below = edge;
+            let mut above = edge;
+            for _ in 0..k {
+                below = below.next_down();
+                above = above.next_up();
+            }
+            assert_eq!(insphere(a, b, c, d, Point3D::new([x, y, below])), Ordering::Less, "k = {}", k);
+            assert_eq!(insphere(a, b, c, d, Point3D::new([x, y, above])), Ordering::Greater, "k = {}", k);
+        }
+
+        // Consistent with orient3d in left-handed bases
+        let [a, b, c, d, e] = [[3.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 3.0], [-3.0, 0.0, 0.0], [0.0, 0.0, 0.0]].map(Point3D::<f64, YUpLeftHanded>::new);
+        assert_eq!(orient3d(a, b, c, d), Ordering::Less);
+        assert_eq!(insphere(a, b, c, d, e), Ordering::Less);
     }
 }
@@ -64,3 +64,45 @@ impl<T: Debug + Copy> MaybeTwo<T> for (T, Option<T>) {
         (f(self.0), self.1.map(f))
     }
 }
+
+/// Small deterministic pseudo-random number generator (SplitMix64), for randomized algorithms which must be reproducible
+///
+/// Not suitable for cryptographic use
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    #[inline]
+    pub(crate) fn new(seed: u64) -> Self {
+        SplitMix64(seed)
+    }
+
+    #[inline]
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed value in `low..high`, from the top 53 bits of the next value
+    #[cfg(test)]
+    #[inline]
+    pub(crate) fn uniform(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64)
+    }
+
+    /// Uniformly distributed value in `0..bound`, through Lemire's multiply-shift reduction; `bound` must be non-zero
+    #[inline]
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Fisher–Yates shuffle
+    pub(crate) fn shuffle<X>(&mut self, items: &mut [X]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
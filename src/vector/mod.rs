@@ -1,8 +1,9 @@
 use std::fmt::{Display, Formatter};
 use std::marker::PhantomData;
+use crate::approx::ApproxEq;
 use crate::basis::Basis;
 
-use crate::scalar::Scalar;
+use crate::scalar::{Field, Ring, Scalar};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// N-dimensional vector
@@ -19,8 +20,26 @@ pub struct VectorN<T, const N: usize, B: Basis<N>> {
     pub(crate) basis: PhantomData<B>
 }
 
-/// Type alias for VectorN that is used as a point
-pub type PointN<T, const N: usize, B> = VectorN<T, N, B>;
+/// N-dimensional point
+///
+/// Points are positions in an affine space, and are distinct from [`VectorN`] displacements/directions:
+/// * point - point = vector
+/// * point + vector = point
+/// * point - vector = point
+///
+/// Points cannot be added to each other, or scaled; Weighted combinations of points are provided as affine combinations, such as [`PointN::lerp`] and [`PointN::centroid`].
+///
+/// ```compile_fail
+/// use unifiedgeometry::geometry3d::Point3D;
+/// let a = Point3D::<f64, ()>::new([1.0, 2.0, 3.0]);
+/// let b = Point3D::<f64, ()>::new([4.0, 5.0, 6.0]);
+/// let _ = a + b;
+/// ```
+///
+/// Type aliases for 2D and 3D ([`Point3D`](crate::geometry3d::Point3D), [`Point2D`](crate::geometry2d::Point2D)) are provided by the `geometry2d` and `geometry3d` modules
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct PointN<T, const N: usize, B: Basis<N>>(VectorN<T, N, B>);
 
 impl<T, const N: usize, B: Basis<N>> VectorN<T, N, B> {
     /// Create a new vector from an array of components
@@ -64,7 +83,7 @@ impl<T, const N: usize, B: Basis<N>> VectorN<T, N, B> {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// use unifiedgeometry::vector::VectorN;
     ///
     /// let vector = VectorN::new([1, 2, 3]);
@@ -85,7 +104,7 @@ impl<T, const N: usize, B: Basis<N>> VectorN<T, N, B> {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// use unifiedgeometry::vector::VectorN;
     ///
     /// let mut vector = VectorN::new([1, 2, 3]);
@@ -109,7 +128,7 @@ impl<T, const N: usize, B: Basis<N>> VectorN<T, N, B> {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// use unifiedgeometry::vector::VectorN;
     ///
     /// let left_vector = VectorN::new([1, 2, 3]);
@@ -120,7 +139,7 @@ impl<T, const N: usize, B: Basis<N>> VectorN<T, N, B> {
     #[inline]
     fn binary_op<U, V>(self, rhs: VectorN<U, N, B>, f: fn(T, U) -> V) -> VectorN<V, N, B> {
         let mut iter = self.array.into_iter()
-            .zip(rhs.array.into_iter())
+            .zip(rhs.array)
             .map(|(lhs, rhs)| f(lhs, rhs));
         VectorN::new(std::array::from_fn(|_index| iter.next().unwrap()))    // This is a stupid hack, but for small arrays this optimizes well; Using MaybeUninit to consume the arrays is unstable and clunky
     }
@@ -135,7 +154,7 @@ impl<T, const N: usize, B: Basis<N>> VectorN<T, N, B> {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// use unifiedgeometry::vector::VectorN;
     ///
     /// let mut left_vector = VectorN::new([1, 2, 3]);
@@ -144,9 +163,9 @@ impl<T, const N: usize, B: Basis<N>> VectorN<T, N, B> {
     /// assert_eq!(left_vector, [5, 7, 9]);
     /// ```
     #[inline]
-    fn binary_assign<U, V>(&mut self, rhs: VectorN<U, N, B>, f: fn(&mut T, U)) {
+    fn binary_assign<U>(&mut self, rhs: VectorN<U, N, B>, f: fn(&mut T, U)) {
         self.array.iter_mut()
-            .zip(rhs.array.into_iter())
+            .zip(rhs.array)
             .for_each(|(lhs, rhs)| f(lhs, rhs))
     }
 }
@@ -182,7 +201,7 @@ impl<T, const N: usize, B: Basis<N>> IntoIterator for VectorN<T, N, B> {
 // TODO: Reference arithmetic
 
 // Vector-Vector Arithmetic
-impl<T: Scalar, const N: usize, B: Basis<N>> Neg for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> Neg for VectorN<T, N, B> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -190,7 +209,7 @@ impl<T: Scalar, const N: usize, B: Basis<N>> Neg for VectorN<T, N, B> {
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> Add for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> Add for VectorN<T, N, B> {
     type Output = Self;
 
     fn add(self, rhs: VectorN<T, N, B>) -> Self::Output {
@@ -198,13 +217,13 @@ impl<T: Scalar, const N: usize, B: Basis<N>> Add for VectorN<T, N, B> {
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> AddAssign for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> AddAssign for VectorN<T, N, B> {
     fn add_assign(&mut self, rhs: VectorN<T, N, B>) {
-        self.binary_assign::<T, T>(rhs, T::add_assign)
+        self.binary_assign::<T>(rhs, T::add_assign)
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> Sub for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> Sub for VectorN<T, N, B> {
     type Output = Self;
 
     fn sub(self, rhs: VectorN<T, N, B>) -> Self::Output {
@@ -212,14 +231,14 @@ impl<T: Scalar, const N: usize, B: Basis<N>> Sub for VectorN<T, N, B> {
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> SubAssign for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> SubAssign for VectorN<T, N, B> {
     fn sub_assign(&mut self, rhs: VectorN<T, N, B>) {
-        self.binary_assign::<T, T>(rhs, T::sub_assign)
+        self.binary_assign::<T>(rhs, T::sub_assign)
     }
 }
 
 // Vector-Scalar arithmetic
-impl<T: Scalar, const N: usize, B: Basis<N>> Add<T> for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> Add<T> for VectorN<T, N, B> {
     type Output = Self;
 
     fn add(self, rhs: T) -> Self::Output {
@@ -227,13 +246,13 @@ impl<T: Scalar, const N: usize, B: Basis<N>> Add<T> for VectorN<T, N, B> {
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> AddAssign<T> for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> AddAssign<T> for VectorN<T, N, B> {
     fn add_assign(&mut self, rhs: T) {
         self.op_assign(|v| T::add_assign(v, rhs))
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> Sub<T> for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> Sub<T> for VectorN<T, N, B> {
     type Output = Self;
 
     fn sub(self, rhs: T) -> Self::Output {
@@ -241,13 +260,13 @@ impl<T: Scalar, const N: usize, B: Basis<N>> Sub<T> for VectorN<T, N, B> {
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> SubAssign<T> for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> SubAssign<T> for VectorN<T, N, B> {
     fn sub_assign(&mut self, rhs: T) {
         self.op_assign(|v| T::sub_assign(v, rhs))
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> Mul<T> for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> Mul<T> for VectorN<T, N, B> {
     type Output = Self;
 
     fn mul(self, rhs: T) -> Self::Output {
@@ -255,13 +274,13 @@ impl<T: Scalar, const N: usize, B: Basis<N>> Mul<T> for VectorN<T, N, B> {
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> MulAssign<T> for VectorN<T, N, B> {
+impl<T: Ring, const N: usize, B: Basis<N>> MulAssign<T> for VectorN<T, N, B> {
     fn mul_assign(&mut self, rhs: T) {
         self.op_assign(|v| T::mul_assign(v, rhs))
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> Div<T> for VectorN<T, N, B> {
+impl<T: Field, const N: usize, B: Basis<N>> Div<T> for VectorN<T, N, B> {
     type Output = Self;
 
     fn div(self, rhs: T) -> Self::Output {
@@ -269,7 +288,7 @@ impl<T: Scalar, const N: usize, B: Basis<N>> Div<T> for VectorN<T, N, B> {
     }
 }
 
-impl<T: Scalar, const N: usize, B: Basis<N>> DivAssign<T> for VectorN<T, N, B> {
+impl<T: Field, const N: usize, B: Basis<N>> DivAssign<T> for VectorN<T, N, B> {
     fn div_assign(&mut self, rhs: T) {
         self.op_assign(|v| T::div_assign(v, rhs))
     }
@@ -315,8 +334,22 @@ macro_rules! impl_scalar_vector_arithmetic {
 
 impl_scalar_vector_arithmetic!(f32);
 impl_scalar_vector_arithmetic!(f64);
+impl_scalar_vector_arithmetic!(i32);
+impl_scalar_vector_arithmetic!(i64);
+impl_scalar_vector_arithmetic!(i128);
 
 // Other operations
+impl<T: Ring, const N: usize, B: Basis<N>> VectorN<T, N, B> {
+    /// Calculates the "scalar" dot product between this and another equally-sized vector
+    #[inline]
+    pub fn dot(self, rhs: Self) -> T {
+        self.into_iter()
+            .zip(rhs)
+            .map(|(lhs, rhs)| lhs * rhs)
+            .fold(T::ZERO, T::add)
+    }
+}
+
 impl<T: Scalar, const N: usize, B: Basis<N>> VectorN<T, N, B> {
     /// Returns true if all components of this vector are finite, false if any component is an infinity or NaN.
     pub fn is_finite(self) -> bool {
@@ -325,22 +358,23 @@ impl<T: Scalar, const N: usize, B: Basis<N>> VectorN<T, N, B> {
 
     /// Vector length/magnitude
     ///
+    /// Components are scaled by the largest absolute component before squaring (as [`Scalar::hypot`]), such that large or tiny components do not overflow or underflow.
+    ///
     /// Special case: T::ZERO for 0-element vectors
     #[inline]
     pub fn magnitude(self) -> T {
+        let scale = self.into_iter()
+            .map(T::abs)
+            .fold(T::ZERO, T::max);
+        if scale == T::ZERO {
+            return self.into_iter().fold(T::ZERO, |sum, scalar| sum + scalar.abs());  // Zero, or NaN where all components are NaN
+        } else if !scale.is_finite() {
+            return scale;   // Infinite components dominate, as for `hypot`
+        }
         self.into_iter()
-            .map(|scalar| scalar.powi(2))
-            .fold(T::ZERO, T::add)
-            .sqrt()
-    }
-
-    /// Calculates the "scalar" dot product between this and another equally-sized vector
-    #[inline]
-    pub fn dot(self, rhs: Self) -> T {
-        self.into_iter()
-            .zip(rhs.into_iter())
-            .map(|(lhs, rhs)| lhs * rhs)
+            .map(|scalar| (scalar / scale).powi(2))
             .fold(T::ZERO, T::add)
+            .sqrt() * scale
     }
 
     /// Returns vector with same direction, but unit (1) length
@@ -356,7 +390,122 @@ impl<T: Scalar, const N: usize, B: Basis<N>> VectorN<T, N, B> {
     }
 }
 
+impl<T, const N: usize, B: Basis<N>> PointN<T, N, B> {
+    /// Create a new point from an array of coordinates
+    #[inline]
+    pub fn new(array: [T; N]) -> Self {
+        PointN(VectorN::new(array))
+    }
+
+    /// Create a point from its position vector relative to the origin
+    #[inline]
+    pub fn from_vector(vector: VectorN<T, N, B>) -> Self {
+        PointN(vector)
+    }
+
+    /// Position vector of this point, relative to the origin
+    ///
+    /// Acts as inverse to [`PointN::from_vector`]
+    #[inline]
+    pub fn to_vector(self) -> VectorN<T, N, B> {
+        self.0
+    }
+
+    /// Convert to a borrowed array
+    #[inline]
+    pub fn as_array(&self) -> &[T; N] {
+        self.0.as_array()
+    }
+
+    /// "Unwraps" this point, yielding an array
+    ///
+    /// Acts as inverse to [`PointN::new`]
+    #[inline]
+    pub fn to_array(self) -> [T; N] {
+        self.0.to_array()
+    }
+}
+
+impl<T: PartialEq, const N: usize, B: Basis<N>> PartialEq<[T; N]> for PointN<T, N, B> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<T: Display, const N: usize, B: Basis<N>> Display for PointN<T, N, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<T, const N: usize, B: Basis<N>> IntoIterator for PointN<T, N, B> {
+    type Item = <[T; N] as IntoIterator>::Item;
+    type IntoIter = <[T; N] as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: ApproxEq<Epsilon=T>, const N: usize, B: Basis<N>> ApproxEq for VectorN<T, N, B> {
+    impl_approx_eq_fields!(T; array);
+}
+
+impl<T: ApproxEq<Epsilon=T>, const N: usize, B: Basis<N>> ApproxEq for PointN<T, N, B> {
+    impl_approx_eq_fields!(T; 0);
+}
+
+// Point-Vector arithmetic
+impl<T: Ring, const N: usize, B: Basis<N>> Sub for PointN<T, N, B> {
+    type Output = VectorN<T, N, B>;
+
+    fn sub(self, rhs: PointN<T, N, B>) -> Self::Output {
+        self.0 - rhs.0
+    }
+}
+
+impl<T: Ring, const N: usize, B: Basis<N>> Add<VectorN<T, N, B>> for PointN<T, N, B> {
+    type Output = Self;
+
+    fn add(self, rhs: VectorN<T, N, B>) -> Self::Output {
+        PointN(self.0 + rhs)
+    }
+}
+
+impl<T: Ring, const N: usize, B: Basis<N>> AddAssign<VectorN<T, N, B>> for PointN<T, N, B> {
+    fn add_assign(&mut self, rhs: VectorN<T, N, B>) {
+        self.0 += rhs
+    }
+}
+
+impl<T: Ring, const N: usize, B: Basis<N>> Sub<VectorN<T, N, B>> for PointN<T, N, B> {
+    type Output = Self;
+
+    fn sub(self, rhs: VectorN<T, N, B>) -> Self::Output {
+        PointN(self.0 - rhs)
+    }
+}
+
+impl<T: Ring, const N: usize, B: Basis<N>> SubAssign<VectorN<T, N, B>> for PointN<T, N, B> {
+    fn sub_assign(&mut self, rhs: VectorN<T, N, B>) {
+        self.0 -= rhs
+    }
+}
+
 impl<T: Scalar, const N: usize, B: Basis<N>> PointN<T, N, B> {
+    /// Returns true if all coordinates of this point are finite, false if any coordinate is an infinity or NaN.
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+}
+
+impl<T: Ring, const N: usize, B: Basis<N>> PointN<T, N, B> {
+    /// The origin; All coordinates zero
+    #[inline]
+    pub fn origin() -> Self {
+        PointN::new([T::ZERO; N])
+    }
+
     /// Returns relative vector from this point to another point
     ///
     /// Equivalent to `target - self`. This function may be used to provide greater clarity of intent
@@ -364,4 +513,26 @@ impl<T: Scalar, const N: usize, B: Basis<N>> PointN<T, N, B> {
     pub fn vector_to(self, target: PointN<T, N, B>) -> VectorN<T, N, B> {
         target - self
     }
+
+    /// Linear interpolation between this point (`t = 0`) and `target` (`t = 1`)
+    ///
+    /// Values of `t` outside of `[0, 1]` extrapolate along the line through both points
+    #[inline]
+    pub fn lerp(self, target: PointN<T, N, B>, t: T) -> Self {
+        self + (self.vector_to(target) * t)
+    }
+}
+
+impl<T: Field, const N: usize, B: Basis<N>> PointN<T, N, B> {
+    /// Centroid (average position) of a set of points
+    ///
+    /// Returns `None` if `points` is empty
+    pub fn centroid(points: &[PointN<T, N, B>]) -> Option<Self> {
+        let (&first, rest) = points.split_first()?;
+        // Averaging offsets from the first point keeps this an affine combination; The sum of offsets is a vector
+        // The count is accumulated as a scalar, as the length may not fit in an `i32`
+        let (count, offset_sum) = rest.iter()
+            .fold((T::ONE, VectorN::new([T::ZERO; N])), |(count, sum), &point| (count + T::ONE, sum + (point - first)));
+        Some(first + (offset_sum / count))
+    }
 }